@@ -1,16 +1,32 @@
 //! Actors specific to the Chrome browser implementation (L2).
 
 use actix::prelude::*;
+use base64::prelude::*;
 use futures_channel::oneshot;
 use janus_core::error::InternalError;
-use janus_interfaces::common::*; // Re-export L1 common types if needed by messages
+use janus_interfaces::{
+    ContinueOverrides, DialogPolicy, ExposedFunction, FileChooser, HarRecordOptions, MouseOptions, NavigationResult, RouteAction, SubscriptionId, TargetCreatedEvent,
+    WaitUntil,
+};
 use janus_protocol_handler::{
     CommandActor, EventActor, ProtocolEvent, SendCommand, Subscribe, Unsubscribe,
 };
-use log::{debug, error, info, warn};
-use serde_json::Value;
+use log::{debug, error, info, trace, warn};
+use serde_json::{json, Value};
 use std::collections::HashMap;
-
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::bindings::{self, Binding};
+use crate::downloads;
+use crate::emulation::DeviceOverrides;
+use crate::events::{self, BrowserEventHandler, HandlerRegistry, PageEventHandler};
+use crate::fetch::{self, Route};
+use crate::frames::{FrameInfo, FrameTracker};
+use crate::har::HarRecorder;
+use crate::input::{self, InputState};
+use crate::navigation::{self, DocumentResponse, MainFrameState};
+use crate::page::ChromePage;
 use crate::protocol::*; // Import CDP structures
 
 // ================= Messages =================
@@ -24,16 +40,84 @@ pub struct GetVersion;
 #[rtype(result = "Result<NewPageResponse, InternalError>")]
 pub struct CreatePage {
     pub url: String,
+    pub browser_context_id: Option<String>, // Default context if unset
 }
 
+// Pages of a browser context, or all pages
 #[derive(Debug, Message)]
 #[rtype(result = "Result<Vec<PageInfo>, InternalError>")]
-pub struct GetPages;
+pub struct GetPages {
+    pub browser_context_id: Option<String>,
+}
+
+// Creates an isolated browser context and returns its ID
+#[derive(Debug, Message)]
+#[rtype(result = "Result<String, InternalError>")]
+pub struct CreateBrowserContext {
+    pub params: CreateBrowserContextParams,
+    pub emulation: Vec<SetEmulation>, // Applied to every page of the context
+}
+
+// Closes a browser context together with its pages
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct DisposeBrowserContext {
+    pub browser_context_id: String,
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct GrantPermissions(pub GrantPermissionsParams);
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct ResetPermissions {
+    pub browser_context_id: Option<String>,
+}
+
+// Sets the download behavior of a browser context; `download_dir` is where
+// `allowAndName` saves its downloads
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub(crate) struct SetDownloadBehavior {
+    pub(crate) params: SetDownloadBehaviorParams,
+    pub(crate) download_dir: Option<PathBuf>,
+}
 
 #[derive(Debug, Message)]
 #[rtype(result = "()")] // Just ack stopping process begins
 pub struct ShutdownBrowser;
 
+#[derive(Debug, Message)]
+#[rtype(result = "SubscriptionId")]
+pub struct AddBrowserEventHandler(pub(crate) BrowserEventHandler);
+
+#[derive(Debug, Message)]
+#[rtype(result = "bool")] // false if the ID was unknown
+pub struct RemoveBrowserEventHandler(pub SubscriptionId);
+
+// All cookies of a browser context (`Storage.getCookies`)
+#[derive(Debug, Message)]
+#[rtype(result = "Result<Vec<Cookie>, InternalError>")]
+pub struct GetAllCookies {
+    pub browser_context_id: Option<String>, // Default context if unset
+}
+
+// Deletes all cookies of a browser context (`Storage.clearCookies`)
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct ClearCookies {
+    pub browser_context_id: Option<String>,
+}
+
+// Sets cookies in a browser context (`Storage.setCookies`)
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct StoreCookies {
+    pub cookies: Vec<CookieParam>,
+    pub browser_context_id: Option<String>,
+}
+
 
 // Response from CreatePage
 #[derive(Debug)]
@@ -59,6 +143,25 @@ pub struct Navigate {
     pub url: String,
 }
 
+/// Navigates and waits until the new document reaches `wait_until`, all within `timeout`.
+#[derive(Debug, Message)]
+#[rtype(result = "Result<NavigationResult, InternalError>")]
+pub struct NavigateAndWait {
+    pub url: String,
+    pub wait_until: WaitUntil,
+    pub timeout: Duration,
+}
+
+// Internal: parks a navigation until its loader reaches `event_name`.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+struct AwaitNavigation {
+    url: String,
+    loader_id: String,
+    event_name: &'static str,
+    tx: oneshot::Sender<Result<NavigationResult, InternalError>>,
+}
+
 #[derive(Debug, Message)]
 #[rtype(result = "Result<Value, InternalError>")]
 pub struct EvaluateScript {
@@ -69,6 +172,284 @@ pub struct EvaluateScript {
 #[rtype(result = "Result<(), InternalError>")]
 pub struct ClosePage;
 
+// Asks the page to close, running its `beforeunload` handlers (`Page.close`)
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct RequestClose;
+
+// Like EvaluateScript, but returns a handle (objectId) to the result instead of its value
+#[derive(Debug, Message)]
+#[rtype(result = "Result<RemoteObject, InternalError>")]
+pub struct EvaluateHandle {
+    pub script: String,
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<RemoteObject, InternalError>")]
+pub struct CallFunctionOn {
+    pub session_id: Option<String>, // Session of an out-of-process frame holding the object
+    pub object_id: Option<String>,  // Bound as `this`; None to call in the page's main world
+    pub function_declaration: String,
+    pub args: Vec<CallArgument>,
+    pub return_by_value: bool,
+}
+
+// Own properties of an object (`Runtime.getProperties`)
+#[derive(Debug, Message)]
+#[rtype(result = "Result<Vec<PropertyDescriptor>, InternalError>")]
+pub struct GetProperties {
+    pub session_id: Option<String>,
+    pub object_id: String,
+}
+
+// Resolves the backendNodeId of a DOM node object
+#[derive(Debug, Message)]
+#[rtype(result = "Result<i64, InternalError>")]
+pub struct DescribeNode {
+    pub session_id: Option<String>,
+    pub object_id: String,
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct ReleaseObject {
+    pub session_id: Option<String>,
+    pub object_id: String,
+}
+
+// Frames of the page, parents before their children
+#[derive(Debug, Message)]
+#[rtype(result = "Vec<FrameInfo>")]
+pub(crate) struct GetFrames;
+
+// Evaluates in the main world of a frame; resolves to the result and the session of
+// the frame, if it is out-of-process
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(RemoteObject, Option<String>), InternalError>")]
+pub(crate) struct EvaluateInFrame {
+    pub(crate) frame_id: String,
+    pub(crate) script: String,
+    pub(crate) return_by_value: bool,
+}
+
+// Sent by the browser actor when an out-of-process iframe of the page was attached
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub(crate) struct AttachFrameSession {
+    pub(crate) session_id: String,
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub(crate) struct DetachFrameSession {
+    pub(crate) session_id: String,
+}
+
+// Cookies sent to `urls` (the page's URLs if empty)
+#[derive(Debug, Message)]
+#[rtype(result = "Result<Vec<Cookie>, InternalError>")]
+pub struct GetCookies {
+    pub urls: Vec<String>,
+}
+
+// Sets cookies through the page's session (`Network.setCookies`)
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct SetCookies(pub Vec<CookieParam>);
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct DeleteCookies(pub DeleteCookiesParams);
+
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetDialogPolicy(pub DialogPolicy);
+
+// Accepts or dismisses the open dialog
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct HandleDialog(pub HandleJavaScriptDialogParams);
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct SetFileInputFiles {
+    pub session_id: Option<String>,
+    pub params: SetFileInputFilesParams,
+}
+
+// Answers a file chooser reported to the `on_file_chooser` handlers
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct SetFileChooserFiles {
+    pub id: u64,
+    pub files: Vec<String>,
+}
+
+// Intercepts file choosers exactly while an `on_file_chooser` handler is registered
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct SyncFileChooserInterception;
+
+// Starts a HAR recording; false if one is already in progress
+#[derive(Debug, Message)]
+#[rtype(result = "bool")]
+pub(crate) struct StartHarRecording(pub(crate) HarRecordOptions);
+
+// Ends the HAR recording and hands over what was recorded
+#[derive(Debug, Message)]
+#[rtype(result = "Option<HarRecorder>")]
+pub(crate) struct StopHarRecording;
+
+// Body of a finished response, decoded
+#[derive(Debug, Message)]
+#[rtype(result = "Result<Vec<u8>, InternalError>")]
+pub struct GetResponseBody {
+    pub request_id: String,
+}
+
+// Captures a screenshot; resolves to the decoded image bytes
+#[derive(Debug, Message)]
+#[rtype(result = "Result<Vec<u8>, InternalError>")]
+pub struct CaptureScreenshot(pub CaptureScreenshotParams);
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<LayoutMetrics, InternalError>")]
+pub struct GetLayoutMetrics;
+
+// Prints the page; resolves to the `IO.StreamHandle` holding the PDF
+#[derive(Debug, Message)]
+#[rtype(result = "Result<String, InternalError>")]
+pub struct PrintToPdf(pub PrintToPdfParams);
+
+// Reads the next chunk of an `IO` stream
+#[derive(Debug, Message)]
+#[rtype(result = "Result<StreamChunk, InternalError>")]
+pub struct ReadStream {
+    pub handle: String,
+    pub size: Option<i64>,
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct CloseStream {
+    pub handle: String,
+}
+
+/// Decoded chunk of an `IO` stream.
+#[derive(Debug)]
+pub struct StreamChunk {
+    pub data: Vec<u8>,
+    pub eof: bool,
+}
+
+// Applies the viewport, touch and user agent overrides of an emulated device
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct EmulateDevice(pub(crate) DeviceOverrides);
+
+// Sets one of the page's emulation overrides
+#[derive(Debug, Clone, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub enum SetEmulation {
+    Timezone(SetTimezoneOverrideParams),
+    Locale(SetLocaleOverrideParams),
+    Geolocation(Option<SetGeolocationOverrideParams>), // None clears the override
+    Media(SetEmulatedMediaParams),
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<BoxModel, InternalError>")]
+pub struct GetBoxModel {
+    pub backend_node_id: i64,
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub enum MouseInput {
+    Move { x: f64, y: f64 },
+    Down { x: f64, y: f64, options: MouseOptions },
+    Up { x: f64, y: f64, options: MouseOptions },
+    Wheel { x: f64, y: f64, delta_x: f64, delta_y: f64 },
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub enum KeyInput {
+    Down(String), // Key name or code on the US layout
+    Up(String),
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct InsertText(pub String);
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub struct TouchTap {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "SubscriptionId")]
+pub struct AddPageEventHandler(pub(crate) PageEventHandler);
+
+#[derive(Debug, Message)]
+#[rtype(result = "bool")] // false if the ID was unknown
+pub struct RemovePageEventHandler(pub SubscriptionId);
+
+// Registers a route; resolves once `Fetch` interception covers its pattern
+#[derive(Debug, Message)]
+#[rtype(result = "Result<SubscriptionId, InternalError>")]
+pub struct AddRoute(pub(crate) Route);
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<bool, InternalError>")] // false if the ID was unknown
+pub struct RemoveRoute(pub SubscriptionId);
+
+// Adds a script to evaluate in new documents; resolves to its identifier
+#[derive(Debug, Message)]
+#[rtype(result = "Result<String, InternalError>")]
+pub struct AddInitScript {
+    pub source: String,
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "Result<bool, InternalError>")] // false if the identifier was unknown
+pub struct RemoveInitScript {
+    pub identifier: String,
+}
+
+// Exposes a Rust function as `window[name]`; resolves once the current document has it
+#[derive(Debug, Message)]
+#[rtype(result = "Result<(), InternalError>")]
+pub(crate) struct ExposeFunction(pub(crate) Binding);
+
+// Sends a command through the CommandActor and awaits the result via oneshot channel.
+// Takes owned handles so the returned future can outlive the calling actor's borrow.
+pub(crate) async fn execute_command(
+    command_actor: Addr<CommandActor>,
+    session_id: Option<String>,
+    method: String,
+    params: Value,
+) -> Result<Value, InternalError> {
+    let (tx, rx) = oneshot::channel();
+    let command = SendCommand {
+        session_id,
+        method,
+        params,
+        result_tx: tx,
+    };
+
+    command_actor
+        .send(command)
+        .await
+        .map_err(|mb_err| InternalError::Actor(format!("CommandActor mailbox error: {}", mb_err)))??; // Mailbox and acceptance errors
+
+    rx.await.map_err(|_canceled| {
+        InternalError::Actor("Command result channel cancelled".to_string())
+    })?
+}
 
 // ================= Chrome Browser Actor =================
 
@@ -90,6 +471,21 @@ pub struct ChromeBrowserActor {
     page_actors: HashMap<String, Addr<ChromePageActor>>,
     // Maps Target ID -> Session ID (for sending commands)
     target_sessions: HashMap<String, String>,
+    // Maps Session ID of an out-of-process iframe -> Target ID of the page it belongs to
+    frame_sessions: HashMap<String, String>,
+    // Last known info of every discovered target (all types, not only pages)
+    targets: HashMap<String, TargetInfo>,
+    // Handlers registered through the L1 `Browser::on_*` methods
+    event_handlers: HandlerRegistry<BrowserEventHandler>,
+    // Maps Frame ID of an in-process iframe -> Target ID of the target it is in
+    frame_targets: HashMap<String, String>,
+    // Maps ID of each context created through this actor -> emulation overrides applied
+    // to each of its pages. Targets of other contexts are in the default one.
+    context_emulation: HashMap<String, Vec<SetEmulation>>,
+    // Maps Browser Context ID (None for the default one) -> directory downloads are saved in
+    download_dirs: HashMap<Option<String>, PathBuf>,
+    // Maps GUID -> expected path of downloads in progress
+    download_paths: HashMap<String, PathBuf>,
     // Self address for subscriptions
     self_addr: Option<Addr<Self>>,
 }
@@ -105,36 +501,25 @@ impl ChromeBrowserActor {
             event_actor,
             page_actors: HashMap::new(),
             target_sessions: HashMap::new(),
+            frame_sessions: HashMap::new(),
+            targets: HashMap::new(),
+            event_handlers: HandlerRegistry::default(),
+            frame_targets: HashMap::new(),
+            context_emulation: HashMap::new(),
+            download_dirs: HashMap::new(),
+            download_paths: HashMap::new(),
             self_addr: None,
         }
     }
 
     // Helper to send a command and await the result via oneshot channel
-    async fn send_command(
+    fn send_command(
         &self,
         session_id: Option<String>,
         method: String,
         params: Value,
-    ) -> Result<Value, InternalError> {
-        let (tx, rx) = oneshot::channel();
-        let command = SendCommand {
-            session_id,
-            method,
-            params,
-            result_tx: tx,
-        };
-
-        self.command_actor
-            .send(command)
-            .await
-            .map_err(|mb_err| InternalError::Actor(format!("CommandActor mailbox error: {}", mb_err)))? // Mailbox Error
-            .map_err(|accept_err| accept_err)?; // SendCommand acceptance Error (e.g. serialization)
-
-
-        // Await the result from the oneshot channel
-        rx.await.map_err(|_canceled| {
-            InternalError::Actor("Command result channel cancelled".to_string())
-        })? // Channel Canceled/Dropped Error
+    ) -> impl Future<Output = Result<Value, InternalError>> + 'static {
+        execute_command(self.command_actor.clone(), session_id, method, params)
     }
 
 
@@ -158,9 +543,16 @@ impl ChromeBrowserActor {
                 match serde_json::from_value::<TargetCreatedParams>(event.params) {
                     Ok(params) => {
                         info!("New target created: {:?}", params.target_info);
-                        if params.target_info.type_ == "page" && !self.page_actors.contains_key(&params.target_info.target_id) {
-                             // If it's a page target we don't know about, try to attach and create an actor
-                             self.attach_and_create_page_actor(params.target_info.target_id, ctx);
+                        let target_id = params.target_info.target_id.clone();
+                        self.targets.insert(target_id.clone(), params.target_info.clone());
+                        if params.target_info.type_ != "page" {
+                            self.dispatch_target_created(&params.target_info, None);
+                        } else if let Some(page_actor) = self.page_actors.get(&target_id) {
+                            self.dispatch_target_created(&params.target_info, Some(page_actor));
+                        } else {
+                             // If it's a page target we don't know about, try to attach and create an actor.
+                             // Handlers are notified once the actor exists (see attachedToTarget).
+                             self.attach_and_create_page_actor(target_id, ctx);
                          }
                     }
                     Err(e) => warn!("Failed to parse Target.targetCreated params: {}", e),
@@ -171,6 +563,13 @@ impl ChromeBrowserActor {
                     Ok(params) => {
                          debug!("Target info changed: {:?}", params.target_info);
                          // Could update page actor state if needed (e.g., URL, title)
+                         let info = events::target_info_from_cdp(&params.target_info);
+                         self.targets.insert(params.target_info.target_id.clone(), params.target_info);
+                         for handler in self.event_handlers.values() {
+                             if let BrowserEventHandler::Changed(handler) = handler {
+                                 handler(info.clone());
+                             }
+                         }
                     }
                      Err(e) => warn!("Failed to parse Target.targetInfoChanged params: {}", e),
                  }
@@ -182,13 +581,28 @@ impl ChromeBrowserActor {
                  struct AttachedParams { session_id: String, target_info: TargetInfo }
 
                  match serde_json::from_value::<AttachedParams>(event.params) {
+                     Ok(params) if params.target_info.type_ == "iframe" => {
+                         // Auto-attached out-of-process iframe; `event.session_id` is its parent's session.
+                         self.attach_frame_session(event.session_id.as_deref(), params.session_id, ctx);
+                     }
                      Ok(params) => {
                          info!("Attached to target {}, session ID: {}", params.target_info.target_id, params.session_id);
                          if params.target_info.type_ == "page" {
                              self.target_sessions.insert(params.target_info.target_id.clone(), params.session_id.clone());
                              // If we don't have an actor yet, create one now
                              if !self.page_actors.contains_key(&params.target_info.target_id) {
-                                 self.create_page_actor_internal(params.target_info.target_id, params.session_id, ctx);
+                                 self.set_auto_attach(params.session_id.clone(), ctx);
+                                 let page_actor = self.create_page_actor_internal(
+                                     params.target_info.target_id.clone(),
+                                     params.session_id,
+                                     params.target_info.browser_context_id.clone(),
+                                     ctx,
+                                 );
+                                 let target_info = self.targets
+                                     .entry(params.target_info.target_id.clone())
+                                     .or_insert(params.target_info)
+                                     .clone();
+                                 self.dispatch_target_created(&target_info, Some(&page_actor));
                              }
                          }
                      }
@@ -210,6 +624,12 @@ impl ChromeBrowserActor {
                                  page_actor.do_send(ClosePage); // Tell actor to stop gracefully
                              }
                              self.target_sessions.remove(&tid);
+                             self.frame_sessions.retain(|_, page_tid| *page_tid != tid);
+                             self.frame_targets.retain(|_, frame_tid| *frame_tid != tid);
+                        } else if let Some(page_tid) = self.frame_sessions.remove(&params.session_id) {
+                             if let Some(page_actor) = self.page_actors.get(&page_tid) {
+                                 page_actor.do_send(DetachFrameSession { session_id: params.session_id });
+                             }
                         } else {
                              warn!("Received detachedFromTarget for unknown session: {}", params.session_id);
                          }
@@ -226,6 +646,15 @@ impl ChromeBrowserActor {
                              page_actor.do_send(ClosePage); // Tell actor to stop gracefully
                          }
                          self.target_sessions.remove(&params.target_id);
+                         self.frame_targets.retain(|_, frame_tid| *frame_tid != params.target_id);
+                         if let Some(target_info) = self.targets.remove(&params.target_id) {
+                             let info = events::target_info_from_cdp(&target_info);
+                             for handler in self.event_handlers.values() {
+                                 if let BrowserEventHandler::Destroyed(handler) = handler {
+                                     handler(info.clone());
+                                 }
+                             }
+                         }
                      }
                     Err(e) => warn!("Failed to parse Target.targetDestroyed params: {}", e),
                  }
@@ -234,6 +663,135 @@ impl ChromeBrowserActor {
         }
     }
 
+    // Handles Browser.download* events
+    fn handle_download_event(&mut self, event: ProtocolEvent) {
+        match event.method.as_str() {
+            "Browser.downloadWillBegin" => match serde_json::from_value::<DownloadWillBeginParams>(event.params) {
+                Ok(params) => {
+                    debug!("Download {} of {} will begin", params.guid, params.url);
+                    // Expected only if the frame's context has a directory set; otherwise Chrome
+                    // reports the path itself once completed.
+                    let context_id = self.frame_context(&params.frame_id);
+                    if let Some(path) = downloads::saved_path(&self.download_dirs, context_id, &params.guid) {
+                        self.download_paths.insert(params.guid.clone(), path);
+                    }
+                    let started = downloads::download_started_from_cdp(params);
+                    for handler in self.event_handlers.values() {
+                        if let BrowserEventHandler::DownloadStarted(handler) = handler {
+                            handler(started.clone());
+                        }
+                    }
+                }
+                Err(e) => warn!("Failed to parse Browser.downloadWillBegin params: {}", e),
+            },
+            "Browser.downloadProgress" => match serde_json::from_value::<DownloadProgressParams>(event.params) {
+                Ok(params) => {
+                    let saved_path = if params.state == "inProgress" {
+                        None
+                    } else {
+                        self.download_paths.remove(&params.guid)
+                    };
+                    let Some(progress) = downloads::download_progress_from_cdp(params, saved_path) else {
+                        return;
+                    };
+                    trace!("Download progress: {:?}", progress);
+                    for handler in self.event_handlers.values() {
+                        if let BrowserEventHandler::DownloadProgress(handler) = handler {
+                            handler(progress.clone());
+                        }
+                    }
+                }
+                Err(e) => warn!("Failed to parse Browser.downloadProgress params: {}", e),
+            },
+            _ => {}
+        }
+    }
+
+    // Context of a frame: `None` if the frame is unknown, `Some(None)` for the default
+    // context (whose targets report an ID too).
+    fn frame_context(&self, frame_id: &str) -> Option<Option<String>> {
+        // Main frames and out-of-process iframes are targets of their own.
+        let target_id = if self.targets.contains_key(frame_id) {
+            frame_id
+        } else {
+            self.frame_targets.get(frame_id)?
+        };
+        let context_id = self.targets.get(target_id)?.browser_context_id.clone();
+        Some(context_id.filter(|context_id| self.context_emulation.contains_key(context_id)))
+    }
+
+    // Handles the Page.frame* events of every session, to tell which target a frame is in.
+    fn handle_frame_event(&mut self, event: ProtocolEvent) {
+        let Some(session_id) = event.session_id.as_deref() else {
+            return;
+        };
+        match event.method.as_str() {
+            "Page.frameAttached" => match serde_json::from_value::<FrameAttachedParams>(event.params) {
+                Ok(params) => {
+                    let target_id = self
+                        .target_sessions
+                        .iter()
+                        .find_map(|(tid, sid)| (sid == session_id).then_some(tid))
+                        .or_else(|| self.frame_sessions.get(session_id));
+                    if let Some(target_id) = target_id {
+                        self.frame_targets.insert(params.frame_id, target_id.clone());
+                    }
+                }
+                Err(e) => warn!("Failed to parse Page.frameAttached params: {}", e),
+            },
+            "Page.frameDetached" => match serde_json::from_value::<FrameDetachedParams>(event.params) {
+                Ok(params) => {
+                    self.frame_targets.remove(&params.frame_id);
+                }
+                Err(e) => warn!("Failed to parse Page.frameDetached params: {}", e),
+            },
+            _ => {}
+        }
+    }
+
+    // Attaches to the out-of-process iframes of a session (and their own iframes, once attached).
+    fn set_auto_attach(&self, session_id: String, ctx: &mut Context<Self>) {
+        let params = SetAutoAttachParams {
+            auto_attach: true,
+            wait_for_debugger_on_start: false,
+            flatten: true,
+        };
+        let future = self.send_command(
+            Some(session_id.clone()),
+            "Target.setAutoAttach".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        ctx.spawn(
+            async move {
+                if let Err(e) = future.await {
+                    warn!("Failed to enable auto-attach for session {}: {}", session_id, e);
+                }
+            }
+            .into_actor(self),
+        );
+    }
+
+    // Hands an attached iframe session to the actor of the page that (indirectly) owns it.
+    fn attach_frame_session(&mut self, parent_session_id: Option<&str>, session_id: String, ctx: &mut Context<Self>) {
+        let page_target_id = parent_session_id.and_then(|parent| {
+            self.frame_sessions.get(parent).cloned().or_else(|| {
+                self.target_sessions
+                    .iter()
+                    .find_map(|(tid, sid)| (sid == parent).then(|| tid.clone()))
+            })
+        });
+        let Some((page_target_id, page_actor)) = page_target_id
+            .and_then(|tid| self.page_actors.get(&tid).cloned().map(|page_actor| (tid, page_actor)))
+        else {
+            warn!("Attached iframe session {} of unknown parent session {:?}", session_id, parent_session_id);
+            return;
+        };
+        info!("Attached out-of-process iframe session {} of page {}", session_id, page_target_id);
+        page_actor.do_send(AttachFrameSession { session_id: session_id.clone() });
+        self.frame_sessions.insert(session_id.clone(), page_target_id);
+        self.set_auto_attach(session_id, ctx);
+    }
+
     // Spawns a task to attach to a target and create its actor
     fn attach_and_create_page_actor(&self, target_id: String, ctx: &mut Context<Self>) {
          info!("Attempting to attach to target: {}", target_id);
@@ -265,18 +823,45 @@ impl ChromeBrowserActor {
          }.into_actor(self)); // Associate future with the actor
     }
 
-    fn create_page_actor_internal(&mut self, target_id: String, session_id: String, ctx: &mut Context<Self>) -> Addr<ChromePageActor> {
+    fn create_page_actor_internal(
+        &mut self,
+        target_id: String,
+        session_id: String,
+        browser_context_id: Option<String>,
+        ctx: &mut Context<Self>,
+    ) -> Addr<ChromePageActor> {
         info!("Creating PageActor for target {}, session {}", target_id, session_id);
+        // Pages opened by the page itself (popups, `window.open`) get their context's overrides too.
+        let emulation = browser_context_id
+            .as_ref()
+            .and_then(|context_id| self.context_emulation.get(context_id))
+            .cloned()
+            .unwrap_or_default();
         let page_actor = ChromePageActor::new(
             target_id.clone(),
             session_id,
             self.command_actor.clone(),
             self.event_actor.clone(),
-        ).start();
+        )
+        .in_context(browser_context_id, emulation)
+        .start();
         self.page_actors.insert(target_id, page_actor.clone());
         page_actor
     }
 
+    // Notifies `on_target_created` handlers. Each handler gets its own `Page` handle.
+    fn dispatch_target_created(&self, target_info: &TargetInfo, page_actor: Option<&Addr<ChromePageActor>>) {
+        let info = events::target_info_from_cdp(target_info);
+        for handler in self.event_handlers.values() {
+            if let BrowserEventHandler::Created(handler) = handler {
+                let page = page_actor.map(|addr| {
+                    Box::new(ChromePage::new(addr.clone(), info.target_id.clone())) as Box<dyn janus_interfaces::Page>
+                });
+                handler(TargetCreatedEvent { target: info.clone(), page });
+            }
+        }
+    }
+
 }
 
 
@@ -293,7 +878,12 @@ impl Actor for ChromeBrowserActor {
         self.subscribe_to_event("Target.targetInfoChanged", None, self_recipient.clone());
         self.subscribe_to_event("Target.attachedToTarget", None, self_recipient.clone()); // Handle attach results
         self.subscribe_to_event("Target.detachedFromTarget", None, self_recipient.clone());
-        self.subscribe_to_event("Target.targetDestroyed", None, self_recipient);
+        self.subscribe_to_event("Target.targetDestroyed", None, self_recipient.clone());
+        self.subscribe_to_event("Browser.downloadWillBegin", None, self_recipient.clone());
+        self.subscribe_to_event("Browser.downloadProgress", None, self_recipient.clone());
+        // Frames of all sessions, to map downloads to their context
+        self.subscribe_to_event("Page.frameAttached", None, self_recipient.clone());
+        self.subscribe_to_event("Page.frameDetached", None, self_recipient);
 
         // Enable target discovery
         let command_actor = self.command_actor.clone();
@@ -337,6 +927,10 @@ impl Actor for ChromeBrowserActor {
         }
         self.page_actors.clear();
         self.target_sessions.clear();
+        self.targets.clear();
+        self.frame_targets.clear();
+        self.event_handlers.clear();
+        self.download_paths.clear();
         // TODO: Unsubscribe from events? Might happen automatically if EventActor handles dead recipients.
         Running::Stop
     }
@@ -358,31 +952,195 @@ impl Handler<GetVersion> for ChromeBrowserActor {
     }
 }
 
-impl Handler<CreatePage> for ChromeBrowserActor {
-    type Result = ResponseFuture<Result<NewPageResponse, InternalError>>;
-
-    fn handle(&mut self, msg: CreatePage, ctx: &mut Context<Self>) -> Self::Result {
-        let command_actor = self.command_actor.clone();
-        let self_addr = ctx.address(); // Get self address to interact with state later
+impl Handler<GetAllCookies> for ChromeBrowserActor {
+    type Result = ResponseFuture<Result<Vec<Cookie>, InternalError>>;
 
+    fn handle(&mut self, msg: GetAllCookies, _ctx: &mut Context<Self>) -> Self::Result {
+        let params = BrowserContextParams { browser_context_id: msg.browser_context_id };
+        let future = self.send_command(
+            None,
+            "Storage.getCookies".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
         Box::pin(async move {
-            info!("BrowserActor handling CreatePage request for URL: {}", msg.url);
-            let params = CreateTargetParams { url: msg.url };
-            let result_value = Self::send_command(&self_addr.clone().into(), // Kludgy way to call method on self from async block
-                None,
-                "Target.createTarget".to_string(),
-                serde_json::to_value(params).map_err(|e| InternalError::Serialization(e.to_string()))?
-            ).await?;
+            let result: GetCookiesResult = serde_json::from_value(future.await?)
+                .map_err(|e| InternalError::Deserialization(format!("Failed to parse GetCookiesResult: {}", e)))?;
+            Ok(result.cookies)
+        })
+    }
+}
 
-            let create_result: CreateTargetResult = serde_json::from_value(result_value)
-                .map_err(|e| InternalError::Deserialization(format!("Failed to parse CreateTargetResult: {}", e)))?;
+impl Handler<StoreCookies> for ChromeBrowserActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
 
-            let target_id = create_result.target_id;
-            info!("Target.createTarget successful, target_id: {}", target_id);
+    fn handle(&mut self, msg: StoreCookies, _ctx: &mut Context<Self>) -> Self::Result {
+        let params = SetCookiesParams {
+            cookies: msg.cookies,
+            browser_context_id: msg.browser_context_id,
+        };
+        let future = self.send_command(
+            None,
+            "Storage.setCookies".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        Box::pin(async move {
+            future.await?;
+            Ok(())
+        })
+    }
+}
 
-            // Now we need to attach to this target to get a session ID and control it.
-            // The attachment and actor creation is handled via events ("Target.attachedToTarget").
-            // We need to wait until the actor is created and return its address.
+impl Handler<ClearCookies> for ChromeBrowserActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: ClearCookies, _ctx: &mut Context<Self>) -> Self::Result {
+        let params = BrowserContextParams { browser_context_id: msg.browser_context_id };
+        let future = self.send_command(
+            None,
+            "Storage.clearCookies".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        Box::pin(async move {
+            future.await?;
+            Ok(())
+        })
+    }
+}
+
+impl Handler<CreateBrowserContext> for ChromeBrowserActor {
+    type Result = ResponseActFuture<Self, Result<String, InternalError>>;
+
+    fn handle(&mut self, msg: CreateBrowserContext, _ctx: &mut Context<Self>) -> Self::Result {
+        let future = self.send_command(
+            None,
+            "Target.createBrowserContext".to_string(),
+            serde_json::to_value(msg.params).unwrap(),
+        );
+        let emulation = msg.emulation;
+        Box::pin(
+            async move {
+                let result: CreateBrowserContextResult = serde_json::from_value(future.await?)
+                    .map_err(|e| InternalError::Deserialization(format!("Failed to parse CreateBrowserContextResult: {}", e)))?;
+                info!("Created browser context {}", result.browser_context_id);
+                Ok(result.browser_context_id)
+            }
+            .into_actor(self)
+            .map(move |result, act, _ctx| {
+                if let Ok(context_id) = &result {
+                    act.context_emulation.insert(context_id.clone(), emulation);
+                }
+                result
+            }),
+        )
+    }
+}
+
+impl Handler<DisposeBrowserContext> for ChromeBrowserActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: DisposeBrowserContext, _ctx: &mut Context<Self>) -> Self::Result {
+        // The context's pages are closed by the browser; their actors stop on Target.targetDestroyed.
+        self.context_emulation.remove(&msg.browser_context_id);
+        let params = DisposeBrowserContextParams { browser_context_id: msg.browser_context_id };
+        let future = self.send_command(
+            None,
+            "Target.disposeBrowserContext".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        Box::pin(async move {
+            future.await?;
+            Ok(())
+        })
+    }
+}
+
+impl Handler<GrantPermissions> for ChromeBrowserActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: GrantPermissions, _ctx: &mut Context<Self>) -> Self::Result {
+        let future = self.send_command(
+            None,
+            "Browser.grantPermissions".to_string(),
+            serde_json::to_value(msg.0).unwrap(),
+        );
+        Box::pin(async move {
+            future.await?;
+            Ok(())
+        })
+    }
+}
+
+impl Handler<ResetPermissions> for ChromeBrowserActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: ResetPermissions, _ctx: &mut Context<Self>) -> Self::Result {
+        let params = BrowserContextParams { browser_context_id: msg.browser_context_id };
+        let future = self.send_command(
+            None,
+            "Browser.resetPermissions".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        Box::pin(async move {
+            future.await?;
+            Ok(())
+        })
+    }
+}
+
+impl Handler<SetDownloadBehavior> for ChromeBrowserActor {
+    type Result = ResponseActFuture<Self, Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: SetDownloadBehavior, _ctx: &mut Context<Self>) -> Self::Result {
+        let context_id = msg.params.browser_context_id.clone();
+        let future = self.send_command(
+            None,
+            "Browser.setDownloadBehavior".to_string(),
+            serde_json::to_value(msg.params).unwrap(),
+        );
+        Box::pin(
+            async move {
+                future.await?;
+                Ok(())
+            }
+            .into_actor(self)
+            .map(move |result, act, _ctx| {
+                if result.is_ok() {
+                    match msg.download_dir {
+                        Some(dir) => act.download_dirs.insert(context_id, dir),
+                        None => act.download_dirs.remove(&context_id),
+                    };
+                }
+                result
+            }),
+        )
+    }
+}
+
+impl Handler<CreatePage> for ChromeBrowserActor {
+    type Result = ResponseFuture<Result<NewPageResponse, InternalError>>;
+
+    fn handle(&mut self, msg: CreatePage, ctx: &mut Context<Self>) -> Self::Result {
+        let command_actor = self.command_actor.clone();
+        let self_addr = ctx.address(); // Get self address to interact with state later
+
+        Box::pin(async move {
+            info!("BrowserActor handling CreatePage request for URL: {}", msg.url);
+            let params = CreateTargetParams { url: msg.url, browser_context_id: msg.browser_context_id };
+            let result_value = Self::send_command(&self_addr.clone().into(), // Kludgy way to call method on self from async block
+                None,
+                "Target.createTarget".to_string(),
+                serde_json::to_value(params).map_err(|e| InternalError::Serialization(e.to_string()))?
+            ).await?;
+
+            let create_result: CreateTargetResult = serde_json::from_value(result_value)
+                .map_err(|e| InternalError::Deserialization(format!("Failed to parse CreateTargetResult: {}", e)))?;
+
+            let target_id = create_result.target_id;
+            info!("Target.createTarget successful, target_id: {}", target_id);
+
+            // Now we need to attach to this target to get a session ID and control it.
+            // The attachment and actor creation is handled via events ("Target.attachedToTarget").
+            // We need to wait until the actor is created and return its address.
 
             // Use a temporary oneshot channel to wait for the actor creation signal
             // This is a bit complex, maybe there's a simpler way?
@@ -431,12 +1189,17 @@ impl Handler<GetPageActorAddr> for ChromeBrowserActor {
 impl Handler<GetPages> for ChromeBrowserActor {
      type Result = Result<Vec<PageInfo>, InternalError>; // Directly return result
 
-     fn handle(&mut self, _msg: GetPages, _ctx: &mut Context<Self>) -> Self::Result {
+     fn handle(&mut self, msg: GetPages, _ctx: &mut Context<Self>) -> Self::Result {
          // This just returns the currently known page actors.
          // For a more accurate list, we might need to call Target.getTargets.
          // Phase 2: Return actors we know about.
          let pages: Vec<PageInfo> = self.page_actors
              .iter()
+             .filter(|(tid, _)| {
+                 msg.browser_context_id.is_none()
+                     || self.targets.get(*tid).and_then(|info| info.browser_context_id.as_ref())
+                         == msg.browser_context_id.as_ref()
+             })
              // .filter_map(|(tid, addr)| { // Also need URL/Title, which actor doesn't have easily
              //      // Need to ask each PageActor for its URL/Title? Too complex for now.
              //      Some(PageInfo { id: tid.clone(), title: "Unknown".into(), url: "Unknown".into(), actor_addr: addr.clone() })
@@ -457,6 +1220,24 @@ impl Handler<ShutdownBrowser> for ChromeBrowserActor {
 }
 
 
+impl Handler<AddBrowserEventHandler> for ChromeBrowserActor {
+    type Result = MessageResult<AddBrowserEventHandler>;
+
+    fn handle(&mut self, msg: AddBrowserEventHandler, _ctx: &mut Context<Self>) -> Self::Result {
+        let id = self.event_handlers.insert(msg.0);
+        debug!("BrowserActor registered event handler {}", id);
+        MessageResult(id)
+    }
+}
+
+impl Handler<RemoveBrowserEventHandler> for ChromeBrowserActor {
+    type Result = bool;
+
+    fn handle(&mut self, msg: RemoveBrowserEventHandler, _ctx: &mut Context<Self>) -> Self::Result {
+        self.event_handlers.remove(msg.0)
+    }
+}
+
 // Handler for ProtocolEvent messages (forwarded by EventActor)
 impl Handler<ProtocolEvent> for ChromeBrowserActor {
     type Result = ();
@@ -465,6 +1246,10 @@ impl Handler<ProtocolEvent> for ChromeBrowserActor {
         trace!("BrowserActor received event: {:?}", msg);
         if msg.method.starts_with("Target.") {
             self.handle_target_event(msg, ctx);
+        } else if msg.method.starts_with("Browser.download") {
+            self.handle_download_event(msg);
+        } else if msg.method.starts_with("Page.frame") {
+            self.handle_frame_event(msg);
         } else {
             // Ignore other events at browser level for now
         }
@@ -488,11 +1273,48 @@ enum PageActorState {
 pub struct ChromePageActor {
     target_id: String,
     session_id: String,
+    browser_context_id: Option<String>, // None for the default context
+    // Overrides of the page's browser context, applied before any other message is handled
+    context_emulation: Vec<SetEmulation>,
     state: PageActorState,
     command_actor: Addr<CommandActor>,
     event_actor: Recipient<ProtocolEvent>,
+    // Handlers registered through the L1 `Page::on_*` methods
+    event_handlers: HandlerRegistry<PageEventHandler>,
+    // Held keys / mouse buttons, applied to subsequent input events
+    input: InputState,
+    // Lifecycle of the main frame's document, and navigations waiting on it
+    main_frame: MainFrameState,
+    pending_navigations: Vec<AwaitNavigation>,
+    // Routes registered through `Page::route`; any route enables `Fetch` interception
+    routes: HandlerRegistry<Route>,
+    // Traffic recorded between `start_har_recording` and `stop_har_recording`
+    har: Option<HarRecorder>,
+    // How dialogs are answered while no `on_dialog` handler is registered
+    dialog_policy: DialogPolicy,
+    // Intercepted file choosers awaiting files: chooser ID -> backend node ID of the input
+    file_choosers: HashMap<u64, i64>,
+    next_file_chooser_id: u64,
+    intercept_file_chooser: bool,
+    // Frame tree, including out-of-process iframes attached by the browser actor
+    frames: FrameTracker,
+    frame_sessions: Vec<String>,
+    // Identifiers of the scripts added through `Page::add_init_script`
+    init_scripts: Vec<String>,
+    // Functions exposed through `Page::expose_function`, by name
+    bindings: HashMap<String, ExposedFunction>,
 }
 
+// Events that keep the frame tree and its execution contexts up to date, in every session
+const FRAME_EVENTS: [&str; 6] = [
+    "Page.frameAttached",
+    "Page.frameNavigated",
+    "Page.frameDetached",
+    "Runtime.executionContextCreated",
+    "Runtime.executionContextDestroyed",
+    "Runtime.executionContextsCleared",
+];
+
 impl ChromePageActor {
     pub fn new(
         target_id: String,
@@ -503,152 +1325,1184 @@ impl ChromePageActor {
         Self {
             target_id,
             session_id,
+            browser_context_id: None,
+            context_emulation: Vec::new(),
             state: PageActorState::Initializing,
             command_actor,
             event_actor,
+            event_handlers: HandlerRegistry::default(),
+            input: InputState::default(),
+            main_frame: MainFrameState::default(),
+            pending_navigations: Vec::new(),
+            routes: HandlerRegistry::default(),
+            har: None,
+            dialog_policy: DialogPolicy::default(),
+            file_choosers: HashMap::new(),
+            next_file_chooser_id: 1,
+            intercept_file_chooser: false,
+            frames: FrameTracker::default(),
+            frame_sessions: Vec::new(),
+            init_scripts: Vec::new(),
+            bindings: HashMap::new(),
         }
     }
 
-    // Helper to send a command *for this page's session*
-    async fn send_page_command(
-        &self,
-        method: String,
-        params: Value,
-    ) -> Result<Value, InternalError> {
-         let (tx, rx) = oneshot::channel();
-         let command = SendCommand {
-             session_id: Some(self.session_id.clone()), // Use this page's session
-             method,
-             params,
-             result_tx: tx,
-         };
+    /// Places the page in a browser context, whose emulation overrides it applies on start.
+    pub(crate) fn in_context(mut self, browser_context_id: Option<String>, emulation: Vec<SetEmulation>) -> Self {
+        self.browser_context_id = browser_context_id;
+        self.context_emulation = emulation;
+        self
+    }
 
-         self.command_actor
-            .send(command)
-            .await
-            .map_err(|mb_err| InternalError::Actor(format!("CommandActor mailbox error: {}", mb_err)))??; // Handle both errors
+    // Sends the commands of an emulation override.
+    fn emulation_command(&self, msg: SetEmulation) -> ResponseFuture<Result<(), InternalError>> {
+        match msg {
+            SetEmulation::Timezone(params) => self.send_void_command("Emulation.setTimezoneOverride", params),
+            SetEmulation::Locale(params) => self.send_void_command("Emulation.setLocaleOverride", params),
+            SetEmulation::Geolocation(Some(params)) => {
+                // The override is only visible to pages allowed to use the Geolocation API.
+                let grant = SetPermissionParams {
+                    permission: PermissionDescriptor { name: "geolocation" },
+                    setting: "granted",
+                    origin: None,
+                    browser_context_id: self.browser_context_id.clone(),
+                };
+                let grant = execute_command(
+                    self.command_actor.clone(),
+                    None,
+                    "Browser.setPermission".to_string(),
+                    serde_json::to_value(grant).unwrap(),
+                );
+                let set = self.send_void_command("Emulation.setGeolocationOverride", params);
+                Box::pin(async move {
+                    grant.await?;
+                    set.await
+                })
+            }
+            SetEmulation::Geolocation(None) => self.send_void_command("Emulation.clearGeolocationOverride", json!({})),
+            SetEmulation::Media(params) => self.send_void_command("Emulation.setEmulatedMedia", params),
+        }
+    }
 
+    // Applies the context's overrides; messages wait until they are in place.
+    fn apply_context_emulation(&mut self, ctx: &mut Context<Self>) {
+        if self.context_emulation.is_empty() {
+            return;
+        }
+        let commands: Vec<_> = std::mem::take(&mut self.context_emulation)
+            .into_iter()
+            .map(|msg| self.emulation_command(msg))
+            .collect();
+        let target_id = self.target_id.clone();
+        ctx.wait(
+            async move {
+                for command in commands {
+                    if let Err(e) = command.await {
+                        warn!("Failed to apply context emulation to {}: {}", target_id, e);
+                    }
+                }
+            }
+            .into_actor(self),
+        );
+    }
 
-         rx.await.map_err(|_canceled| {
-             InternalError::Actor("Command result channel cancelled".to_string())
-         })?
+    // Enables `Fetch` for the patterns of all routes, or disables it when there are none.
+    fn sync_fetch_patterns(&self) -> impl Future<Output = Result<Value, InternalError>> + 'static {
+        if self.routes.is_empty() {
+            return self.send_page_command("Fetch.disable".to_string(), json!({}));
+        }
+        let patterns = fetch::request_patterns(self.routes.values().map(|route| route.pattern.as_str()));
+        self.send_page_command(
+            "Fetch.enable".to_string(),
+            json!(FetchEnableParams { patterns }),
+        )
     }
 
-    // Helper to subscribe to page-specific events
-    fn subscribe_to_page_event(&self, event_name: &str, addr: Recipient<ProtocolEvent>) {
-        debug!("PageActor {} subscribing to {}", self.target_id, event_name);
-         if self.event_actor.do_send(Subscribe {
-             event_name: event_name.to_string(),
-             session_id: Some(self.session_id.clone()), // Session-specific subscription
-             subscriber: addr,
-         }).is_err() {
-              error!("Failed to send Subscribe message to EventActor for page {}.", self.target_id);
-          }
+    // Lets the newest matching route decide on a paused request; unmatched requests continue.
+    fn handle_request_paused(&mut self, params: Value, ctx: &mut Context<Self>) {
+        let params: RequestPausedParams = match serde_json::from_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                warn!("PageActor {}: unparsable Fetch.requestPaused: {}", self.target_id, e);
+                return;
+            }
+        };
+        let request = fetch::intercepted_request_from_cdp(&params);
+        let action = self
+            .routes
+            .iter()
+            .filter(|(_, route)| fetch::url_pattern_matches(&route.pattern, &request.url))
+            .max_by_key(|(id, _)| *id)
+            .map(|(_, route)| (route.handler)(request))
+            .unwrap_or_else(|| RouteAction::Continue(ContinueOverrides::default()));
+        debug!("PageActor {}: request {} -> {:?}", self.target_id, params.request_id, action);
+        let (method, params) = fetch::route_action_command(params.request_id, action);
+        let future = self.send_page_command(method.to_string(), params);
+        let target_id = self.target_id.clone();
+        ctx.spawn(
+            async move {
+                if let Err(e) = future.await {
+                    // The request may be gone already, e.g. when the page navigated away.
+                    warn!("PageActor {}: {} failed: {}", target_id, method, e);
+                }
+            }
+            .into_actor(self),
+        );
     }
-}
 
-impl Actor for ChromePageActor {
-    type Context = Context<Self>;
+    // Runs the exposed function of a binding call, and settles the caller's promise
+    // with its result. Calls of bindings added by others are ignored.
+    fn handle_binding_called(&mut self, params: Value, ctx: &mut Context<Self>) {
+        let params: BindingCalledParams = match serde_json::from_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                warn!("PageActor {}: unparsable Runtime.bindingCalled: {}", self.target_id, e);
+                return;
+            }
+        };
+        let Some(function) = self.bindings.get(&params.name) else {
+            return;
+        };
+        let Some(call) = bindings::parse_binding_call(&params.payload) else {
+            warn!("PageActor {}: unexpected payload for binding {}", self.target_id, params.name);
+            return;
+        };
+        let future = function(call.args);
+        let command_actor = self.command_actor.clone();
+        let session_id = Some(self.session_id.clone());
+        let target_id = self.target_id.clone();
+        ctx.spawn(
+            async move {
+                let result = future.await;
+                let params = CallFunctionOnParams {
+                    function_declaration: bindings::DELIVER_RESULT,
+                    object_id: None,
+                    execution_context_id: Some(params.execution_context_id),
+                    arguments: bindings::deliver_arguments(&params.name, call.seq, result),
+                    return_by_value: None,
+                    await_promise: None,
+                };
+                let params = serde_json::to_value(params).unwrap();
+                if let Err(e) = execute_command(command_actor, session_id, "Runtime.callFunctionOn".to_string(), params).await {
+                    // The calling document may be gone by now.
+                    debug!("PageActor {}: could not deliver binding result: {}", target_id, e);
+                }
+            }
+            .into_actor(self),
+        );
+    }
 
-    fn started(&mut self, ctx: &mut Context<Self>) {
-        info!(
-            "ChromePageActor started for target {}, session {}.",
-            self.target_id, self.session_id
+    // Sends `Page.navigate`; an `errorText` in the reply (e.g. `net::ERR_NAME_NOT_RESOLVED`)
+    // fails the navigation.
+    fn navigate_command(
+        &self,
+        url: String,
+    ) -> impl Future<Output = Result<NavigateResult, InternalError>> + 'static {
+        let future = self.send_page_command(
+            "Page.navigate".to_string(),
+            json!(NavigateParams { url: &url }),
         );
-        self.state = PageActorState::Idle; // Assume idle after start
+        async move {
+            let result: NavigateResult = serde_json::from_value(future.await?)?;
+            match result.error_text.as_deref() {
+                Some(error_text) if !error_text.is_empty() => Err(InternalError::Navigation {
+                    url,
+                    reason: error_text.to_string(),
+                }),
+                _ => Ok(result),
+            }
+        }
+    }
 
-        // Subscribe to relevant events for this page
-        let self_recipient = ctx.address().recipient();
-        self.subscribe_to_page_event("Page.lifecycleEvent", self_recipient.clone());
-        self.subscribe_to_page_event("Runtime.consoleAPICalled", self_recipient.clone());
-        // Add more subscriptions later (DOM.*, Network.*)
+    fn track_main_frame_lifecycle(&mut self, params: &Value) {
+        let field = |name: &str| params.get(name).and_then(Value::as_str);
+        let (Some(frame_id), Some(loader_id), Some(name)) =
+            (field("frameId"), field("loaderId"), field("name"))
+        else {
+            return;
+        };
+        if frame_id != self.target_id {
+            return;
+        }
+        if let Some(har) = &mut self.har
+            && let Some(timestamp) = params.get("timestamp").and_then(Value::as_f64)
+        {
+            har.lifecycle_event(loader_id, name, timestamp);
+        }
+        let new_document = self.main_frame.record_lifecycle_event(loader_id, name);
+        self.resolve_navigations(new_document);
     }
 
-    fn stopping(&mut self, _ctx: &mut Context<Self>) -> Running {
-        info!(
-            "ChromePageActor stopping for target {}, session {}.",
-            self.target_id, self.session_id
+    // Tracks main frame document responses for navigations, records the response in
+    // the HAR and reports it.
+    fn handle_response_received(&mut self, params: Value) {
+        let params: ResponseReceivedParams = match serde_json::from_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                warn!("PageActor {}: unparsable Network.responseReceived: {}", self.target_id, e);
+                return;
+            }
+        };
+        if let Some(har) = &mut self.har {
+            har.response_received(&params);
+        }
+        if params.type_ == "Document" && params.frame_id.as_deref() == Some(self.target_id.as_str()) {
+            self.main_frame.record_document_response(DocumentResponse {
+                loader_id: params.loader_id.clone(),
+                url: params.response.url.clone(),
+                status: u16::try_from(params.response.status).ok(),
+            });
+        }
+        let response = events::network_response_from_cdp(
+            &params.request_id,
+            Some(params.type_),
+            params.timestamp,
+            &params.response,
         );
-        self.state = PageActorState::Closed;
-        // TODO: Unsubscribe?
-        Running::Stop
+        for handler in self.event_handlers.values() {
+            if let PageEventHandler::Response(handler) = handler {
+                handler(response.clone());
+            }
+        }
     }
-}
 
-// --- Page Actor Message Handlers ---
+    // Completes parked navigations whose loader reached the awaited event. When a new
+    // document was committed, navigations of other loaders were superseded and fail.
+    fn resolve_navigations(&mut self, new_document: bool) {
+        for waiter in std::mem::take(&mut self.pending_navigations) {
+            if waiter.tx.is_canceled() {
+                continue; // Timed out
+            }
+            if self.main_frame.has_reached(&waiter.loader_id, waiter.event_name) {
+                let result = self.main_frame.navigation_result(&waiter.loader_id, &waiter.url);
+                let _ = waiter.tx.send(Ok(result));
+            } else if new_document && self.main_frame.current_loader() != Some(waiter.loader_id.as_str()) {
+                let _ = waiter.tx.send(Err(InternalError::Navigation {
+                    url: waiter.url,
+                    reason: "interrupted by another navigation".to_string(),
+                }));
+            } else {
+                self.pending_navigations.push(waiter);
+            }
+        }
+    }
 
-impl Handler<Navigate> for ChromePageActor {
-    type Result = ResponseFuture<Result<(), InternalError>>;
+    // Helper to send a command *for this page's session*
+    fn send_page_command(
+        &self,
+        method: String,
+        params: Value,
+    ) -> impl Future<Output = Result<Value, InternalError>> + 'static {
+        self.send_session_command(None, method, params)
+    }
 
-    fn handle(&mut self, msg: Navigate, _ctx: &mut Context<Self>) -> Self::Result {
-        self.state = PageActorState::Navigating; // Update state
-        let params = NavigateParams { url: &msg.url };
-        let future = self.send_page_command(
-            "Page.navigate".to_string(),
-            serde_json::to_value(params).unwrap(), // Handle serde error better later
-        );
+    // Sends a command to the session of an out-of-process frame, or to the page's own.
+    fn send_session_command(
+        &self,
+        session_id: Option<&str>,
+        method: String,
+        params: Value,
+    ) -> impl Future<Output = Result<Value, InternalError>> + 'static {
+        execute_command(
+            self.command_actor.clone(),
+            Some(session_id.unwrap_or(&self.session_id).to_string()),
+            method,
+            params,
+        )
+    }
+
+    // Sends a command whose result carries no data (`Input.*`, `Runtime.releaseObject`, ...).
+    fn send_void_command(
+        &self,
+        method: &str,
+        params: impl serde::Serialize,
+    ) -> ResponseFuture<Result<(), InternalError>> {
+        self.send_void_session_command(None, method, params)
+    }
 
+    fn send_void_session_command(
+        &self,
+        session_id: Option<&str>,
+        method: &str,
+        params: impl serde::Serialize,
+    ) -> ResponseFuture<Result<(), InternalError>> {
+        let params = match serde_json::to_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                let err = InternalError::Serialization(e.to_string());
+                return Box::pin(async move { Err(err) });
+            }
+        };
+        let future = self.send_session_command(session_id, method.to_string(), params);
         Box::pin(async move {
-            let result = future.await;
-            // TODO: Update state based on result / lifecycle events
-            // self.state = PageActorState::Idle; // Simplistic update for now
-            result?; // Propagate error
+            future.await?;
             Ok(())
         })
     }
-}
-
-impl Handler<EvaluateScript> for ChromePageActor {
-    type Result = ResponseFuture<Result<Value, InternalError>>;
 
-    fn handle(&mut self, msg: EvaluateScript, _ctx: &mut Context<Self>) -> Self::Result {
-         self.state = PageActorState::Evaluating;
-         let params = EvaluateParams {
-             expression: &msg.script,
-             context_id: None,
-             return_by_value: Some(true), // Attempt to get simple values directly
-             await_promise: Some(true),   // Await promises by default
-         };
-        let future = self.send_page_command(
-            "Runtime.evaluate".to_string(),
-            serde_json::to_value(params).unwrap(),
+    // Enables the CDP domains whose events back the L1 subscriptions.
+    fn enable_page_events(&self, ctx: &mut Context<Self>) {
+        let commands = [
+            self.send_page_command("Page.enable".to_string(), json!({})),
+            self.send_page_command(
+                "Page.setLifecycleEventsEnabled".to_string(),
+                json!({ "enabled": true }),
+            ),
+            self.send_page_command("Runtime.enable".to_string(), json!({})),
+            self.send_page_command("Network.enable".to_string(), json!({})),
+        ];
+        let target_id = self.target_id.clone();
+        ctx.spawn(
+            async move {
+                for command in commands {
+                    if let Err(e) = command.await {
+                        warn!("Failed to enable page events for {}: {}", target_id, e);
+                    }
+                }
+            }
+            .into_actor(self),
         );
+    }
 
-        Box::pin(async move {
-            // self.state = PageActorState::Idle; // Update state later
-            let result_value = future.await?;
-            let eval_result: EvaluateResult = serde_json::from_value(result_value)
-                 .map_err(|e| InternalError::Deserialization(format!("Failed to parse EvaluateResult: {}", e)))?;
-
-            if let Some(exception_details) = eval_result.exception_details {
-                 Err(InternalError::Protocol {
-                     code: None, // CDP exceptions don't always have codes here
-                     message: format!("Script evaluation failed: {}", exception_details.text),
-                     data: Some(serde_json::to_string(&exception_details).unwrap_or_default()),
-                 })
-             } else {
-                 Ok(eval_result.result.value) // Return the evaluated value
-             }
-        })
+    fn dispatch_console_message(&self, params: Value) {
+        let params: ConsoleApiCalledParams = match serde_json::from_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                warn!("PageActor {}: unparsable Runtime.consoleAPICalled: {}", self.target_id, e);
+                return;
+            }
+        };
+        let message = events::console_message_from_cdp(&params);
+        for handler in self.event_handlers.values() {
+            if let PageEventHandler::Console(handler) = handler {
+                handler(message.clone());
+            }
+        }
     }
-}
 
-impl Handler<ClosePage> for ChromePageActor {
-     type Result = ResponseFuture<Result<(), InternalError>>;
+    fn dispatch_lifecycle_event(&self, params: Value) {
+        let params: LifecycleEventParams = match serde_json::from_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                warn!("PageActor {}: unparsable Page.lifecycleEvent: {}", self.target_id, e);
+                return;
+            }
+        };
+        let event = events::lifecycle_event_from_cdp(params);
+        for handler in self.event_handlers.values() {
+            if let PageEventHandler::Lifecycle(handler) = handler {
+                handler(event.clone());
+            }
+        }
+    }
 
-     fn handle(&mut self, _msg: ClosePage, ctx: &mut Context<Self>) -> Self::Result {
-         info!("ClosePage message received for target {}", self.target_id);
-         self.state = PageActorState::Closing;
+    fn dispatch_page_error(&self, params: Value) {
+        let params: ExceptionThrownParams = match serde_json::from_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                warn!("PageActor {}: unparsable Runtime.exceptionThrown: {}", self.target_id, e);
+                return;
+            }
+        };
+        let error = events::page_error_from_cdp(&params.exception_details);
+        for handler in self.event_handlers.values() {
+            if let PageEventHandler::PageError(handler) = handler {
+                handler(error.clone());
+            }
+        }
+    }
 
-        // Send Target.closeTarget command (Browser-level command)
-        let command_actor = self.command_actor.clone();
-        let target_id = self.target_id.clone();
-        let actor_addr = ctx.address(); // Address to stop self
+    fn dispatch_dialog(&self, params: Value, ctx: &mut Context<Self>) {
+        let params: JavascriptDialogOpeningParams = match serde_json::from_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                warn!("PageActor {}: unparsable Page.javascriptDialogOpening: {}", self.target_id, e);
+                return;
+            }
+        };
+        let known_type = events::dialog_type_from_cdp(&params.type_).is_some();
+        if !known_type {
+            // It blocks the page all the same, so the policy answers it.
+            warn!("PageActor {}: unknown dialog type {}", self.target_id, params.type_);
+        }
+        let dialog = events::dialog_info_from_cdp(params);
+        let mut subscribed = false;
+        for handler in self.event_handlers.values().filter(|_| known_type) {
+            if let PageEventHandler::Dialog(handler) = handler {
+                handler(dialog.clone());
+                subscribed = true;
+            }
+        }
+        if subscribed {
+            return; // Answered through `Page::accept_dialog` / `Page::dismiss_dialog`
+        }
 
-         Box::pin(async move {
-            let params = json!({ "targetId": target_id });
-             let (tx, rx) = oneshot::channel();
-             let command = SendCommand {
+        let accept = match self.dialog_policy {
+            DialogPolicy::Accept => true,
+            DialogPolicy::Dismiss => false,
+            DialogPolicy::Error => {
+                let error = events::unexpected_dialog_error(&dialog);
+                for handler in self.event_handlers.values() {
+                    if let PageEventHandler::PageError(handler) = handler {
+                        handler(error.clone());
+                    }
+                }
+                false
+            }
+        };
+        debug!(
+            "PageActor {}: answering {:?} dialog by {:?} policy",
+            self.target_id, dialog.dialog_type, self.dialog_policy
+        );
+        let params = HandleJavaScriptDialogParams {
+            accept,
+            prompt_text: dialog.default_prompt.filter(|_| accept),
+        };
+        let future = self.send_void_command("Page.handleJavaScriptDialog", params);
+        ctx.spawn(future.into_actor(self).map(|result, act, _ctx| {
+            if let Err(e) = result {
+                warn!("PageActor {}: failed to answer dialog: {}", act.target_id, e);
+            }
+        }));
+    }
+
+    fn handle_file_chooser_opened(&mut self, params: Value) {
+        let params: FileChooserOpenedParams = match serde_json::from_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                warn!("PageActor {}: unparsable Page.fileChooserOpened: {}", self.target_id, e);
+                return;
+            }
+        };
+        let Some(backend_node_id) = params.backend_node_id else {
+            debug!("PageActor {}: ignoring file chooser without an input element", self.target_id);
+            return;
+        };
+        let id = self.next_file_chooser_id;
+        self.next_file_chooser_id += 1;
+        self.file_choosers.insert(id, backend_node_id);
+        let chooser = FileChooser {
+            id,
+            multiple: params.mode == "selectMultiple",
+            frame_id: params.frame_id,
+        };
+        for handler in self.event_handlers.values() {
+            if let PageEventHandler::FileChooser(handler) = handler {
+                handler(chooser.clone());
+            }
+        }
+    }
+
+    // Turns file chooser interception on or off to match the registered handlers.
+    fn sync_file_chooser_interception(&mut self) -> ResponseFuture<Result<(), InternalError>> {
+        let enabled = self
+            .event_handlers
+            .values()
+            .any(|handler| matches!(handler, PageEventHandler::FileChooser(_)));
+        if enabled == self.intercept_file_chooser {
+            return Box::pin(async { Ok(()) });
+        }
+        self.intercept_file_chooser = enabled;
+        self.send_void_command(
+            "Page.setInterceptFileChooserDialog",
+            SetInterceptFileChooserDialogParams { enabled },
+        )
+    }
+
+    fn handle_request_will_be_sent(&mut self, params: Value) {
+        let params: RequestWillBeSentParams = match serde_json::from_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                warn!("PageActor {}: unparsable Network.requestWillBeSent: {}", self.target_id, e);
+                return;
+            }
+        };
+        if let Some(har) = &mut self.har {
+            har.request_will_be_sent(&params);
+        }
+        let request = events::network_request_from_cdp(&params);
+        for handler in self.event_handlers.values() {
+            if let PageEventHandler::Request(handler) = handler {
+                handler(request.clone());
+            }
+        }
+    }
+
+    fn handle_loading_finished(&mut self, params: Value, ctx: &mut Context<Self>) {
+        let params: LoadingFinishedParams = match serde_json::from_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                warn!("PageActor {}: unparsable Network.loadingFinished: {}", self.target_id, e);
+                return;
+            }
+        };
+        if let Some(har) = &mut self.har
+            && har.loading_finished(&params)
+        {
+            self.record_har_body(params.request_id.clone(), ctx);
+        }
+        let finished = events::request_finished_from_cdp(params);
+        for handler in self.event_handlers.values() {
+            if let PageEventHandler::RequestFinished(handler) = handler {
+                handler(finished.clone());
+            }
+        }
+    }
+
+    fn handle_loading_failed(&mut self, params: Value) {
+        let params: LoadingFailedParams = match serde_json::from_value(params) {
+            Ok(params) => params,
+            Err(e) => {
+                warn!("PageActor {}: unparsable Network.loadingFailed: {}", self.target_id, e);
+                return;
+            }
+        };
+        if let Some(har) = &mut self.har {
+            har.loading_failed(&params);
+        }
+        let failed = events::request_failed_from_cdp(params);
+        for handler in self.event_handlers.values() {
+            if let PageEventHandler::RequestFailed(handler) = handler {
+                handler(failed.clone());
+            }
+        }
+    }
+
+    // Reads a finished response body into the HAR while the browser still holds it.
+    // Bodies missed here are read again when the recording stops.
+    fn record_har_body(&self, request_id: String, ctx: &mut Context<Self>) {
+        let params = GetResponseBodyParams { request_id: &request_id };
+        let future = self.send_page_command(
+            "Network.getResponseBody".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        ctx.spawn(future.into_actor(self).map(move |result, act, _ctx| {
+            match result.and_then(decode_response_body) {
+                Ok(body) => {
+                    if let Some(har) = &mut act.har {
+                        har.set_body(&request_id, body);
+                    }
+                }
+                Err(e) => debug!("PageActor {}: no body for request {}: {}", act.target_id, request_id, e),
+            }
+        }));
+    }
+
+    // Fetches the frame tree of a session (None for the page's own) into `frames`.
+    fn load_frame_tree(&self, session_id: Option<String>, ctx: &mut Context<Self>) {
+        let future = self.send_session_command(session_id.as_deref(), "Page.getFrameTree".to_string(), json!({}));
+        ctx.spawn(future.into_actor(self).map(move |result, act, _ctx| {
+            match result.and_then(|value| Ok(serde_json::from_value::<GetFrameTreeResult>(value)?)) {
+                Ok(result) => act.frames.load_tree(&result.frame_tree, session_id.as_deref()),
+                Err(e) => warn!("PageActor {}: failed to get frame tree ({:?}): {}", act.target_id, session_id, e),
+            }
+        }));
+    }
+
+    // Updates the frame tree from `FRAME_EVENTS` of the page's session or an iframe's.
+    fn handle_frame_event(&mut self, method: &str, params: Value, session_id: Option<&str>) {
+        let parsed = match method {
+            "Page.frameAttached" => serde_json::from_value::<FrameAttachedParams>(params)
+                .map(|params| self.frames.attached(&params.frame_id, &params.parent_frame_id, session_id)),
+            "Page.frameNavigated" => serde_json::from_value::<FrameNavigatedParams>(params)
+                .map(|params| self.frames.navigated(&params.frame, session_id)),
+            "Page.frameDetached" => serde_json::from_value::<FrameDetachedParams>(params).map(|params| {
+                self.frames.detached(&params.frame_id, params.reason.as_deref() == Some("swap"))
+            }),
+            "Runtime.executionContextCreated" => serde_json::from_value::<ExecutionContextCreatedParams>(params)
+                .map(|params| self.frames.context_created(&params.context, session_id)),
+            "Runtime.executionContextDestroyed" => serde_json::from_value::<ExecutionContextDestroyedParams>(params)
+                .map(|params| self.frames.context_destroyed(params.execution_context_id, session_id)),
+            "Runtime.executionContextsCleared" => {
+                self.frames.contexts_cleared(session_id);
+                Ok(())
+            }
+            _ => Ok(()),
+        };
+        if let Err(e) = parsed {
+            warn!("PageActor {}: unparsable {}: {}", self.target_id, method, e);
+        }
+    }
+
+    // Helper to subscribe to page-specific events
+    fn subscribe_to_page_event(&self, event_name: &str, addr: Recipient<ProtocolEvent>) {
+        self.subscribe_to_session_event(event_name, self.session_id.clone(), addr);
+    }
+
+    fn subscribe_to_session_event(&self, event_name: &str, session_id: String, addr: Recipient<ProtocolEvent>) {
+        debug!("PageActor {} subscribing to {} (session: {})", self.target_id, event_name, session_id);
+         if self.event_actor.do_send(Subscribe {
+             event_name: event_name.to_string(),
+             session_id: Some(session_id), // Session-specific subscription
+             subscriber: addr,
+         }).is_err() {
+              error!("Failed to send Subscribe message to EventActor for page {}.", self.target_id);
+          }
+    }
+}
+
+impl Actor for ChromePageActor {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Context<Self>) {
+        info!(
+            "ChromePageActor started for target {}, session {}.",
+            self.target_id, self.session_id
+        );
+        self.state = PageActorState::Idle; // Assume idle after start
+
+        // Subscribe to relevant events for this page
+        let self_recipient = ctx.address().recipient();
+        self.subscribe_to_page_event("Page.lifecycleEvent", self_recipient.clone());
+        self.subscribe_to_page_event("Runtime.consoleAPICalled", self_recipient.clone());
+        self.subscribe_to_page_event("Runtime.exceptionThrown", self_recipient.clone());
+        self.subscribe_to_page_event("Page.javascriptDialogOpening", self_recipient.clone());
+        self.subscribe_to_page_event("Page.fileChooserOpened", self_recipient.clone());
+        self.subscribe_to_page_event("Network.requestWillBeSent", self_recipient.clone());
+        self.subscribe_to_page_event("Network.responseReceived", self_recipient.clone());
+        self.subscribe_to_page_event("Network.loadingFinished", self_recipient.clone());
+        self.subscribe_to_page_event("Network.loadingFailed", self_recipient.clone());
+        self.subscribe_to_page_event("Fetch.requestPaused", self_recipient.clone());
+        self.subscribe_to_page_event("Runtime.bindingCalled", self_recipient.clone());
+        for event_name in FRAME_EVENTS {
+            self.subscribe_to_page_event(event_name, self_recipient.clone());
+        }
+        // Add more subscriptions later (DOM.*)
+
+        self.enable_page_events(ctx);
+        self.load_frame_tree(None, ctx);
+        self.apply_context_emulation(ctx);
+    }
+
+    fn stopping(&mut self, _ctx: &mut Context<Self>) -> Running {
+        info!(
+            "ChromePageActor stopping for target {}, session {}.",
+            self.target_id, self.session_id
+        );
+        self.state = PageActorState::Closed;
+        self.event_handlers.clear();
+        self.pending_navigations.clear(); // Waiters see a canceled channel
+        self.routes.clear();
+        self.har = None;
+        // TODO: Unsubscribe?
+        Running::Stop
+    }
+}
+
+// --- Page Actor Message Handlers ---
+
+impl Handler<Navigate> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: Navigate, _ctx: &mut Context<Self>) -> Self::Result {
+        self.state = PageActorState::Navigating; // Update state
+        let future = self.navigate_command(msg.url);
+
+        Box::pin(async move {
+            future.await?; // Propagate error
+            Ok(())
+        })
+    }
+}
+
+impl Handler<NavigateAndWait> for ChromePageActor {
+    type Result = ResponseFuture<Result<NavigationResult, InternalError>>;
+
+    fn handle(&mut self, msg: NavigateAndWait, ctx: &mut Context<Self>) -> Self::Result {
+        self.state = PageActorState::Navigating;
+        let event_name = navigation::lifecycle_event_name(msg.wait_until);
+        let navigate = self.navigate_command(msg.url.clone());
+        let self_addr = ctx.address();
+        let url = msg.url;
+        let timeout = msg.timeout;
+
+        Box::pin(async move {
+            let navigation = async {
+                let result = navigate.await?;
+                let Some(loader_id) = result.loader_id else {
+                    // Same-document navigation (e.g. a fragment change): nothing to load.
+                    return Ok(NavigationResult { loader_id: None, url: url.clone(), status: None });
+                };
+                let (tx, rx) = oneshot::channel();
+                self_addr
+                    .send(AwaitNavigation { url: url.clone(), loader_id, event_name, tx })
+                    .await
+                    .map_err(|e| InternalError::Actor(format!("Mailbox error awaiting navigation: {}", e)))?;
+                rx.await.unwrap_or_else(|_| {
+                    Err(InternalError::Navigation {
+                        url: url.clone(),
+                        reason: "page closed before the navigation finished".to_string(),
+                    })
+                })
+            };
+            let outcome = tokio::time::timeout(timeout, navigation).await;
+            outcome.unwrap_or_else(|_| {
+                Err(InternalError::Navigation {
+                    url,
+                    reason: format!("timeout of {} ms exceeded waiting for `{}`", timeout.as_millis(), event_name),
+                })
+            })
+        })
+    }
+}
+
+impl Handler<AwaitNavigation> for ChromePageActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: AwaitNavigation, _ctx: &mut Context<Self>) {
+        // The awaited event may have arrived before `Page.navigate` replied.
+        self.pending_navigations.push(msg);
+        self.resolve_navigations(false);
+    }
+}
+
+impl Handler<EvaluateScript> for ChromePageActor {
+    type Result = ResponseFuture<Result<Value, InternalError>>;
+
+    fn handle(&mut self, msg: EvaluateScript, _ctx: &mut Context<Self>) -> Self::Result {
+         self.state = PageActorState::Evaluating;
+         let params = EvaluateParams {
+             expression: &msg.script,
+             context_id: None,
+             return_by_value: Some(true), // Attempt to get simple values directly
+             await_promise: Some(true),   // Await promises by default
+         };
+        let future = self.send_page_command(
+            "Runtime.evaluate".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+
+        Box::pin(async move {
+            // self.state = PageActorState::Idle; // Update state later
+            let result_value = future.await?;
+            Ok(parse_evaluate_result(result_value)?.value) // Return the evaluated value
+        })
+    }
+}
+
+// Parses a Runtime.evaluate / Runtime.callFunctionOn result, turning thrown exceptions into errors.
+fn parse_evaluate_result(result_value: Value) -> Result<RemoteObject, InternalError> {
+    let eval_result: EvaluateResult = serde_json::from_value(result_value)
+         .map_err(|e| InternalError::Deserialization(format!("Failed to parse EvaluateResult: {}", e)))?;
+
+    match eval_result.exception_details {
+        Some(exception_details) => Err(evaluation_error(&exception_details)),
+        None => Ok(eval_result.result),
+    }
+}
+
+fn evaluation_error(exception_details: &ExceptionDetails) -> InternalError {
+    let error = events::page_error_from_cdp(exception_details);
+    InternalError::Evaluation {
+        message: error.message,
+        url: error.url,
+        line_number: error.line_number,
+        column_number: error.column_number,
+        stack: error.stack,
+    }
+}
+
+impl Handler<EvaluateHandle> for ChromePageActor {
+    type Result = ResponseFuture<Result<RemoteObject, InternalError>>;
+
+    fn handle(&mut self, msg: EvaluateHandle, _ctx: &mut Context<Self>) -> Self::Result {
+        let params = EvaluateParams {
+            expression: &msg.script,
+            context_id: None,
+            return_by_value: Some(false),
+            await_promise: Some(true),
+        };
+        let future = self.send_page_command(
+            "Runtime.evaluate".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        Box::pin(async move { parse_evaluate_result(future.await?) })
+    }
+}
+
+impl Handler<CallFunctionOn> for ChromePageActor {
+    type Result = ResponseFuture<Result<RemoteObject, InternalError>>;
+
+    fn handle(&mut self, msg: CallFunctionOn, _ctx: &mut Context<Self>) -> Self::Result {
+        let command_actor = self.command_actor.clone();
+        let session_id = Some(msg.session_id.clone().unwrap_or_else(|| self.session_id.clone()));
+        let context_id = match msg.object_id {
+            Some(_) => None,
+            None => self.frames.execution_target(&self.target_id).ok().and_then(|(_, context_id)| context_id),
+        };
+        Box::pin(async move {
+            // Until the main world's context is known, the global object is bound as `this`.
+            let global_id = if msg.object_id.is_none() && context_id.is_none() {
+                let params = EvaluateParams {
+                    expression: "globalThis",
+                    context_id: None,
+                    return_by_value: Some(false),
+                    await_promise: None,
+                };
+                let result = execute_command(
+                    command_actor.clone(),
+                    session_id.clone(),
+                    "Runtime.evaluate".to_string(),
+                    serde_json::to_value(params).unwrap(),
+                )
+                .await?;
+                parse_evaluate_result(result)?.object_id
+            } else {
+                None
+            };
+            let params = CallFunctionOnParams {
+                function_declaration: &msg.function_declaration,
+                object_id: msg.object_id.as_deref().or(global_id.as_deref()),
+                execution_context_id: context_id,
+                arguments: msg.args,
+                return_by_value: Some(msg.return_by_value),
+                await_promise: Some(true),
+            };
+            let result = execute_command(
+                command_actor.clone(),
+                session_id.clone(),
+                "Runtime.callFunctionOn".to_string(),
+                serde_json::to_value(params).unwrap(),
+            )
+            .await;
+            if let Some(object_id) = global_id {
+                let params = serde_json::to_value(ObjectIdParams { object_id: &object_id }).unwrap();
+                if let Err(e) = execute_command(command_actor, session_id, "Runtime.releaseObject".to_string(), params).await {
+                    debug!("Failed to release global object: {}", e);
+                }
+            }
+            parse_evaluate_result(result?)
+        })
+    }
+}
+
+impl Handler<GetProperties> for ChromePageActor {
+    type Result = ResponseFuture<Result<Vec<PropertyDescriptor>, InternalError>>;
+
+    fn handle(&mut self, msg: GetProperties, _ctx: &mut Context<Self>) -> Self::Result {
+        let params = GetPropertiesParams { object_id: &msg.object_id, own_properties: true };
+        let future = self.send_session_command(
+            msg.session_id.as_deref(),
+            "Runtime.getProperties".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        Box::pin(async move {
+            let result: GetPropertiesResult = serde_json::from_value(future.await?)
+                .map_err(|e| InternalError::Deserialization(format!("Failed to parse GetPropertiesResult: {}", e)))?;
+            match result.exception_details {
+                Some(exception_details) => Err(evaluation_error(&exception_details)),
+                None => Ok(result.result),
+            }
+        })
+    }
+}
+
+impl Handler<DescribeNode> for ChromePageActor {
+    type Result = ResponseFuture<Result<i64, InternalError>>;
+
+    fn handle(&mut self, msg: DescribeNode, _ctx: &mut Context<Self>) -> Self::Result {
+        let params = ObjectIdParams { object_id: &msg.object_id };
+        let future = self.send_session_command(
+            msg.session_id.as_deref(),
+            "DOM.describeNode".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        Box::pin(async move {
+            let result: DescribeNodeResult = serde_json::from_value(future.await?)
+                .map_err(|e| InternalError::Deserialization(format!("Failed to parse DescribeNodeResult: {}", e)))?;
+            Ok(result.node.backend_node_id)
+        })
+    }
+}
+
+impl Handler<GetFrames> for ChromePageActor {
+    type Result = MessageResult<GetFrames>;
+
+    fn handle(&mut self, _msg: GetFrames, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.frames.in_tree_order())
+    }
+}
+
+impl Handler<EvaluateInFrame> for ChromePageActor {
+    type Result = ResponseFuture<Result<(RemoteObject, Option<String>), InternalError>>;
+
+    fn handle(&mut self, msg: EvaluateInFrame, _ctx: &mut Context<Self>) -> Self::Result {
+        let target = match self.frames.execution_target(&msg.frame_id) {
+            // The main frame exists before the frame tree is loaded.
+            Err(_) if msg.frame_id == self.target_id && self.frames.get(&msg.frame_id).is_none() => Ok((None, None)),
+            target => target,
+        };
+        let (session_id, context_id) = match target {
+            Ok(target) => target,
+            Err(e) => return Box::pin(async move { Err(e) }),
+        };
+        let params = EvaluateParams {
+            expression: &msg.script,
+            context_id,
+            return_by_value: Some(msg.return_by_value),
+            await_promise: Some(true),
+        };
+        let future = self.send_session_command(
+            session_id.as_deref(),
+            "Runtime.evaluate".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        Box::pin(async move { Ok((parse_evaluate_result(future.await?)?, session_id)) })
+    }
+}
+
+impl Handler<AttachFrameSession> for ChromePageActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: AttachFrameSession, ctx: &mut Context<Self>) {
+        info!("PageActor {}: out-of-process frame attached, session {}", self.target_id, msg.session_id);
+        let self_recipient = ctx.address().recipient();
+        for event_name in FRAME_EVENTS {
+            self.subscribe_to_session_event(event_name, msg.session_id.clone(), self_recipient.clone());
+        }
+        self.frame_sessions.push(msg.session_id.clone());
+        let commands = [
+            self.send_session_command(Some(&msg.session_id), "Page.enable".to_string(), json!({})),
+            self.send_session_command(Some(&msg.session_id), "Runtime.enable".to_string(), json!({})),
+        ];
+        let session_id = msg.session_id.clone();
+        ctx.spawn(
+            async move {
+                for command in commands {
+                    if let Err(e) = command.await {
+                        warn!("Failed to enable frame events for session {}: {}", session_id, e);
+                    }
+                }
+            }
+            .into_actor(self),
+        );
+        self.load_frame_tree(Some(msg.session_id), ctx);
+    }
+}
+
+impl Handler<DetachFrameSession> for ChromePageActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: DetachFrameSession, ctx: &mut Context<Self>) {
+        debug!("PageActor {}: out-of-process frame detached, session {}", self.target_id, msg.session_id);
+        self.frame_sessions.retain(|session_id| *session_id != msg.session_id);
+        self.frames.session_detached(&msg.session_id);
+        let self_recipient: Recipient<ProtocolEvent> = ctx.address().recipient();
+        for event_name in FRAME_EVENTS {
+            self.event_actor.do_send(Unsubscribe {
+                event_name: event_name.to_string(),
+                session_id: Some(msg.session_id.clone()),
+                subscriber: self_recipient.clone(),
+            });
+        }
+    }
+}
+
+impl Handler<GetCookies> for ChromePageActor {
+    type Result = ResponseFuture<Result<Vec<Cookie>, InternalError>>;
+
+    fn handle(&mut self, msg: GetCookies, _ctx: &mut Context<Self>) -> Self::Result {
+        let params = GetCookiesParams { urls: msg.urls };
+        let future = self.send_page_command(
+            "Network.getCookies".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        Box::pin(async move {
+            let result: GetCookiesResult = serde_json::from_value(future.await?)
+                .map_err(|e| InternalError::Deserialization(format!("Failed to parse GetCookiesResult: {}", e)))?;
+            Ok(result.cookies)
+        })
+    }
+}
+
+impl Handler<SetCookies> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: SetCookies, _ctx: &mut Context<Self>) -> Self::Result {
+        self.send_void_command(
+            "Network.setCookies",
+            SetCookiesParams { cookies: msg.0, browser_context_id: None },
+        )
+    }
+}
+
+impl Handler<DeleteCookies> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: DeleteCookies, _ctx: &mut Context<Self>) -> Self::Result {
+        self.send_void_command("Network.deleteCookies", msg.0)
+    }
+}
+
+impl Handler<GetResponseBody> for ChromePageActor {
+    type Result = ResponseFuture<Result<Vec<u8>, InternalError>>;
+
+    fn handle(&mut self, msg: GetResponseBody, _ctx: &mut Context<Self>) -> Self::Result {
+        let params = GetResponseBodyParams { request_id: &msg.request_id };
+        let future = self.send_page_command(
+            "Network.getResponseBody".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        Box::pin(async move { decode_response_body(future.await?) })
+    }
+}
+
+// Decodes the result of `Network.getResponseBody`.
+fn decode_response_body(result: Value) -> Result<Vec<u8>, InternalError> {
+    let result: GetResponseBodyResult = serde_json::from_value(result)
+        .map_err(|e| InternalError::Deserialization(format!("Failed to parse GetResponseBodyResult: {}", e)))?;
+    if result.base64_encoded {
+        BASE64_STANDARD
+            .decode(result.body)
+            .map_err(|e| InternalError::Deserialization(format!("Invalid base64 response body: {}", e)))
+    } else {
+        Ok(result.body.into_bytes())
+    }
+}
+
+impl Handler<StartHarRecording> for ChromePageActor {
+    type Result = bool;
+
+    fn handle(&mut self, msg: StartHarRecording, _ctx: &mut Context<Self>) -> Self::Result {
+        if self.har.is_some() {
+            return false;
+        }
+        debug!("PageActor {}: HAR recording started", self.target_id);
+        self.har = Some(HarRecorder::new(msg.0, self.target_id.clone()));
+        true
+    }
+}
+
+impl Handler<StopHarRecording> for ChromePageActor {
+    type Result = MessageResult<StopHarRecording>;
+
+    fn handle(&mut self, _msg: StopHarRecording, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.har.take())
+    }
+}
+
+impl Handler<CaptureScreenshot> for ChromePageActor {
+    type Result = ResponseFuture<Result<Vec<u8>, InternalError>>;
+
+    fn handle(&mut self, msg: CaptureScreenshot, _ctx: &mut Context<Self>) -> Self::Result {
+        let future = self.send_page_command(
+            "Page.captureScreenshot".to_string(),
+            serde_json::to_value(msg.0).unwrap(),
+        );
+        Box::pin(async move {
+            let result: CaptureScreenshotResult = serde_json::from_value(future.await?)
+                .map_err(|e| InternalError::Deserialization(format!("Failed to parse CaptureScreenshotResult: {}", e)))?;
+            BASE64_STANDARD
+                .decode(result.data)
+                .map_err(|e| InternalError::Deserialization(format!("Invalid base64 screenshot data: {}", e)))
+        })
+    }
+}
+
+impl Handler<GetLayoutMetrics> for ChromePageActor {
+    type Result = ResponseFuture<Result<LayoutMetrics, InternalError>>;
+
+    fn handle(&mut self, _msg: GetLayoutMetrics, _ctx: &mut Context<Self>) -> Self::Result {
+        let future = self.send_page_command("Page.getLayoutMetrics".to_string(), json!({}));
+        Box::pin(async move {
+            serde_json::from_value(future.await?)
+                .map_err(|e| InternalError::Deserialization(format!("Failed to parse LayoutMetrics: {}", e)))
+        })
+    }
+}
+
+impl Handler<PrintToPdf> for ChromePageActor {
+    type Result = ResponseFuture<Result<String, InternalError>>;
+
+    fn handle(&mut self, msg: PrintToPdf, _ctx: &mut Context<Self>) -> Self::Result {
+        let future = self.send_page_command(
+            "Page.printToPDF".to_string(),
+            serde_json::to_value(msg.0).unwrap(),
+        );
+        Box::pin(async move {
+            let result: PrintToPdfResult = serde_json::from_value(future.await?)
+                .map_err(|e| InternalError::Deserialization(format!("Failed to parse PrintToPdfResult: {}", e)))?;
+            result.stream.ok_or_else(|| {
+                InternalError::Deserialization("Page.printToPDF returned no stream handle".to_string())
+            })
+        })
+    }
+}
+
+impl Handler<ReadStream> for ChromePageActor {
+    type Result = ResponseFuture<Result<StreamChunk, InternalError>>;
+
+    fn handle(&mut self, msg: ReadStream, _ctx: &mut Context<Self>) -> Self::Result {
+        let params = IoReadParams { handle: &msg.handle, size: msg.size };
+        let future = self.send_page_command("IO.read".to_string(), serde_json::to_value(params).unwrap());
+        Box::pin(async move {
+            let result: IoReadResult = serde_json::from_value(future.await?)
+                .map_err(|e| InternalError::Deserialization(format!("Failed to parse IoReadResult: {}", e)))?;
+            let data = if result.base64_encoded {
+                BASE64_STANDARD
+                    .decode(result.data)
+                    .map_err(|e| InternalError::Deserialization(format!("Invalid base64 stream data: {}", e)))?
+            } else {
+                result.data.into_bytes()
+            };
+            Ok(StreamChunk { data, eof: result.eof })
+        })
+    }
+}
+
+impl Handler<CloseStream> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: CloseStream, _ctx: &mut Context<Self>) -> Self::Result {
+        self.send_void_command("IO.close", IoCloseParams { handle: &msg.handle })
+    }
+}
+
+impl Handler<GetBoxModel> for ChromePageActor {
+    type Result = ResponseFuture<Result<BoxModel, InternalError>>;
+
+    fn handle(&mut self, msg: GetBoxModel, _ctx: &mut Context<Self>) -> Self::Result {
+        let params = GetBoxModelParams { backend_node_id: msg.backend_node_id };
+        let future = self.send_page_command(
+            "DOM.getBoxModel".to_string(),
+            serde_json::to_value(params).unwrap(),
+        );
+        Box::pin(async move {
+            let result: GetBoxModelResult = serde_json::from_value(future.await?)
+                .map_err(|e| InternalError::Deserialization(format!("Failed to parse GetBoxModelResult: {}", e)))?;
+            Ok(result.model)
+        })
+    }
+}
+
+impl Handler<ReleaseObject> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: ReleaseObject, _ctx: &mut Context<Self>) -> Self::Result {
+        self.send_void_session_command(
+            msg.session_id.as_deref(),
+            "Runtime.releaseObject",
+            ObjectIdParams { object_id: &msg.object_id },
+        )
+    }
+}
+
+impl Handler<ClosePage> for ChromePageActor {
+     type Result = ResponseFuture<Result<(), InternalError>>;
+
+     fn handle(&mut self, _msg: ClosePage, ctx: &mut Context<Self>) -> Self::Result {
+         info!("ClosePage message received for target {}", self.target_id);
+         self.state = PageActorState::Closing;
+
+        // Send Target.closeTarget command (Browser-level command)
+        let command_actor = self.command_actor.clone();
+        let target_id = self.target_id.clone();
+        let actor_addr = ctx.address(); // Address to stop self
+
+         Box::pin(async move {
+            let params = json!({ "targetId": target_id });
+             let (tx, rx) = oneshot::channel();
+             let command = SendCommand {
                  session_id: None, // Browser-level command
                  method: "Target.closeTarget".to_string(),
                  params,
@@ -680,18 +2534,393 @@ impl Handler<ClosePage> for ChromePageActor {
 }
 
 
+impl Handler<MouseInput> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: MouseInput, _ctx: &mut Context<Self>) -> Self::Result {
+        let (type_, x, y, button, click_count, modifiers, delta) = match msg {
+            MouseInput::Move { x, y } => {
+                ("mouseMoved", x, y, self.input.held_button(), None, 0, None)
+            }
+            MouseInput::Down { x, y, options } => {
+                self.input.buttons |= input::mouse_button_bit(options.button);
+                let button = input::mouse_button_to_cdp(options.button);
+                let modifiers = input::modifiers_to_cdp(options.modifiers);
+                ("mousePressed", x, y, button, Some(options.click_count), modifiers, None)
+            }
+            MouseInput::Up { x, y, options } => {
+                self.input.buttons &= !input::mouse_button_bit(options.button);
+                let button = input::mouse_button_to_cdp(options.button);
+                let modifiers = input::modifiers_to_cdp(options.modifiers);
+                ("mouseReleased", x, y, button, Some(options.click_count), modifiers, None)
+            }
+            MouseInput::Wheel { x, y, delta_x, delta_y } => {
+                ("mouseWheel", x, y, "none", None, 0, Some((delta_x, delta_y)))
+            }
+        };
+        self.input.mouse_x = x;
+        self.input.mouse_y = y;
+        let params = DispatchMouseEventParams {
+            type_,
+            x,
+            y,
+            modifiers: self.input.modifiers | modifiers,
+            button,
+            buttons: self.input.buttons,
+            click_count,
+            delta_x: delta.map(|(dx, _)| dx),
+            delta_y: delta.map(|(_, dy)| dy),
+        };
+        self.send_void_command("Input.dispatchMouseEvent", params)
+    }
+}
+
+impl Handler<KeyInput> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: KeyInput, _ctx: &mut Context<Self>) -> Self::Result {
+        let (key, is_down) = match msg {
+            KeyInput::Down(key) => (key, true),
+            KeyInput::Up(key) => (key, false),
+        };
+        let shift = self.input.modifiers & input::MODIFIER_SHIFT != 0;
+        let Some(description) = input::describe_key(&key, shift) else {
+            return Box::pin(async move {
+                Err(InternalError::InvalidParams(format!("Unknown key: {}", key)))
+            });
+        };
+
+        let modifier = input::modifier_bit(&description.key);
+        let (type_, text) = if is_down {
+            self.input.modifiers |= modifier;
+            // Shortcuts such as Control+A must not insert text.
+            let text = description.text.filter(|_| !self.input.has_command_modifier());
+            (if text.is_some() { "keyDown" } else { "rawKeyDown" }, text)
+        } else {
+            self.input.modifiers &= !modifier;
+            ("keyUp", None)
+        };
+        let params = DispatchKeyEventParams {
+            type_,
+            modifiers: self.input.modifiers,
+            key: description.key,
+            code: description.code,
+            windows_virtual_key_code: description.key_code,
+            unmodified_text: text.clone(),
+            text,
+            location: description.location,
+            auto_repeat: false,
+        };
+        self.send_void_command("Input.dispatchKeyEvent", params)
+    }
+}
+
+impl Handler<InsertText> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: InsertText, _ctx: &mut Context<Self>) -> Self::Result {
+        self.send_void_command("Input.insertText", InsertTextParams { text: &msg.0 })
+    }
+}
+
+impl Handler<TouchTap> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: TouchTap, _ctx: &mut Context<Self>) -> Self::Result {
+        let start = self.send_void_command(
+            "Input.dispatchTouchEvent",
+            DispatchTouchEventParams {
+                type_: "touchStart",
+                touch_points: vec![TouchPoint { x: msg.x, y: msg.y }],
+                modifiers: self.input.modifiers,
+            },
+        );
+        let end = self.send_void_command(
+            "Input.dispatchTouchEvent",
+            DispatchTouchEventParams {
+                type_: "touchEnd",
+                touch_points: Vec::new(),
+                modifiers: self.input.modifiers,
+            },
+        );
+        Box::pin(async move {
+            start.await?;
+            end.await
+        })
+    }
+}
+
+impl Handler<RequestClose> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, _msg: RequestClose, _ctx: &mut Context<Self>) -> Self::Result {
+        // The actor stops once the target is destroyed (see `Target.targetDestroyed`).
+        self.send_void_command("Page.close", json!({}))
+    }
+}
+
+impl Handler<SetDialogPolicy> for ChromePageActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: SetDialogPolicy, _ctx: &mut Context<Self>) {
+        self.dialog_policy = msg.0;
+    }
+}
+
+impl Handler<HandleDialog> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: HandleDialog, _ctx: &mut Context<Self>) -> Self::Result {
+        self.send_void_command("Page.handleJavaScriptDialog", msg.0)
+    }
+}
+
+impl Handler<EmulateDevice> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: EmulateDevice, _ctx: &mut Context<Self>) -> Self::Result {
+        let overrides = msg.0;
+        let metrics = self.send_void_command("Emulation.setDeviceMetricsOverride", overrides.metrics);
+        let touch = self.send_void_command("Emulation.setTouchEmulationEnabled", overrides.touch);
+        let user_agent = self.send_void_command("Network.setUserAgentOverride", overrides.user_agent);
+        Box::pin(async move {
+            metrics.await?;
+            touch.await?;
+            user_agent.await
+        })
+    }
+}
+
+impl Handler<SetEmulation> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: SetEmulation, _ctx: &mut Context<Self>) -> Self::Result {
+        self.emulation_command(msg)
+    }
+}
+
+impl Handler<AddPageEventHandler> for ChromePageActor {
+    type Result = MessageResult<AddPageEventHandler>;
+
+    fn handle(&mut self, msg: AddPageEventHandler, _ctx: &mut Context<Self>) -> Self::Result {
+        let id = self.event_handlers.insert(msg.0);
+        debug!("PageActor {} registered event handler {}", self.target_id, id);
+        MessageResult(id)
+    }
+}
+
+impl Handler<RemovePageEventHandler> for ChromePageActor {
+    type Result = bool;
+
+    fn handle(&mut self, msg: RemovePageEventHandler, ctx: &mut Context<Self>) -> Self::Result {
+        let removed = self.event_handlers.remove(msg.0);
+        if removed {
+            ctx.spawn(self.sync_file_chooser_interception().into_actor(self).map(|result, act, _ctx| {
+                if let Err(e) = result {
+                    warn!("PageActor {}: failed to stop intercepting file choosers: {}", act.target_id, e);
+                }
+            }));
+        }
+        removed
+    }
+}
+
+impl Handler<SyncFileChooserInterception> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, _msg: SyncFileChooserInterception, _ctx: &mut Context<Self>) -> Self::Result {
+        self.sync_file_chooser_interception()
+    }
+}
+
+impl Handler<SetFileInputFiles> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: SetFileInputFiles, _ctx: &mut Context<Self>) -> Self::Result {
+        self.send_void_session_command(msg.session_id.as_deref(), "DOM.setFileInputFiles", msg.params)
+    }
+}
+
+impl Handler<SetFileChooserFiles> for ChromePageActor {
+    type Result = ResponseFuture<Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: SetFileChooserFiles, _ctx: &mut Context<Self>) -> Self::Result {
+        let Some(backend_node_id) = self.file_choosers.remove(&msg.id) else {
+            return Box::pin(async move {
+                Err(InternalError::InvalidParams(format!("Unknown file chooser: {}", msg.id)))
+            });
+        };
+        let params = SetFileInputFilesParams {
+            files: msg.files,
+            backend_node_id: Some(backend_node_id),
+            object_id: None,
+        };
+        self.send_void_command("DOM.setFileInputFiles", params)
+    }
+}
+
+impl Handler<AddRoute> for ChromePageActor {
+    type Result = ResponseActFuture<Self, Result<SubscriptionId, InternalError>>;
+
+    fn handle(&mut self, msg: AddRoute, _ctx: &mut Context<Self>) -> Self::Result {
+        let id = self.routes.insert(msg.0);
+        debug!("PageActor {} registered route {}", self.target_id, id);
+        Box::pin(self.sync_fetch_patterns().into_actor(self).map(move |result, act, _ctx| {
+            if result.is_err() {
+                act.routes.remove(id); // Interception is not active for it
+            }
+            result.map(|_| id)
+        }))
+    }
+}
+
+impl Handler<RemoveRoute> for ChromePageActor {
+    type Result = ResponseFuture<Result<bool, InternalError>>;
+
+    fn handle(&mut self, msg: RemoveRoute, _ctx: &mut Context<Self>) -> Self::Result {
+        if !self.routes.remove(msg.0) {
+            return Box::pin(async { Ok(false) });
+        }
+        let future = self.sync_fetch_patterns();
+        Box::pin(async move {
+            future.await?;
+            Ok(true)
+        })
+    }
+}
+
+impl Handler<AddInitScript> for ChromePageActor {
+    type Result = ResponseActFuture<Self, Result<String, InternalError>>;
+
+    fn handle(&mut self, msg: AddInitScript, _ctx: &mut Context<Self>) -> Self::Result {
+        let future = self.send_page_command(
+            "Page.addScriptToEvaluateOnNewDocument".to_string(),
+            serde_json::to_value(AddScriptToEvaluateOnNewDocumentParams { source: &msg.source }).unwrap(),
+        );
+        Box::pin(
+            async move {
+                let result: AddScriptToEvaluateOnNewDocumentResult = serde_json::from_value(future.await?)?;
+                Ok(result.identifier)
+            }
+            .into_actor(self)
+            .map(|result, act, _ctx| {
+                if let Ok(identifier) = &result {
+                    act.init_scripts.push(identifier.clone());
+                }
+                result
+            }),
+        )
+    }
+}
+
+impl Handler<RemoveInitScript> for ChromePageActor {
+    type Result = ResponseFuture<Result<bool, InternalError>>;
+
+    fn handle(&mut self, msg: RemoveInitScript, _ctx: &mut Context<Self>) -> Self::Result {
+        let Some(index) = self.init_scripts.iter().position(|identifier| *identifier == msg.identifier) else {
+            return Box::pin(async { Ok(false) });
+        };
+        self.init_scripts.remove(index);
+        let future = self.send_void_command(
+            "Page.removeScriptToEvaluateOnNewDocument",
+            RemoveScriptToEvaluateOnNewDocumentParams { identifier: &msg.identifier },
+        );
+        Box::pin(async move {
+            future.await?;
+            Ok(true)
+        })
+    }
+}
+
+impl Handler<ExposeFunction> for ChromePageActor {
+    type Result = ResponseActFuture<Self, Result<(), InternalError>>;
+
+    fn handle(&mut self, msg: ExposeFunction, _ctx: &mut Context<Self>) -> Self::Result {
+        let Binding { name, function } = msg.0;
+        if self.bindings.contains_key(&name) {
+            let err = InternalError::InvalidParams(format!("A function named {} is already exposed", name));
+            return Box::pin(fut::ready(Err(err)));
+        }
+        debug!("PageActor {} exposing function {}", self.target_id, name);
+        self.bindings.insert(name.clone(), function);
+        let script = bindings::binding_script(&name);
+        // In order: the binding, and the wrapper for new documents.
+        let commands = [
+            self.send_page_command(
+                "Runtime.addBinding".to_string(),
+                serde_json::to_value(AddBindingParams { name: &name }).unwrap(),
+            ),
+            self.send_page_command(
+                "Page.addScriptToEvaluateOnNewDocument".to_string(),
+                serde_json::to_value(AddScriptToEvaluateOnNewDocumentParams { source: &script }).unwrap(),
+            ),
+        ];
+        // Then the wrapper for the current documents: the main frame's (the default
+        // context), and those of the frames of the page's session.
+        let contexts = std::iter::once(None).chain(self.frames.session_contexts(None).into_iter().map(Some));
+        let evaluations: Vec<_> = contexts
+            .map(|context_id| {
+                self.send_page_command(
+                    "Runtime.evaluate".to_string(),
+                    serde_json::to_value(EvaluateParams {
+                        expression: &script,
+                        context_id,
+                        return_by_value: Some(true),
+                        await_promise: None,
+                    })
+                    .unwrap(),
+                )
+            })
+            .collect();
+        Box::pin(
+            async move {
+                for command in commands {
+                    command.await?;
+                }
+                // A context may be gone by now; a new document gets the wrapper anyway.
+                for evaluation in evaluations {
+                    if let Err(e) = evaluation.await {
+                        debug!("Binding wrapper not installed in a current document: {:?}", e);
+                    }
+                }
+                Ok(())
+            }
+            .into_actor(self)
+            .map(move |result, act, _ctx| {
+                if result.is_err() {
+                    act.bindings.remove(&name);
+                }
+                result
+            }),
+        )
+    }
+}
+
 // Handler for ProtocolEvent messages (forwarded by EventActor)
 impl Handler<ProtocolEvent> for ChromePageActor {
     type Result = ();
 
-    fn handle(&mut self, msg: ProtocolEvent, _ctx: &mut Context<Self>) {
-        // Ensure the event is for this page's session
-        if msg.session_id.as_deref() != Some(&self.session_id) {
-            warn!("PageActor {} received event for wrong session: {:?}", self.target_id, msg.session_id);
-            return;
-        }
+    fn handle(&mut self, msg: ProtocolEvent, ctx: &mut Context<Self>) {
+        // Ensure the event is for this page's session, or one of its out-of-process frames
+        let frame_session = match msg.session_id.as_deref() {
+            Some(session_id) if session_id == self.session_id => None,
+            Some(session_id) if self.frame_sessions.iter().any(|frame_session| frame_session == session_id) => {
+                Some(session_id.to_string())
+            }
+            _ => {
+                warn!("PageActor {} received event for wrong session: {:?}", self.target_id, msg.session_id);
+                return;
+            }
+        };
 
         trace!("PageActor {} received event: {:?}", self.target_id, msg);
+        if FRAME_EVENTS.contains(&msg.method.as_str()) {
+            self.handle_frame_event(&msg.method, msg.params, frame_session.as_deref());
+            return;
+        }
+        if frame_session.is_some() {
+            return; // Only the frame tree is followed in frame sessions
+        }
         match msg.method.as_str() {
             "Page.lifecycleEvent" => {
                 // Update state based on lifecycle, e.g., navigation completion
@@ -706,11 +2935,28 @@ impl Handler<ProtocolEvent> for ChromePageActor {
                          _ => {}
                      }
                  }
+                 self.track_main_frame_lifecycle(&msg.params);
+                 self.dispatch_lifecycle_event(msg.params);
             }
             "Runtime.consoleAPICalled" => {
-                // TODO: Parse and potentially emit L1 ConsoleMessage event
                 debug!("Console API called on page {}: {:?}", self.target_id, msg.params);
+                self.dispatch_console_message(msg.params);
+            }
+            "Runtime.exceptionThrown" => {
+                debug!("Uncaught exception on page {}", self.target_id);
+                self.dispatch_page_error(msg.params);
+            }
+            "Page.javascriptDialogOpening" => {
+                debug!("Dialog opening on page {}: {:?}", self.target_id, msg.params);
+                self.dispatch_dialog(msg.params, ctx);
             }
+            "Page.fileChooserOpened" => self.handle_file_chooser_opened(msg.params),
+            "Network.requestWillBeSent" => self.handle_request_will_be_sent(msg.params),
+            "Network.responseReceived" => self.handle_response_received(msg.params),
+            "Network.loadingFinished" => self.handle_loading_finished(msg.params, ctx),
+            "Network.loadingFailed" => self.handle_loading_failed(msg.params),
+            "Fetch.requestPaused" => self.handle_request_paused(msg.params, ctx),
+            "Runtime.bindingCalled" => self.handle_binding_called(msg.params, ctx),
             _ => {} // Ignore other events for now
         }
     }
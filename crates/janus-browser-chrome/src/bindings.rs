@@ -0,0 +1,79 @@
+//! Rust functions exposed to page scripts (`Page::expose_function`).
+//!
+//! A CDP binding (`Runtime.addBinding`) is a global function taking one string, whose
+//! calls are reported as `Runtime.bindingCalled`. A wrapper script replaces it with an
+//! async function that sends `{ seq, args }` through the binding; the Rust result is
+//! delivered back into the calling context by sequence number.
+
+use crate::protocol::CallArgument;
+use janus_interfaces::ExposedFunction;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::fmt;
+
+/// A function exposed through `Page::expose_function`.
+pub(crate) struct Binding {
+    pub name: String,
+    pub function: ExposedFunction,
+}
+
+impl fmt::Debug for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Binding({})", self.name)
+    }
+}
+
+/// Payload of a call of a wrapped binding.
+#[derive(Deserialize, Debug, PartialEq)]
+pub(crate) struct BindingCall {
+    pub seq: u64,
+    #[serde(default)]
+    pub args: Vec<Value>,
+}
+
+/// Wraps the binding `name` of the current document; runs once per document.
+pub(crate) fn binding_script(name: &str) -> String {
+    format!(
+        "(() => {{
+    const name = {};
+    const binding = globalThis[name];
+    if (typeof binding !== 'function' || binding.__janusCallbacks) return;
+    const callbacks = new Map();
+    let lastSeq = 0;
+    const exposed = (...args) => new Promise((resolve, reject) => {{
+        const seq = ++lastSeq;
+        callbacks.set(seq, {{ resolve, reject }});
+        binding(JSON.stringify({{ seq, args }}));
+    }});
+    exposed.__janusCallbacks = callbacks;
+    globalThis[name] = exposed;
+}})()",
+        json!(name)
+    )
+}
+
+/// Settles the promise of call `seq` of `window[name]` with a value, or an error
+/// message if `error` is not null.
+pub(crate) const DELIVER_RESULT: &str = "function(name, seq, error, value) {
+    const callbacks = globalThis[name] && globalThis[name].__janusCallbacks;
+    const callback = callbacks && callbacks.get(seq);
+    if (!callback) return;
+    callbacks.delete(seq);
+    if (error === null) callback.resolve(value); else callback.reject(new Error(error));
+}";
+
+pub(crate) fn parse_binding_call(payload: &str) -> Option<BindingCall> {
+    serde_json::from_str(payload).ok()
+}
+
+/// Arguments of [`DELIVER_RESULT`] for the outcome of a call.
+pub(crate) fn deliver_arguments(name: &str, seq: u64, result: Result<Value, String>) -> Vec<CallArgument> {
+    let (error, value) = match result {
+        Ok(value) => (Value::Null, value),
+        Err(message) => (json!(message), Value::Null),
+    };
+    [json!(name), json!(seq), error, value]
+        .into_iter()
+        .map(|value| CallArgument { value: Some(value), object_id: None })
+        .collect()
+}
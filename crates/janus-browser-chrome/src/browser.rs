@@ -1,13 +1,28 @@
 //! L2 Implementation of `janus_interfaces::Browser` for Chrome.
 
 use crate::actors::{
-    ChromeBrowserActor, CreatePage, GetPages, GetVersion, PageInfo, ShutdownBrowser,
+    AddBrowserEventHandler, ChromeBrowserActor, ClearCookies, CreateBrowserContext, CreatePage,
+    GetAllCookies, GetPages, GetVersion, PageInfo, RemoveBrowserEventHandler, SetDownloadBehavior,
+    ShutdownBrowser, StoreCookies,
 };
+use crate::context::ChromeBrowserContext;
+use crate::cookies;
+use crate::downloads::{self, DownloadEvent, DownloadWaiter};
+use crate::emulation;
 use crate::error::map_internal_to_api_error; // Need an error mapping module
+use crate::events::BrowserEventHandler;
 use crate::page::ChromePage;
+use crate::process::ChromeProcess;
 use actix::prelude::*;
 use async_trait::async_trait;
-use janus_interfaces::{ApiError, Browser, Page};
+use crate::protocol::{CookieParam, CreateBrowserContextParams};
+use janus_interfaces::{
+    ApiError, Browser, BrowserContext, BrowserContextOptions, Cookie, CookieFilter, Download, DownloadBehavior,
+    DownloadProgress, DownloadStarted, EventHandler, Page, SubscriptionId, TargetCreatedEvent, TargetInfo,
+};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use url::Url;
 use log::debug;
 
 // Represents the user-facing handle to a Chrome browser instance
@@ -15,12 +30,35 @@ use log::debug;
 pub struct ChromeBrowser {
     // Internal handle to the actor managing this browser instance.
     actor_addr: Addr<ChromeBrowserActor>,
+    // The browser process, if it was launched (rather than connected to) by Janus.
+    process: Option<ChromeProcess>,
 }
 
 impl ChromeBrowser {
     // Renamed from launch, called by janus-client::launch
     pub fn new(actor_addr: Addr<ChromeBrowserActor>) -> Self {
-        Self { actor_addr }
+        Self {
+            actor_addr,
+            process: None,
+        }
+    }
+
+    /// Creates a handle that owns the launched browser process; `close()` kills and reaps it.
+    pub fn with_process(actor_addr: Addr<ChromeBrowserActor>, process: ChromeProcess) -> Self {
+        Self {
+            actor_addr,
+            process: Some(process),
+        }
+    }
+
+    async fn add_event_handler(&self, handler: BrowserEventHandler) -> Result<SubscriptionId, ApiError> {
+        debug!("ChromeBrowser registering {:?}", handler);
+        self.actor_addr
+            .send(AddBrowserEventHandler(handler))
+            .await
+            .map_err(|mb_err| {
+                ApiError::InternalError(format!("Mailbox error registering event handler: {}", mb_err))
+            })
     }
 }
 
@@ -45,50 +83,59 @@ impl Browser for ChromeBrowser {
         // BrowserActor should handle sending Browser.close or Target.closeTarget commands.
         // For now, it just stops the actor system part.
         debug!("ChromeBrowser::close requested. Sending ShutdownBrowser to BrowserActor.");
-        self.actor_addr
+        let shutdown_result = self
+            .actor_addr
             .send(ShutdownBrowser)
             .await
             .map_err(|mb_err| {
                 ApiError::InternalError(format!("Mailbox error closing browser: {}", mb_err))
-            })?;
+            });
+        // Kill the process we launched even if the actor was already gone.
+        if let Some(mut process) = self.process.take() {
+            debug!("Killing launched browser process (pid: {:?}).", process.pid());
+            process.shutdown().await;
+        }
         // TODO (Phase 3): Ensure Browser.close is actually sent.
-        Ok(())
+        shutdown_result
     }
 
     async fn new_page(&self) -> Result<Box<dyn Page>, ApiError> {
-        let url = "about:blank".to_string(); // Default URL for new tabs
-        debug!("ChromeBrowser::new_page requested (url: {})", url);
-        let response = self
-            .actor_addr
-            .send(CreatePage { url })
-            .await
-            .map_err(|mb_err| {
-                ApiError::InternalError(format!("Mailbox error creating page: {}", mb_err))
-            })? // Mailbox Error
-            .map_err(map_internal_to_api_error)?; // Logical Error
-
-        Ok(Box::new(ChromePage::new(
-            response.page_actor_addr,
-            response.page_id,
-        )))
+        debug!("ChromeBrowser::new_page requested.");
+        create_page(&self.actor_addr, None).await
     }
 
-    async fn pages(&self) -> Result<Vec<Box<dyn Page>>, ApiError> {
-        debug!("ChromeBrowser::pages requested.");
-        let pages_info: Vec<PageInfo> = self
+    async fn new_context(
+        &self,
+        options: BrowserContextOptions,
+    ) -> Result<Box<dyn BrowserContext>, ApiError> {
+        debug!("ChromeBrowser::new_context requested ({:?})", options);
+        let params = CreateBrowserContextParams {
+            proxy_server: options.proxy_server.clone(),
+            proxy_bypass_list: options.proxy_bypass_list.clone(),
+        };
+        let emulation = emulation::context_emulation(&options)?;
+        let context_id = self
             .actor_addr
-            .send(GetPages)
+            .send(CreateBrowserContext { params, emulation })
             .await
             .map_err(|mb_err| {
-                ApiError::InternalError(format!("Mailbox error getting pages: {}", mb_err))
+                ApiError::InternalError(format!("Mailbox error creating browser context: {}", mb_err))
             })?
             .map_err(map_internal_to_api_error)?;
+        let permissions = options.permissions.clone();
+        let context = ChromeBrowserContext::new(self.actor_addr.clone(), context_id);
+        if !permissions.is_empty()
+            && let Err(e) = context.grant_permissions(&permissions, None).await
+        {
+            let _ = context.close().await;
+            return Err(e);
+        }
+        Ok(Box::new(context))
+    }
 
-        let pages: Vec<Box<dyn Page>> = pages_info
-            .into_iter()
-            .map(|info| Box::new(ChromePage::new(info.actor_addr, info.id)) as Box<dyn Page>)
-            .collect();
-        Ok(pages)
+    async fn pages(&self) -> Result<Vec<Box<dyn Page>>, ApiError> {
+        debug!("ChromeBrowser::pages requested.");
+        list_pages(&self.actor_addr, None).await
     }
 
     async fn version(&self) -> Result<String, ApiError> {
@@ -101,6 +148,259 @@ impl Browser for ChromeBrowser {
             })?
             .map_err(map_internal_to_api_error)
     }
+
+    async fn cookies(&self, urls: &[String]) -> Result<Vec<Cookie>, ApiError> {
+        debug!("ChromeBrowser::cookies for {:?}", urls);
+        storage_cookies(&self.actor_addr, None, urls).await
+    }
+
+    async fn set_cookies(&self, cookies: Vec<Cookie>) -> Result<(), ApiError> {
+        debug!("ChromeBrowser::set_cookies ({} cookies)", cookies.len());
+        set_storage_cookies(&self.actor_addr, None, cookies).await
+    }
+
+    async fn delete_cookies(&self, filter: CookieFilter) -> Result<(), ApiError> {
+        debug!("ChromeBrowser::delete_cookies {:?}", filter);
+        delete_storage_cookies(&self.actor_addr, None, filter).await
+    }
+
+    async fn clear_cookies(&self) -> Result<(), ApiError> {
+        debug!("ChromeBrowser::clear_cookies");
+        clear_storage_cookies(&self.actor_addr, None).await
+    }
+
+    async fn set_download_behavior(&self, behavior: DownloadBehavior) -> Result<(), ApiError> {
+        debug!("ChromeBrowser::set_download_behavior {:?}", behavior);
+        set_download_behavior(&self.actor_addr, None, behavior).await
+    }
+
+    async fn wait_for_download(&self, timeout_ms: u64) -> Result<Download, ApiError> {
+        debug!("ChromeBrowser::wait_for_download (timeout: {}ms)", timeout_ms);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let started_tx = tx.clone();
+        let started_id = self
+            .on_download_started(Box::new(move |started| {
+                let _ = started_tx.send(DownloadEvent::Started(started));
+            }))
+            .await?;
+        let progress_id = match self
+            .on_download_progress(Box::new(move |progress| {
+                let _ = tx.send(DownloadEvent::Progress(progress));
+            }))
+            .await
+        {
+            Ok(id) => id,
+            Err(e) => {
+                let _ = self.unsubscribe(started_id).await;
+                return Err(e);
+            }
+        };
+        let wait = async {
+            let mut waiter = DownloadWaiter::default();
+            while let Some(event) = rx.recv().await {
+                if let Some(outcome) = waiter.on_event(event) {
+                    return outcome;
+                }
+            }
+            Err(ApiError::InternalError("Browser actor stopped while waiting for a download".to_string()))
+        };
+        let outcome = tokio::time::timeout(Duration::from_millis(timeout_ms), wait)
+            .await
+            .map_err(|_| ApiError::Timeout { last_observed: None });
+        let _ = self.unsubscribe(started_id).await;
+        let _ = self.unsubscribe(progress_id).await;
+        outcome?
+    }
+
+    async fn on_target_created(
+        &self,
+        handler: EventHandler<TargetCreatedEvent>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(BrowserEventHandler::Created(handler)).await
+    }
+
+    async fn on_target_changed(
+        &self,
+        handler: EventHandler<TargetInfo>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(BrowserEventHandler::Changed(handler)).await
+    }
+
+    async fn on_target_destroyed(
+        &self,
+        handler: EventHandler<TargetInfo>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(BrowserEventHandler::Destroyed(handler)).await
+    }
+
+    async fn on_download_started(
+        &self,
+        handler: EventHandler<DownloadStarted>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(BrowserEventHandler::DownloadStarted(handler)).await
+    }
+
+    async fn on_download_progress(
+        &self,
+        handler: EventHandler<DownloadProgress>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(BrowserEventHandler::DownloadProgress(handler)).await
+    }
+
+    async fn unsubscribe(&self, id: SubscriptionId) -> Result<(), ApiError> {
+        let removed = self
+            .actor_addr
+            .send(RemoveBrowserEventHandler(id))
+            .await
+            .map_err(|mb_err| {
+                ApiError::InternalError(format!("Mailbox error unsubscribing: {}", mb_err))
+            })?;
+        if removed {
+            Ok(())
+        } else {
+            Err(ApiError::InvalidParameters(format!("Unknown subscription ID: {}", id)))
+        }
+    }
+}
+
+// --- Shared by `ChromeBrowser` (default context) and `ChromeBrowserContext` ---
+
+pub(crate) async fn create_page(
+    actor_addr: &Addr<ChromeBrowserActor>,
+    browser_context_id: Option<String>,
+) -> Result<Box<dyn Page>, ApiError> {
+    let url = "about:blank".to_string(); // Default URL for new tabs
+    let response = actor_addr
+        .send(CreatePage { url, browser_context_id })
+        .await
+        .map_err(|mb_err| {
+            ApiError::InternalError(format!("Mailbox error creating page: {}", mb_err))
+        })? // Mailbox Error
+        .map_err(map_internal_to_api_error)?; // Logical Error
+
+    Ok(Box::new(ChromePage::new(
+        response.page_actor_addr,
+        response.page_id,
+    )))
+}
+
+pub(crate) async fn list_pages(
+    actor_addr: &Addr<ChromeBrowserActor>,
+    browser_context_id: Option<String>,
+) -> Result<Vec<Box<dyn Page>>, ApiError> {
+    let pages_info: Vec<PageInfo> = actor_addr
+        .send(GetPages { browser_context_id })
+        .await
+        .map_err(|mb_err| {
+            ApiError::InternalError(format!("Mailbox error getting pages: {}", mb_err))
+        })?
+        .map_err(map_internal_to_api_error)?;
+
+    let pages: Vec<Box<dyn Page>> = pages_info
+        .into_iter()
+        .map(|info| Box::new(ChromePage::new(info.actor_addr, info.id)) as Box<dyn Page>)
+        .collect();
+    Ok(pages)
+}
+
+async fn all_cookies(
+    actor_addr: &Addr<ChromeBrowserActor>,
+    browser_context_id: Option<String>,
+) -> Result<Vec<Cookie>, ApiError> {
+    let cookies = actor_addr
+        .send(GetAllCookies { browser_context_id })
+        .await
+        .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error getting cookies: {}", mb_err)))?
+        .map_err(map_internal_to_api_error)?;
+    Ok(cookies.into_iter().map(cookies::cookie_from_cdp).collect())
+}
+
+async fn store_cookies(
+    actor_addr: &Addr<ChromeBrowserActor>,
+    browser_context_id: Option<String>,
+    cookies: Vec<CookieParam>,
+) -> Result<(), ApiError> {
+    actor_addr
+        .send(StoreCookies { cookies, browser_context_id })
+        .await
+        .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error setting cookies: {}", mb_err)))?
+        .map_err(map_internal_to_api_error)
+}
+
+pub(crate) async fn storage_cookies(
+    actor_addr: &Addr<ChromeBrowserActor>,
+    browser_context_id: Option<String>,
+    urls: &[String],
+) -> Result<Vec<Cookie>, ApiError> {
+    // Storage.getCookies has no URL filter, so match the URLs here.
+    let urls = urls
+        .iter()
+        .map(|url| {
+            Url::parse(url).map_err(|e| ApiError::InvalidParameters(format!("Invalid URL {}: {}", url, e)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let cookies = all_cookies(actor_addr, browser_context_id).await?;
+    Ok(cookies
+        .into_iter()
+        .filter(|cookie| urls.is_empty() || urls.iter().any(|url| cookies::cookie_matches_url(cookie, url)))
+        .collect())
+}
+
+pub(crate) async fn set_storage_cookies(
+    actor_addr: &Addr<ChromeBrowserActor>,
+    browser_context_id: Option<String>,
+    cookies: Vec<Cookie>,
+) -> Result<(), ApiError> {
+    if let Some(cookie) = cookies.iter().find(|cookie| cookie.url.is_none() && cookie.domain.is_none()) {
+        return Err(ApiError::InvalidParameters(format!(
+            "Cookie {} needs a url or a domain",
+            cookie.name
+        )));
+    }
+    let params = cookies.into_iter().map(cookies::cookie_to_cdp).collect();
+    store_cookies(actor_addr, browser_context_id, params).await
+}
+
+pub(crate) async fn delete_storage_cookies(
+    actor_addr: &Addr<ChromeBrowserActor>,
+    browser_context_id: Option<String>,
+    filter: CookieFilter,
+) -> Result<(), ApiError> {
+    // There is no Storage.deleteCookies; overwrite the matches with expired copies.
+    let expired: Vec<_> = all_cookies(actor_addr, browser_context_id.clone())
+        .await?
+        .iter()
+        .filter(|cookie| cookies::filter_matches(&filter, cookie))
+        .map(cookies::expired_cookie_param)
+        .collect();
+    if expired.is_empty() {
+        return Ok(());
+    }
+    store_cookies(actor_addr, browser_context_id, expired).await
+}
+
+pub(crate) async fn clear_storage_cookies(
+    actor_addr: &Addr<ChromeBrowserActor>,
+    browser_context_id: Option<String>,
+) -> Result<(), ApiError> {
+    actor_addr
+        .send(ClearCookies { browser_context_id })
+        .await
+        .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error clearing cookies: {}", mb_err)))?
+        .map_err(map_internal_to_api_error)
+}
+
+pub(crate) async fn set_download_behavior(
+    actor_addr: &Addr<ChromeBrowserActor>,
+    browser_context_id: Option<String>,
+    behavior: DownloadBehavior,
+) -> Result<(), ApiError> {
+    let (params, download_dir) = downloads::download_behavior_params(behavior, browser_context_id)?;
+    actor_addr
+        .send(SetDownloadBehavior { params, download_dir })
+        .await
+        .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error setting download behavior: {}", mb_err)))?
+        .map_err(map_internal_to_api_error)
 }
 
 impl Drop for ChromeBrowser {
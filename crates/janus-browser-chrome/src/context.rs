@@ -0,0 +1,125 @@
+//! L2 Implementation of `janus_interfaces::BrowserContext` for Chrome.
+
+use crate::actors::{ChromeBrowserActor, DisposeBrowserContext, GrantPermissions, ResetPermissions};
+use crate::browser;
+use crate::error::map_internal_to_api_error;
+use crate::protocol::GrantPermissionsParams;
+use actix::prelude::*;
+use async_trait::async_trait;
+use janus_interfaces::{
+    ApiError, BrowserContext, Cookie, CookieFilter, DownloadBehavior, Page, Permission,
+};
+use log::debug;
+
+/// Handle to a browser context created with `Target.createBrowserContext`. Commands go
+/// through the browser actor, scoped with the context's ID.
+#[derive(Debug)]
+pub struct ChromeBrowserContext {
+    actor_addr: Addr<ChromeBrowserActor>,
+    context_id: String,
+}
+
+impl ChromeBrowserContext {
+    pub fn new(actor_addr: Addr<ChromeBrowserActor>, context_id: String) -> Self {
+        Self { actor_addr, context_id }
+    }
+}
+
+/// `Browser.PermissionType` for a permission.
+pub(crate) fn permission_to_cdp(permission: Permission) -> &'static str {
+    match permission {
+        Permission::Geolocation => "geolocation",
+        Permission::Notifications => "notifications",
+        Permission::Camera => "videoCapture",
+        Permission::Microphone => "audioCapture",
+        Permission::ClipboardRead => "clipboardReadWrite",
+        Permission::ClipboardWrite => "clipboardSanitizedWrite",
+        Permission::Midi => "midi",
+        Permission::MidiSysex => "midiSysex",
+        Permission::BackgroundSync => "backgroundSync",
+        Permission::Sensors => "sensors",
+        Permission::PaymentHandler => "paymentHandler",
+        Permission::StorageAccess => "storageAccess",
+        Permission::IdleDetection => "idleDetection",
+        Permission::LocalFonts => "localFonts",
+    }
+}
+
+#[async_trait]
+impl BrowserContext for ChromeBrowserContext {
+    fn id(&self) -> String {
+        self.context_id.clone()
+    }
+
+    async fn new_page(&self) -> Result<Box<dyn Page>, ApiError> {
+        debug!("ChromeBrowserContext ({})::new_page", self.context_id);
+        // The context's emulation options are applied by the page actor as it starts.
+        browser::create_page(&self.actor_addr, Some(self.context_id.clone())).await
+    }
+
+    async fn pages(&self) -> Result<Vec<Box<dyn Page>>, ApiError> {
+        debug!("ChromeBrowserContext ({})::pages", self.context_id);
+        browser::list_pages(&self.actor_addr, Some(self.context_id.clone())).await
+    }
+
+    async fn cookies(&self, urls: &[String]) -> Result<Vec<Cookie>, ApiError> {
+        debug!("ChromeBrowserContext ({})::cookies for {:?}", self.context_id, urls);
+        browser::storage_cookies(&self.actor_addr, Some(self.context_id.clone()), urls).await
+    }
+
+    async fn set_cookies(&self, cookies: Vec<Cookie>) -> Result<(), ApiError> {
+        debug!("ChromeBrowserContext ({})::set_cookies ({} cookies)", self.context_id, cookies.len());
+        browser::set_storage_cookies(&self.actor_addr, Some(self.context_id.clone()), cookies).await
+    }
+
+    async fn delete_cookies(&self, filter: CookieFilter) -> Result<(), ApiError> {
+        debug!("ChromeBrowserContext ({})::delete_cookies {:?}", self.context_id, filter);
+        browser::delete_storage_cookies(&self.actor_addr, Some(self.context_id.clone()), filter).await
+    }
+
+    async fn clear_cookies(&self) -> Result<(), ApiError> {
+        debug!("ChromeBrowserContext ({})::clear_cookies", self.context_id);
+        browser::clear_storage_cookies(&self.actor_addr, Some(self.context_id.clone())).await
+    }
+
+    async fn grant_permissions(
+        &self,
+        permissions: &[Permission],
+        origin: Option<&str>,
+    ) -> Result<(), ApiError> {
+        debug!("ChromeBrowserContext ({})::grant_permissions {:?} to {:?}", self.context_id, permissions, origin);
+        let params = GrantPermissionsParams {
+            permissions: permissions.iter().copied().map(permission_to_cdp).collect(),
+            origin: origin.map(str::to_string),
+            browser_context_id: Some(self.context_id.clone()),
+        };
+        self.actor_addr
+            .send(GrantPermissions(params))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error granting permissions: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn clear_permissions(&self) -> Result<(), ApiError> {
+        debug!("ChromeBrowserContext ({})::clear_permissions", self.context_id);
+        self.actor_addr
+            .send(ResetPermissions { browser_context_id: Some(self.context_id.clone()) })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error resetting permissions: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn set_download_behavior(&self, behavior: DownloadBehavior) -> Result<(), ApiError> {
+        debug!("ChromeBrowserContext ({})::set_download_behavior {:?}", self.context_id, behavior);
+        browser::set_download_behavior(&self.actor_addr, Some(self.context_id.clone()), behavior).await
+    }
+
+    async fn close(&self) -> Result<(), ApiError> {
+        debug!("ChromeBrowserContext ({})::close", self.context_id);
+        self.actor_addr
+            .send(DisposeBrowserContext { browser_context_id: self.context_id.clone() })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error closing browser context: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+}
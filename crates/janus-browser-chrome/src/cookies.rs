@@ -0,0 +1,103 @@
+//! Mapping between L1 cookies and the CDP `Network.Cookie` / `Network.CookieParam`
+//! types, plus the URL matching needed where Chrome offers no filter (the browser-wide
+//! `Storage.*` cookie commands).
+
+use crate::protocol::{self, CookieParam, DeleteCookiesParams};
+use janus_interfaces::{Cookie, CookieFilter, SameSite};
+use url::Url;
+
+/// Expiry used to delete a cookie by overwriting it (any time in the past works).
+pub(crate) const EXPIRED: f64 = 1.0;
+
+fn same_site_from_cdp(same_site: &str) -> Option<SameSite> {
+    match same_site {
+        "Strict" => Some(SameSite::Strict),
+        "Lax" => Some(SameSite::Lax),
+        "None" => Some(SameSite::None),
+        _ => None,
+    }
+}
+
+fn same_site_to_cdp(same_site: SameSite) -> &'static str {
+    match same_site {
+        SameSite::Strict => "Strict",
+        SameSite::Lax => "Lax",
+        SameSite::None => "None",
+    }
+}
+
+pub(crate) fn cookie_from_cdp(cookie: protocol::Cookie) -> Cookie {
+    Cookie {
+        name: cookie.name,
+        value: cookie.value,
+        domain: Some(cookie.domain),
+        path: Some(cookie.path),
+        url: None,
+        expires: (!cookie.session && cookie.expires >= 0.0).then_some(cookie.expires),
+        http_only: cookie.http_only,
+        secure: cookie.secure,
+        same_site: cookie.same_site.as_deref().and_then(same_site_from_cdp),
+    }
+}
+
+pub(crate) fn cookie_to_cdp(cookie: Cookie) -> CookieParam {
+    CookieParam {
+        name: cookie.name,
+        value: cookie.value,
+        url: cookie.url,
+        domain: cookie.domain,
+        path: cookie.path,
+        secure: cookie.secure,
+        http_only: cookie.http_only,
+        same_site: cookie.same_site.map(same_site_to_cdp),
+        expires: cookie.expires,
+    }
+}
+
+pub(crate) fn delete_params_from_filter(filter: CookieFilter) -> DeleteCookiesParams {
+    DeleteCookiesParams {
+        name: filter.name,
+        url: filter.url,
+        domain: filter.domain,
+        path: filter.path,
+    }
+}
+
+/// Whether the browser would send `cookie` with a request to `url` (domain, path and
+/// `secure` rules of RFC 6265).
+pub(crate) fn cookie_matches_url(cookie: &Cookie, url: &Url) -> bool {
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+    let domain = cookie.domain.as_deref().unwrap_or_default();
+    let domain_matches = match domain.strip_prefix('.') {
+        Some(parent) => host == parent || host.ends_with(&format!(".{}", parent)),
+        None => host.eq_ignore_ascii_case(domain),
+    };
+    let cookie_path = cookie.path.as_deref().unwrap_or("/");
+    let request_path = url.path();
+    let path_matches = request_path == cookie_path
+        || (request_path.starts_with(cookie_path)
+            && (cookie_path.ends_with('/') || request_path[cookie_path.len()..].starts_with('/')));
+    let secure_matches = !cookie.secure || matches!(url.scheme(), "https" | "wss");
+    domain_matches && path_matches && secure_matches
+}
+
+/// Whether `filter` selects `cookie`. An unparsable filter URL matches nothing.
+pub(crate) fn filter_matches(filter: &CookieFilter, cookie: &Cookie) -> bool {
+    cookie.name == filter.name
+        && filter.domain.as_ref().is_none_or(|domain| cookie.domain.as_ref() == Some(domain))
+        && filter.path.as_ref().is_none_or(|path| cookie.path.as_ref() == Some(path))
+        && filter.url.as_deref().is_none_or(|url| {
+            Url::parse(url).is_ok_and(|url| cookie_matches_url(cookie, &url))
+        })
+}
+
+/// Parameters that delete `cookie` when passed to `Storage.setCookies`.
+pub(crate) fn expired_cookie_param(cookie: &Cookie) -> CookieParam {
+    CookieParam {
+        value: String::new(),
+        expires: Some(EXPIRED),
+        ..cookie_to_cdp(cookie.clone())
+    }
+}
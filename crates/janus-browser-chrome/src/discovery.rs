@@ -0,0 +1,263 @@
+//! Endpoint and target discovery over Chrome's DevTools HTTP interface.
+//!
+//! Chrome serves a small JSON API next to the WebSocket endpoint:
+//! `/json/version`, `/json/list`, `/json/new` and `/json/close/{id}`.
+//! This module talks to it over plain HTTP/1.1, so tools can resolve the browser
+//! WebSocket URL or list targets without opening a WebSocket connection.
+
+use janus_interfaces::ApiError;
+use log::{debug, trace};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Default timeout for a single discovery request.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Result of `GET /json/version`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VersionInfo {
+    #[serde(rename = "Browser")]
+    pub browser: String,
+    #[serde(rename = "Protocol-Version")]
+    pub protocol_version: String,
+    #[serde(rename = "User-Agent", default)]
+    pub user_agent: String,
+    #[serde(rename = "V8-Version", default)]
+    pub v8_version: Option<String>,
+    #[serde(rename = "WebKit-Version", default)]
+    pub webkit_version: Option<String>,
+    /// Browser-level WebSocket URL, including the browser GUID.
+    #[serde(rename = "webSocketDebuggerUrl")]
+    pub web_socket_debugger_url: String,
+}
+
+/// One entry of `GET /json/list` (also returned by `/json/new`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetEntry {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String, // e.g., "page", "iframe", "service_worker"
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub favicon_url: Option<String>,
+    #[serde(default)]
+    pub devtools_frontend_url: Option<String>,
+    /// Missing while another client is attached to the target.
+    #[serde(default)]
+    pub web_socket_debugger_url: Option<String>,
+}
+
+/// Client for the DevTools HTTP endpoints of a browser listening on `host:port`.
+#[derive(Debug, Clone)]
+pub struct DevToolsHttpClient {
+    host: String,
+    port: u16,
+    timeout: Duration,
+}
+
+impl DevToolsHttpClient {
+    pub fn new(host: impl Into<String>, port: u16) -> Self {
+        Self {
+            host: host.into(),
+            port,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Builds a client from any URL carrying a host and port,
+    /// e.g. `http://127.0.0.1:9222` or `ws://localhost:9222/devtools/browser`.
+    pub fn from_url(url: &str) -> Result<Self, ApiError> {
+        let (host, port) = parse_host_port(url)
+            .ok_or_else(|| ApiError::InvalidParameters(format!("No host:port in URL: {}", url)))?;
+        Ok(Self::new(host, port))
+    }
+
+    /// Sets the timeout applied to each request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// `GET /json/version`: browser version and the browser WebSocket URL.
+    pub async fn version(&self) -> Result<VersionInfo, ApiError> {
+        self.get_json("GET", "/json/version").await
+    }
+
+    /// Convenience for `version().web_socket_debugger_url`.
+    pub async fn browser_ws_url(&self) -> Result<String, ApiError> {
+        Ok(self.version().await?.web_socket_debugger_url)
+    }
+
+    /// `GET /json/list`: all targets the browser currently exposes.
+    pub async fn list(&self) -> Result<Vec<TargetEntry>, ApiError> {
+        self.get_json("GET", "/json/list").await
+    }
+
+    /// `PUT /json/new?{url}`: opens a new tab, optionally at `url`.
+    pub async fn new_target(&self, url: Option<&str>) -> Result<TargetEntry, ApiError> {
+        let path = match url {
+            Some(url) => format!("/json/new?{}", encode_query(url)),
+            None => "/json/new".to_string(),
+        };
+        // Recent Chrome versions reject GET for this endpoint.
+        self.get_json("PUT", &path).await
+    }
+
+    /// `GET /json/close/{id}`: closes the target with the given ID.
+    pub async fn close_target(&self, target_id: &str) -> Result<(), ApiError> {
+        let path = format!("/json/close/{}", target_id);
+        let (status, body) = self.request("GET", &path).await?;
+        if status == 200 {
+            Ok(())
+        } else {
+            Err(ApiError::ProtocolError(format!(
+                "{} returned HTTP {}: {}",
+                path,
+                status,
+                body.trim()
+            )))
+        }
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, method: &str, path: &str) -> Result<T, ApiError> {
+        let (status, body) = self.request(method, path).await?;
+        if status != 200 {
+            return Err(ApiError::ProtocolError(format!(
+                "{} returned HTTP {}: {}",
+                path,
+                status,
+                body.trim()
+            )));
+        }
+        serde_json::from_str(&body).map_err(|e| {
+            ApiError::ProtocolError(format!("Failed to parse response from {}: {}", path, e))
+        })
+    }
+
+    async fn request(&self, method: &str, path: &str) -> Result<(u16, String), ApiError> {
+        debug!("DevTools HTTP {} http://{}:{}{}", method, self.host, self.port, path);
+        let exchange = async {
+            let mut stream = TcpStream::connect((self.host.as_str(), self.port))
+                .await
+                .map_err(|e| {
+                    ApiError::ConnectionFailed(format!(
+                        "{}:{} unreachable: {}",
+                        self.host, self.port, e
+                    ))
+                })?;
+            let request = format!(
+                "{} {} HTTP/1.1\r\nHost: {}:{}\r\nAccept: application/json\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                method, path, self.host, self.port
+            );
+            stream.write_all(request.as_bytes()).await?;
+            let mut raw = Vec::new();
+            stream.read_to_end(&mut raw).await?;
+            Ok::<_, ApiError>(raw)
+        };
+        let raw = tokio::time::timeout(self.timeout, exchange)
+            .await
+            .map_err(|_| ApiError::Timeout { last_observed: None })??;
+        trace!("DevTools HTTP raw response: {}", String::from_utf8_lossy(&raw));
+        parse_http_response(&raw)
+    }
+}
+
+/// Extracts `(host, port)` from `scheme://host:port/...`. Bracketed IPv6 hosts are supported.
+pub(crate) fn parse_host_port(url: &str) -> Option<(String, u16)> {
+    let rest = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let (host, port) = if let Some(bracketed) = authority.strip_prefix('[') {
+        let (host, after) = bracketed.split_once(']')?;
+        (host, after.strip_prefix(':')?)
+    } else {
+        authority.rsplit_once(':')?
+    };
+    if host.is_empty() {
+        return None;
+    }
+    Some((host.to_string(), port.parse().ok()?))
+}
+
+/// Splits a raw HTTP/1.1 response into status code and body.
+/// Handles both `Content-Length` and chunked bodies.
+pub(crate) fn parse_http_response(raw: &[u8]) -> Result<(u16, String), ApiError> {
+    let head_end = find(raw, b"\r\n\r\n").ok_or_else(|| ApiError::ProtocolError("Malformed HTTP response".to_string()))?;
+    let head = String::from_utf8_lossy(&raw[..head_end]);
+    let body = &raw[head_end + 4..];
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .and_then(|status_line| status_line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| ApiError::ProtocolError("Malformed HTTP status line".to_string()))?;
+
+    let chunked = lines.any(|line| {
+        let lower = line.to_ascii_lowercase();
+        lower.starts_with("transfer-encoding:") && lower.contains("chunked")
+    });
+    // Chunk sizes count bytes, so the body is decoded before it is read as UTF-8.
+    let body = if chunked {
+        String::from_utf8_lossy(&decode_chunked(body)?).into_owned()
+    } else {
+        String::from_utf8_lossy(body).into_owned()
+    };
+    Ok((status, body))
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+fn decode_chunked(mut body: &[u8]) -> Result<Vec<u8>, ApiError> {
+    let mut decoded = Vec::new();
+    loop {
+        let line_end = find(body, b"\r\n").ok_or_else(|| ApiError::ProtocolError("Malformed chunked body".to_string()))?;
+        let size_line = String::from_utf8_lossy(&body[..line_end]);
+        let size_hex = size_line.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size_hex, 16)
+            .map_err(|_| ApiError::ProtocolError("Malformed chunk size".to_string()))?;
+        if size == 0 {
+            return Ok(decoded);
+        }
+        let rest = &body[line_end + 2..];
+        let chunk = rest
+            .get(..size)
+            .ok_or_else(|| ApiError::ProtocolError("Truncated chunked body".to_string()))?;
+        decoded.extend_from_slice(chunk);
+        body = rest[size..].strip_prefix(b"\r\n").unwrap_or(&rest[size..]);
+    }
+}
+
+/// Percent-encodes the characters that would otherwise end or split the query.
+fn encode_query(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b' ' | b'#' | b'%' | b'"' | b'<' | b'>' | b'\\' | b'^' | b'`' | b'{' | b'|' | b'}' => {
+                encoded.push_str(&format!("%{:02X}", byte))
+            }
+            0..=0x1F | 0x7F..=0xFF => encoded.push_str(&format!("%{:02X}", byte)),
+            _ => encoded.push(byte as char),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,137 @@
+//! Downloads: `Browser.setDownloadBehavior` parameters, mapping of the
+//! `Browser.download*` events onto the L1 types, and `Browser::wait_for_download`.
+
+use crate::protocol::{DownloadProgressParams, DownloadWillBeginParams, SetDownloadBehaviorParams};
+use janus_interfaces::{ApiError, Download, DownloadBehavior, DownloadProgress, DownloadStarted, DownloadState};
+use std::collections::HashMap;
+use std::path::{self, PathBuf};
+
+/// Parameters for `behavior`, and the directory downloads are saved in, if any.
+pub(crate) fn download_behavior_params(
+    behavior: DownloadBehavior,
+    browser_context_id: Option<String>,
+) -> Result<(SetDownloadBehaviorParams, Option<PathBuf>), ApiError> {
+    let (behavior, download_dir) = match behavior {
+        DownloadBehavior::Default => ("default", None),
+        DownloadBehavior::Deny => ("deny", None),
+        DownloadBehavior::Allow { download_path } => {
+            if !download_path.is_dir() {
+                return Err(ApiError::InvalidParameters(format!(
+                    "Download path is not a directory: {}",
+                    download_path.display()
+                )));
+            }
+            // Chrome resolves relative paths against its own working directory.
+            ("allowAndName", Some(path::absolute(download_path)?))
+        }
+    };
+    let download_path = download_dir
+        .as_ref()
+        .map(|dir| {
+            dir.to_str().map(str::to_string).ok_or_else(|| {
+                ApiError::InvalidParameters(format!("Download path is not valid UTF-8: {}", dir.display()))
+            })
+        })
+        .transpose()?;
+    let params = SetDownloadBehaviorParams {
+        behavior,
+        browser_context_id,
+        download_path,
+        events_enabled: true,
+    };
+    Ok((params, download_dir))
+}
+
+pub(crate) fn download_state_from_cdp(state: &str) -> Option<DownloadState> {
+    match state {
+        "inProgress" => Some(DownloadState::InProgress),
+        "completed" => Some(DownloadState::Completed),
+        "canceled" => Some(DownloadState::Canceled),
+        _ => None,
+    }
+}
+
+pub(crate) fn download_started_from_cdp(params: DownloadWillBeginParams) -> DownloadStarted {
+    DownloadStarted {
+        guid: params.guid,
+        url: params.url,
+        suggested_filename: params.suggested_filename,
+        frame_id: params.frame_id,
+    }
+}
+
+/// Where a download is saved under `allowAndName` (named after its GUID), if the
+/// directory of its context is known. `context_id` is the context of the frame the
+/// download started in (`Some(None)` for the default one), `None` if that is unknown.
+pub(crate) fn saved_path(
+    download_dirs: &HashMap<Option<String>, PathBuf>,
+    context_id: Option<Option<String>>,
+    guid: &str,
+) -> Option<PathBuf> {
+    Some(download_dirs.get(&context_id?)?.join(guid))
+}
+
+/// `saved_path` is where the download is expected under `allowAndName`; Chrome
+/// reports the actual path itself in recent versions.
+pub(crate) fn download_progress_from_cdp(
+    params: DownloadProgressParams,
+    saved_path: Option<PathBuf>,
+) -> Option<DownloadProgress> {
+    let state = download_state_from_cdp(&params.state)?;
+    let path = match state {
+        DownloadState::Completed => params.file_path.map(PathBuf::from).or(saved_path),
+        DownloadState::InProgress | DownloadState::Canceled => None,
+    };
+    Some(DownloadProgress {
+        guid: params.guid,
+        state,
+        received_bytes: params.received_bytes as u64,
+        total_bytes: (params.total_bytes > 0.0).then_some(params.total_bytes as u64),
+        path,
+    })
+}
+
+/// A download event, as delivered to `Browser::wait_for_download`.
+#[derive(Debug)]
+pub(crate) enum DownloadEvent {
+    Started(DownloadStarted),
+    Progress(DownloadProgress),
+}
+
+/// Follows the first download that starts until it completes or is canceled.
+#[derive(Debug, Default)]
+pub(crate) struct DownloadWaiter {
+    started: Option<DownloadStarted>,
+}
+
+impl DownloadWaiter {
+    /// Feeds an event; returns the outcome once the followed download is over.
+    pub(crate) fn on_event(&mut self, event: DownloadEvent) -> Option<Result<Download, ApiError>> {
+        match event {
+            DownloadEvent::Started(started) => {
+                self.started.get_or_insert(started);
+                None
+            }
+            DownloadEvent::Progress(progress) => {
+                let started = self.started.as_ref().filter(|started| started.guid == progress.guid)?;
+                let reason = match (progress.state, progress.path) {
+                    (DownloadState::InProgress, _) => return None,
+                    (DownloadState::Completed, Some(path)) => {
+                        return Some(Ok(Download {
+                            guid: started.guid.clone(),
+                            url: started.url.clone(),
+                            suggested_filename: started.suggested_filename.clone(),
+                            path,
+                        }));
+                    }
+                    (DownloadState::Completed, None) => "not saved; no download path is set",
+                    (DownloadState::Canceled, _) => "canceled",
+                };
+                Some(Err(ApiError::DownloadFailed {
+                    url: started.url.clone(),
+                    reason: reason.to_string(),
+                }))
+            }
+        }
+    }
+}
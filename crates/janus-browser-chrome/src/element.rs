@@ -0,0 +1,297 @@
+//! L2 Implementation of `janus_interfaces::ElementHandle` for Chrome.
+//!
+//! A handle wraps the `objectId` of a DOM node in the main world of its frame. Element
+//! operations run as `Runtime.callFunctionOn` with the node bound to `this`, in the
+//! session of the frame if it is out-of-process; mouse and keyboard interaction goes
+//! through the page's input pipeline.
+
+use crate::actors::{
+    CallFunctionOn, ChromePageActor, DescribeNode, GetBoxModel, ReleaseObject, SetFileInputFiles,
+};
+use crate::error::map_internal_to_api_error;
+use crate::page::ChromePage;
+use crate::protocol::{CallArgument, RemoteObject, SetFileInputFilesParams};
+use crate::screenshot;
+use crate::upload;
+use actix::prelude::*;
+use async_trait::async_trait;
+use janus_interfaces::{
+    ApiError, BoundingBox, ElementHandle, MouseOptions, Page, ScreenshotFormat, ScreenshotOptions,
+};
+use log::debug;
+use serde_json::{Value, json};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Returns the element's box, or null if it has none, after scrolling it into the
+// viewport if `scroll`. Boxes of elements in (same-process) iframes are moved by the
+// offsets of the frames.
+const GET_BOX: &str = "function(scroll) {
+    if (!this.isConnected) return null;
+    if (scroll) this.scrollIntoView({ block: 'center', inline: 'center', behavior: 'instant' });
+    const r = this.getBoundingClientRect();
+    if (r.width === 0 && r.height === 0) return null;
+    let x = r.left, y = r.top;
+    for (let win = this.ownerDocument.defaultView; win && win.frameElement; win = win.parent) {
+        const frame = win.frameElement, f = frame.getBoundingClientRect(), style = getComputedStyle(frame);
+        x += f.left + frame.clientLeft + parseFloat(style.paddingLeft);
+        y += f.top + frame.clientTop + parseFloat(style.paddingTop);
+    }
+    return { x, y, width: r.width, height: r.height };
+}";
+
+const IS_VISIBLE: &str = "function() {
+    if (!this.isConnected) return false;
+    const style = getComputedStyle(this);
+    if (style.visibility === 'hidden' || style.visibility === 'collapse') return false;
+    const r = this.getBoundingClientRect();
+    return r.width > 0 && r.height > 0;
+}";
+
+#[derive(Debug)]
+pub struct ChromeElementHandle {
+    page: ChromePage,
+    session_id: Option<String>, // Session of the out-of-process frame holding the element
+    object_id: String,
+    backend_node_id: i64,
+    description: String,
+    disposed: AtomicBool,
+}
+
+impl ChromeElementHandle {
+    /// Wraps a remote object returned by the page, or by the out-of-process frame
+    /// of `session_id`. Returns `None` for `null` results and non-node objects (the
+    /// latter are released right away).
+    pub(crate) async fn from_remote_object(
+        actor_addr: Addr<ChromePageActor>,
+        page_id: String,
+        session_id: Option<String>,
+        object: RemoteObject,
+    ) -> Result<Option<Self>, ApiError> {
+        let Some(object_id) = object.object_id else {
+            return Ok(None); // null / undefined
+        };
+        if object.subtype.as_deref() != Some("node") {
+            actor_addr.do_send(ReleaseObject { session_id, object_id });
+            return Ok(None);
+        }
+        let backend_node_id = actor_addr
+            .send(DescribeNode { session_id: session_id.clone(), object_id: object_id.clone() })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error describing node: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)?;
+        Ok(Some(Self {
+            page: ChromePage::new(actor_addr, page_id),
+            session_id,
+            object_id,
+            backend_node_id,
+            description: object.description.unwrap_or_else(|| "node".to_string()),
+            disposed: AtomicBool::new(false),
+        }))
+    }
+
+    /// CDP `Runtime.RemoteObjectId` of the element.
+    pub fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    /// CDP `DOM.BackendNodeId` of the element; stable for the lifetime of the node.
+    pub fn backend_node_id(&self) -> i64 {
+        self.backend_node_id
+    }
+
+    // Calls `function_declaration` with the element as `this`.
+    async fn call(
+        &self,
+        function_declaration: &str,
+        args: Vec<Value>,
+        return_by_value: bool,
+    ) -> Result<RemoteObject, ApiError> {
+        if self.disposed.load(Ordering::SeqCst) {
+            return Err(ApiError::InvalidParameters(format!(
+                "Element handle {} is disposed",
+                self.description
+            )));
+        }
+        self.page
+            .actor_addr
+            .send(CallFunctionOn {
+                session_id: self.session_id.clone(),
+                object_id: Some(self.object_id.clone()),
+                function_declaration: function_declaration.to_string(),
+                args: args.into_iter().map(|value| CallArgument { value: Some(value), object_id: None }).collect(),
+                return_by_value,
+            })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error calling function on element: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    pub(crate) async fn call_value(&self, function_declaration: &str, args: Vec<Value>) -> Result<Value, ApiError> {
+        Ok(self.call(function_declaration, args, true).await?.value)
+    }
+
+    // Scrolls into view and returns the center point of the element's box.
+    async fn clickable_point(&self) -> Result<(f64, f64), ApiError> {
+        if self.session_id.is_some() {
+            // Boxes are relative to the frame's own viewport, whose offset is unknown here.
+            return Err(ApiError::NotSupported(format!(
+                "Pointer input on {}, which is in an out-of-process frame",
+                self.description
+            )));
+        }
+        let value = self.call_value(GET_BOX, vec![json!(true)]).await?;
+        let bounding_box = parse_bounding_box(value).ok_or_else(|| {
+            ApiError::InvalidParameters(format!("Element {} is detached or not visible", self.description))
+        })?;
+        Ok((
+            bounding_box.x + bounding_box.width / 2.0,
+            bounding_box.y + bounding_box.height / 2.0,
+        ))
+    }
+}
+
+#[async_trait]
+impl ElementHandle for ChromeElementHandle {
+    fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn remote_object_id(&self) -> Option<String> {
+        (!self.disposed.load(Ordering::SeqCst)).then(|| self.object_id.clone())
+    }
+
+    async fn click(&self) -> Result<(), ApiError> {
+        debug!("ChromeElementHandle ({})::click", self.description);
+        let (x, y) = self.clickable_point().await?;
+        self.page.mouse_click(x, y, MouseOptions::default()).await
+    }
+
+    async fn type_text(&self, text: &str) -> Result<(), ApiError> {
+        self.focus().await?;
+        self.page.keyboard_type(text).await
+    }
+
+    async fn focus(&self) -> Result<(), ApiError> {
+        self.call("function() { this.focus(); }", vec![], true).await?;
+        Ok(())
+    }
+
+    async fn hover(&self) -> Result<(), ApiError> {
+        let (x, y) = self.clickable_point().await?;
+        self.page.mouse_move(x, y).await
+    }
+
+    async fn text_content(&self) -> Result<Option<String>, ApiError> {
+        let value = self.call_value("function() { return this.textContent; }", vec![]).await?;
+        Ok(value.as_str().map(String::from))
+    }
+
+    async fn inner_html(&self) -> Result<String, ApiError> {
+        let value = self.call_value("function() { return this.innerHTML; }", vec![]).await?;
+        Ok(value.as_str().map(String::from).unwrap_or_default())
+    }
+
+    async fn get_attribute(&self, name: &str) -> Result<Option<String>, ApiError> {
+        let value = self
+            .call_value("function(name) { return this.getAttribute(name); }", vec![json!(name)])
+            .await?;
+        Ok(value.as_str().map(String::from))
+    }
+
+    async fn bounding_box(&self) -> Result<Option<BoundingBox>, ApiError> {
+        Ok(parse_bounding_box(self.call_value(GET_BOX, vec![json!(false)]).await?))
+    }
+
+    async fn is_visible(&self) -> Result<bool, ApiError> {
+        Ok(self.call_value(IS_VISIBLE, vec![]).await?.as_bool().unwrap_or(false))
+    }
+
+    async fn screenshot(
+        &self,
+        format: ScreenshotFormat,
+        mut options: ScreenshotOptions,
+    ) -> Result<Vec<u8>, ApiError> {
+        // Scrolling first keeps the capture within the rendered area of lazy pages.
+        self.clickable_point().await?;
+        let box_model = self
+            .page
+            .actor_addr
+            .send(GetBoxModel { backend_node_id: self.backend_node_id })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error getting box model: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)?;
+        let viewport = self.page.layout_metrics().await?.css_layout_viewport;
+        let clip = screenshot::quad_to_clip(&box_model.border, viewport.page_x, viewport.page_y)
+            .ok_or_else(|| ApiError::InvalidParameters(format!("Element {} has an empty box", self.description)))?;
+        options.clip = Some(clip);
+        options.full_page = None;
+        options.capture_beyond_viewport.get_or_insert(true);
+        self.page.take_screenshot(format, options).await
+    }
+
+    async fn set_input_files(&self, files: &[PathBuf]) -> Result<(), ApiError> {
+        debug!("ChromeElementHandle ({})::set_input_files ({} files)", self.description, files.len());
+        let multiple = self.call_value(upload::FILE_INPUT_MULTIPLE, vec![]).await?.as_bool().ok_or_else(|| {
+            ApiError::InvalidParameters(format!("Element {} is not an <input type=file>", self.description))
+        })?;
+        let params = SetFileInputFilesParams {
+            files: upload::input_file_paths(files, multiple)?,
+            backend_node_id: None,
+            object_id: Some(self.object_id.clone()),
+        };
+        self.page
+            .actor_addr
+            .send(SetFileInputFiles { session_id: self.session_id.clone(), params })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error setting input files: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn query_selector(&self, selector: &str) -> Result<Option<Box<dyn ElementHandle>>, ApiError> {
+        let object = self
+            .call("function(selector) { return this.querySelector(selector); }", vec![json!(selector)], false)
+            .await?;
+        let handle = ChromeElementHandle::from_remote_object(
+            self.page.actor_addr.clone(),
+            self.page.id(),
+            self.session_id.clone(),
+            object,
+        )
+        .await?;
+        Ok(handle.map(|handle| Box::new(handle) as Box<dyn ElementHandle>))
+    }
+
+    async fn dispose(&self) -> Result<(), ApiError> {
+        if self.disposed.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+        self.page
+            .actor_addr
+            .send(ReleaseObject { session_id: self.session_id.clone(), object_id: self.object_id.clone() })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error releasing element: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+}
+
+impl Drop for ChromeElementHandle {
+    fn drop(&mut self) {
+        // Fire-and-forget release; the page may already be gone, which is fine.
+        if !self.disposed.swap(true, Ordering::SeqCst) {
+            self.page.actor_addr.do_send(ReleaseObject {
+                session_id: self.session_id.clone(),
+                object_id: self.object_id.clone(),
+            });
+        }
+    }
+}
+
+pub(crate) fn parse_bounding_box(value: Value) -> Option<BoundingBox> {
+    Some(BoundingBox {
+        x: value.get("x")?.as_f64()?,
+        y: value.get("y")?.as_f64()?,
+        width: value.get("width")?.as_f64()?,
+        height: value.get("height")?.as_f64()?,
+    })
+}
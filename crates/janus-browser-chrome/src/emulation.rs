@@ -0,0 +1,126 @@
+//! Translation of L1 device descriptors and emulation settings into `Emulation.*` /
+//! `Network.*` overrides.
+
+use crate::actors::SetEmulation;
+use crate::protocol::{
+    MediaFeature, SetDeviceMetricsOverrideParams, SetEmulatedMediaParams, SetGeolocationOverrideParams,
+    SetLocaleOverrideParams, SetTimezoneOverrideParams, SetTouchEmulationEnabledParams, SetUserAgentOverrideParams,
+};
+use janus_interfaces::{
+    ApiError, BrowserContextOptions, ColorScheme, DeviceDescriptor, Geolocation, MediaEmulation, MediaType,
+    ReducedMotion,
+};
+
+/// Touch points reported by an emulated touch screen.
+const MAX_TOUCH_POINTS: u32 = 5;
+
+/// The three overrides that together emulate a device.
+#[derive(Debug, PartialEq)]
+pub(crate) struct DeviceOverrides {
+    pub metrics: SetDeviceMetricsOverrideParams,
+    pub touch: SetTouchEmulationEnabledParams,
+    pub user_agent: SetUserAgentOverrideParams,
+}
+
+pub(crate) fn device_overrides(device: DeviceDescriptor) -> Result<DeviceOverrides, ApiError> {
+    if device.width == 0 || device.height == 0 {
+        return Err(ApiError::InvalidParameters(format!(
+            "viewport of {} must not be empty, got {}x{}",
+            device.name, device.width, device.height
+        )));
+    }
+    if !device.device_scale_factor.is_finite() || device.device_scale_factor <= 0.0 {
+        return Err(ApiError::InvalidParameters(format!(
+            "device_scale_factor of {} must be positive, got {}",
+            device.name, device.device_scale_factor
+        )));
+    }
+    Ok(DeviceOverrides {
+        metrics: SetDeviceMetricsOverrideParams {
+            width: device.width,
+            height: device.height,
+            device_scale_factor: device.device_scale_factor,
+            mobile: device.is_mobile,
+        },
+        touch: SetTouchEmulationEnabledParams {
+            enabled: device.has_touch,
+            max_touch_points: device.has_touch.then_some(MAX_TOUCH_POINTS),
+        },
+        user_agent: SetUserAgentOverrideParams { user_agent: device.user_agent },
+    })
+}
+
+pub(crate) fn geolocation_params(geolocation: Geolocation) -> Result<SetGeolocationOverrideParams, ApiError> {
+    if !(-90.0..=90.0).contains(&geolocation.latitude) {
+        return Err(ApiError::InvalidParameters(format!(
+            "latitude must be between -90 and 90, got {}",
+            geolocation.latitude
+        )));
+    }
+    if !(-180.0..=180.0).contains(&geolocation.longitude) {
+        return Err(ApiError::InvalidParameters(format!(
+            "longitude must be between -180 and 180, got {}",
+            geolocation.longitude
+        )));
+    }
+    let accuracy = geolocation.accuracy.unwrap_or(0.0);
+    if accuracy.is_nan() || accuracy < 0.0 {
+        return Err(ApiError::InvalidParameters(format!("accuracy must not be negative, got {}", accuracy)));
+    }
+    Ok(SetGeolocationOverrideParams {
+        latitude: geolocation.latitude,
+        longitude: geolocation.longitude,
+        accuracy,
+    })
+}
+
+/// The overrides of a browser context, applied to each of its pages when they are attached.
+pub(crate) fn context_emulation(options: &BrowserContextOptions) -> Result<Vec<SetEmulation>, ApiError> {
+    let mut emulation = Vec::new();
+    if let Some(timezone_id) = &options.timezone_id {
+        emulation.push(SetEmulation::Timezone(SetTimezoneOverrideParams { timezone_id: timezone_id.clone() }));
+    }
+    if let Some(locale) = &options.locale {
+        emulation.push(SetEmulation::Locale(SetLocaleOverrideParams { locale: Some(locale.clone()) }));
+    }
+    if let Some(geolocation) = options.geolocation {
+        emulation.push(SetEmulation::Geolocation(Some(geolocation_params(geolocation)?)));
+    }
+    if options.media != MediaEmulation::default() {
+        emulation.push(SetEmulation::Media(emulated_media_params(options.media)));
+    }
+    Ok(emulation)
+}
+
+/// `Emulation.setEmulatedMedia` replaces all earlier features, so unset ones are
+/// simply left out.
+pub(crate) fn emulated_media_params(media: MediaEmulation) -> SetEmulatedMediaParams {
+    let mut features = Vec::new();
+    if let Some(color_scheme) = media.color_scheme {
+        features.push(MediaFeature {
+            name: "prefers-color-scheme",
+            value: match color_scheme {
+                ColorScheme::Light => "light",
+                ColorScheme::Dark => "dark",
+                ColorScheme::NoPreference => "no-preference",
+            },
+        });
+    }
+    if let Some(reduced_motion) = media.reduced_motion {
+        features.push(MediaFeature {
+            name: "prefers-reduced-motion",
+            value: match reduced_motion {
+                ReducedMotion::Reduce => "reduce",
+                ReducedMotion::NoPreference => "no-preference",
+            },
+        });
+    }
+    SetEmulatedMediaParams {
+        media: match media.media_type {
+            Some(MediaType::Screen) => "screen",
+            Some(MediaType::Print) => "print",
+            None => "",
+        },
+        features,
+    }
+}
@@ -13,7 +13,15 @@ pub(crate) fn map_internal_to_api_error(internal_error: InternalError) -> ApiErr
         InternalError::Actor(actor_err) => {
             ApiError::InternalError(format!("Internal actor error: {}", actor_err))
         }
-        InternalError::Timeout => ApiError::Timeout,
+        InternalError::Timeout => ApiError::Timeout { last_observed: None },
+        InternalError::Navigation { url, reason } => ApiError::NavigationFailed { url, reason },
+        InternalError::Evaluation { message, url, line_number, column_number, stack } => ApiError::Evaluation {
+            message,
+            url,
+            line_number,
+            column_number,
+            stack,
+        },
         InternalError::BrowserProcessDied => ApiError::BrowserCrashed,
         InternalError::InvalidParams(msg) => ApiError::InvalidParameters(msg),
         InternalError::Serialization(msg) | InternalError::Deserialization(msg) => {
@@ -0,0 +1,352 @@
+//! Mapping of CDP events onto the L1 event types, and the registry holding
+//! handlers registered through the L1 `on_*` subscription methods.
+
+use crate::protocol::*;
+use janus_interfaces::{
+    ConsoleLogLevel, ConsoleMessage, DialogInfo, DialogType, DownloadProgress, DownloadStarted, EventHandler,
+    FileChooser, LifecycleEvent, NetworkRequest, NetworkResponse, PageError, RequestFailed, RequestFinished,
+    SubscriptionId, TargetCreatedEvent, TargetType,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+
+// ================= Handler Registry =================
+
+/// Handlers registered on a page, keyed by the `SubscriptionId` handed to the user.
+pub(crate) enum PageEventHandler {
+    Console(EventHandler<ConsoleMessage>),
+    Lifecycle(EventHandler<LifecycleEvent>),
+    PageError(EventHandler<PageError>),
+    Dialog(EventHandler<DialogInfo>),
+    Request(EventHandler<NetworkRequest>),
+    Response(EventHandler<NetworkResponse>),
+    RequestFinished(EventHandler<RequestFinished>),
+    RequestFailed(EventHandler<RequestFailed>),
+    FileChooser(EventHandler<FileChooser>),
+}
+
+impl fmt::Debug for PageEventHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            PageEventHandler::Console(_) => "Console",
+            PageEventHandler::Lifecycle(_) => "Lifecycle",
+            PageEventHandler::PageError(_) => "PageError",
+            PageEventHandler::Dialog(_) => "Dialog",
+            PageEventHandler::Request(_) => "Request",
+            PageEventHandler::Response(_) => "Response",
+            PageEventHandler::RequestFinished(_) => "RequestFinished",
+            PageEventHandler::RequestFailed(_) => "RequestFailed",
+            PageEventHandler::FileChooser(_) => "FileChooser",
+        };
+        write!(f, "PageEventHandler::{}", kind)
+    }
+}
+
+/// Handlers registered on a browser through the L1 `Browser::on_*` methods.
+pub(crate) enum BrowserEventHandler {
+    Created(EventHandler<TargetCreatedEvent>),
+    Changed(EventHandler<janus_interfaces::TargetInfo>),
+    Destroyed(EventHandler<janus_interfaces::TargetInfo>),
+    DownloadStarted(EventHandler<DownloadStarted>),
+    DownloadProgress(EventHandler<DownloadProgress>),
+}
+
+impl fmt::Debug for BrowserEventHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            BrowserEventHandler::Created(_) => "Created",
+            BrowserEventHandler::Changed(_) => "Changed",
+            BrowserEventHandler::Destroyed(_) => "Destroyed",
+            BrowserEventHandler::DownloadStarted(_) => "DownloadStarted",
+            BrowserEventHandler::DownloadProgress(_) => "DownloadProgress",
+        };
+        write!(f, "BrowserEventHandler::{}", kind)
+    }
+}
+
+/// Simple ID -> handler map used by actors that expose L1 event subscriptions.
+#[derive(Debug)]
+pub(crate) struct HandlerRegistry<H> {
+    next_id: SubscriptionId,
+    handlers: HashMap<SubscriptionId, H>,
+}
+
+impl<H> Default for HandlerRegistry<H> {
+    fn default() -> Self {
+        Self {
+            next_id: 1,
+            handlers: HashMap::new(),
+        }
+    }
+}
+
+impl<H> HandlerRegistry<H> {
+    pub(crate) fn insert(&mut self, handler: H) -> SubscriptionId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.handlers.insert(id, handler);
+        id
+    }
+
+    pub(crate) fn remove(&mut self, id: SubscriptionId) -> bool {
+        self.handlers.remove(&id).is_some()
+    }
+
+    pub(crate) fn values(&self) -> impl Iterator<Item = &H> {
+        self.handlers.values()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (SubscriptionId, &H)> {
+        self.handlers.iter().map(|(id, handler)| (*id, handler))
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.handlers.clear();
+    }
+}
+
+// ================= CDP -> L1 Conversion =================
+
+/// Maps a `Runtime.consoleAPICalled` type onto the L1 log level.
+pub(crate) fn console_level_from_cdp(type_: &str) -> ConsoleLogLevel {
+    match type_ {
+        "debug" => ConsoleLogLevel::Debug,
+        "info" => ConsoleLogLevel::Info,
+        "warning" => ConsoleLogLevel::Warning,
+        "error" | "assert" => ConsoleLogLevel::Error,
+        // log, dir, table, trace, count, timeEnd, startGroup, ...
+        _ => ConsoleLogLevel::Log,
+    }
+}
+
+pub(crate) fn console_message_from_cdp(params: &ConsoleApiCalledParams) -> ConsoleMessage {
+    let text = params
+        .args
+        .iter()
+        .map(remote_object_to_text)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let top_frame = params
+        .stack_trace
+        .as_ref()
+        .and_then(|trace| trace.call_frames.first());
+    ConsoleMessage {
+        level: console_level_from_cdp(&params.type_),
+        text,
+        url: top_frame
+            .map(|frame| frame.url.clone())
+            .filter(|url| !url.is_empty()),
+        line_number: top_frame.map(|frame| frame.line_number as u32),
+        column_number: top_frame.map(|frame| frame.column_number as u32),
+    }
+}
+
+pub(crate) fn lifecycle_event_from_cdp(params: LifecycleEventParams) -> LifecycleEvent {
+    LifecycleEvent {
+        name: params.name,
+        frame_id: params.frame_id,
+        timestamp: params.timestamp,
+    }
+}
+
+pub(crate) fn page_error_from_cdp(details: &ExceptionDetails) -> PageError {
+    // `exception.description` carries "TypeError: msg\n    at ..." for Error objects,
+    // while `text` is just "Uncaught" in that case.
+    let description = details
+        .exception
+        .as_ref()
+        .map(remote_object_to_text)
+        .filter(|text| !text.is_empty());
+    let message = match &description {
+        Some(description) => format!(
+            "{} {}",
+            details.text,
+            description.lines().next().unwrap_or_default()
+        ),
+        None => details.text.clone(),
+    };
+    PageError {
+        message,
+        url: details.url.clone().filter(|url| !url.is_empty()),
+        line_number: Some(details.line_number as u32),
+        column_number: Some(details.column_number as u32),
+        stack: details.stack_trace.as_ref().map(format_stack_trace),
+    }
+}
+
+pub(crate) fn dialog_type_from_cdp(type_: &str) -> Option<DialogType> {
+    match type_ {
+        "alert" => Some(DialogType::Alert),
+        "confirm" => Some(DialogType::Confirm),
+        "prompt" => Some(DialogType::Prompt),
+        "beforeunload" => Some(DialogType::BeforeUnload),
+        _ => None,
+    }
+}
+
+/// Dialogs of a type unknown to this version are reported as alerts.
+pub(crate) fn dialog_info_from_cdp(params: JavascriptDialogOpeningParams) -> DialogInfo {
+    DialogInfo {
+        dialog_type: dialog_type_from_cdp(&params.type_).unwrap_or(DialogType::Alert),
+        message: params.message,
+        default_prompt: params.default_prompt,
+        url: params.url,
+    }
+}
+
+/// Page error reported for a dialog under `DialogPolicy::Error`.
+pub(crate) fn unexpected_dialog_error(dialog: &DialogInfo) -> PageError {
+    PageError {
+        message: format!("Unexpected {:?} dialog: {}", dialog.dialog_type, dialog.message),
+        url: Some(dialog.url.clone()).filter(|url| !url.is_empty()),
+        line_number: None,
+        column_number: None,
+        stack: None,
+    }
+}
+
+pub(crate) fn target_type_from_cdp(type_: &str) -> TargetType {
+    match type_ {
+        "page" => TargetType::Page,
+        "background_page" => TargetType::BackgroundPage,
+        "iframe" => TargetType::Iframe,
+        "worker" => TargetType::Worker,
+        "shared_worker" => TargetType::SharedWorker,
+        "service_worker" => TargetType::ServiceWorker,
+        "browser" => TargetType::Browser,
+        _ => TargetType::Other,
+    }
+}
+
+pub(crate) fn target_info_from_cdp(info: &TargetInfo) -> janus_interfaces::TargetInfo {
+    janus_interfaces::TargetInfo {
+        target_id: info.target_id.clone(),
+        target_type: target_type_from_cdp(&info.type_),
+        title: info.title.clone(),
+        url: info.url.clone(),
+        attached: info.attached,
+        opener_id: info.opener_id.clone(),
+        browser_context_id: info.browser_context_id.clone(),
+    }
+}
+
+pub(crate) fn resource_timing_from_cdp(timing: &ResourceTiming) -> janus_interfaces::ResourceTiming {
+    janus_interfaces::ResourceTiming {
+        request_time: timing.request_time,
+        dns_start: timing.dns_start,
+        dns_end: timing.dns_end,
+        connect_start: timing.connect_start,
+        connect_end: timing.connect_end,
+        ssl_start: timing.ssl_start,
+        ssl_end: timing.ssl_end,
+        send_start: timing.send_start,
+        send_end: timing.send_end,
+        receive_headers_end: timing.receive_headers_end,
+    }
+}
+
+pub(crate) fn network_response_from_cdp(
+    request_id: &str,
+    resource_type: Option<String>,
+    timestamp: f64,
+    response: &Response,
+) -> NetworkResponse {
+    NetworkResponse {
+        request_id: request_id.to_string(),
+        url: response.url.clone(),
+        status: u16::try_from(response.status).unwrap_or_default(),
+        status_text: response.status_text.clone(),
+        headers: response.headers.clone(),
+        mime_type: response.mime_type.clone(),
+        resource_type,
+        remote_ip_address: response.remote_ip_address.clone().filter(|ip| !ip.is_empty()),
+        remote_port: response.remote_port.and_then(|port| u16::try_from(port).ok()),
+        protocol: response.protocol.clone(),
+        from_disk_cache: response.from_disk_cache,
+        from_service_worker: response.from_service_worker,
+        timing: response.timing.as_ref().map(resource_timing_from_cdp),
+        timestamp,
+    }
+}
+
+pub(crate) fn network_request_from_cdp(params: &RequestWillBeSentParams) -> NetworkRequest {
+    let request = &params.request;
+    NetworkRequest {
+        request_id: params.request_id.clone(),
+        url: match &request.url_fragment {
+            Some(fragment) => format!("{}{}", request.url, fragment),
+            None => request.url.clone(),
+        },
+        method: request.method.clone(),
+        headers: request.headers.clone(),
+        post_data: request.post_data.clone(),
+        resource_type: params.type_.clone(),
+        frame_id: params.frame_id.clone(),
+        document_url: params.document_url.clone(),
+        timestamp: params.timestamp,
+        wall_time: params.wall_time,
+        redirect_response: params.redirect_response.as_ref().map(|response| {
+            network_response_from_cdp(&params.request_id, params.type_.clone(), params.timestamp, response)
+        }),
+    }
+}
+
+pub(crate) fn request_finished_from_cdp(params: LoadingFinishedParams) -> RequestFinished {
+    RequestFinished {
+        request_id: params.request_id,
+        timestamp: params.timestamp,
+        encoded_data_length: params.encoded_data_length,
+    }
+}
+
+pub(crate) fn request_failed_from_cdp(params: LoadingFailedParams) -> RequestFailed {
+    RequestFailed {
+        request_id: params.request_id,
+        timestamp: params.timestamp,
+        error_text: params.error_text,
+        canceled: params.canceled.unwrap_or(false),
+        blocked_reason: params.blocked_reason,
+        resource_type: Some(params.type_),
+    }
+}
+
+/// Renders a `RemoteObject` the way DevTools would print it in the console.
+pub(crate) fn remote_object_to_text(object: &RemoteObject) -> String {
+    match &object.value {
+        Value::String(text) => text.clone(),
+        Value::Null if object.subtype.as_deref() == Some("null") => "null".to_string(),
+        Value::Null => object
+            .description
+            .clone()
+            .unwrap_or_else(|| object.type_.clone()), // e.g. "undefined"
+        other => other.to_string(),
+    }
+}
+
+/// Formats a CDP stack trace like V8's `Error.stack` frames.
+pub(crate) fn format_stack_trace(trace: &StackTrace) -> String {
+    trace
+        .call_frames
+        .iter()
+        .map(|frame| {
+            let function_name = if frame.function_name.is_empty() {
+                "<anonymous>"
+            } else {
+                frame.function_name.as_str()
+            };
+            format!(
+                "    at {} ({}:{}:{})",
+                function_name,
+                frame.url,
+                frame.line_number + 1,
+                frame.column_number + 1
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,157 @@
+//! Request interception on top of the CDP `Fetch` domain: route bookkeeping, URL
+//! pattern matching, and the mapping of L1 route actions onto `Fetch.*` commands.
+
+use crate::protocol::{
+    ContinueRequestParams, FailRequestParams, FulfillRequestParams, HeaderEntry, RequestPattern,
+    RequestPausedParams,
+};
+use base64::prelude::*;
+use janus_interfaces::{AbortReason, InterceptedRequest, RouteAction, RouteHandler};
+use serde_json::Value;
+use std::fmt;
+
+/// A route registered through `Page::route`.
+pub(crate) struct Route {
+    pub pattern: String,
+    pub handler: RouteHandler,
+}
+
+impl fmt::Debug for Route {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Route({})", self.pattern)
+    }
+}
+
+/// `Fetch.enable` patterns for the registered routes, all at the request stage.
+pub(crate) fn request_patterns<'a>(patterns: impl Iterator<Item = &'a str>) -> Vec<RequestPattern> {
+    let mut request_patterns: Vec<RequestPattern> = Vec::new();
+    for pattern in patterns {
+        if !request_patterns.iter().any(|existing| existing.url_pattern == pattern) {
+            request_patterns.push(RequestPattern {
+                url_pattern: pattern.to_string(),
+                request_stage: Some("Request"),
+            });
+        }
+    }
+    request_patterns
+}
+
+/// Matches `url` against a `Fetch` URL pattern: `*` matches any run of characters,
+/// `?` exactly one, and `\` escapes the next character.
+pub(crate) fn url_pattern_matches(pattern: &str, url: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let url: Vec<char> = url.chars().collect();
+    // Iterative wildcard matching with backtracking to the last `*`.
+    let (mut p, mut u) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while u < url.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, u));
+                p += 1;
+                continue;
+            }
+            Some('?') => {
+                p += 1;
+                u += 1;
+                continue;
+            }
+            Some('\\') if pattern.get(p + 1) == Some(&url[u]) => {
+                p += 2;
+                u += 1;
+                continue;
+            }
+            Some(&c) if c != '\\' && c == url[u] => {
+                p += 1;
+                u += 1;
+                continue;
+            }
+            _ => {}
+        }
+        match backtrack {
+            Some((star, matched)) => {
+                p = star + 1;
+                u = matched + 1;
+                backtrack = Some((star, matched + 1));
+            }
+            None => return false,
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+pub(crate) fn intercepted_request_from_cdp(params: &RequestPausedParams) -> InterceptedRequest {
+    let request = &params.request;
+    InterceptedRequest {
+        url: match &request.url_fragment {
+            Some(fragment) => format!("{}{}", request.url, fragment),
+            None => request.url.clone(),
+        },
+        method: request.method.clone(),
+        headers: request.headers.clone(),
+        post_data: request.post_data.clone(),
+        resource_type: params.resource_type.clone(),
+        frame_id: Some(params.frame_id.clone()).filter(|id| !id.is_empty()),
+    }
+}
+
+/// `Network.ErrorReason` for an abort.
+pub(crate) fn abort_reason_to_cdp(reason: AbortReason) -> &'static str {
+    match reason {
+        AbortReason::Failed => "Failed",
+        AbortReason::Aborted => "Aborted",
+        AbortReason::TimedOut => "TimedOut",
+        AbortReason::AccessDenied => "AccessDenied",
+        AbortReason::ConnectionClosed => "ConnectionClosed",
+        AbortReason::ConnectionReset => "ConnectionReset",
+        AbortReason::ConnectionRefused => "ConnectionRefused",
+        AbortReason::ConnectionAborted => "ConnectionAborted",
+        AbortReason::ConnectionFailed => "ConnectionFailed",
+        AbortReason::NameNotResolved => "NameNotResolved",
+        AbortReason::InternetDisconnected => "InternetDisconnected",
+        AbortReason::AddressUnreachable => "AddressUnreachable",
+        AbortReason::BlockedByClient => "BlockedByClient",
+        AbortReason::BlockedByResponse => "BlockedByResponse",
+    }
+}
+
+fn header_entries(headers: Vec<(String, String)>) -> Vec<HeaderEntry> {
+    headers
+        .into_iter()
+        .map(|(name, value)| HeaderEntry { name, value })
+        .collect()
+}
+
+/// The `Fetch` command (method and params) that resumes a paused request as decided.
+pub(crate) fn route_action_command(request_id: String, action: RouteAction) -> (&'static str, Value) {
+    let (method, params) = match action {
+        RouteAction::Continue(overrides) => (
+            "Fetch.continueRequest",
+            serde_json::to_value(ContinueRequestParams {
+                request_id,
+                url: overrides.url,
+                method: overrides.method,
+                post_data: overrides.post_data.map(|data| BASE64_STANDARD.encode(data)),
+                headers: overrides.headers.map(header_entries),
+            }),
+        ),
+        RouteAction::Fulfill(response) => (
+            "Fetch.fulfillRequest",
+            serde_json::to_value(FulfillRequestParams {
+                request_id,
+                response_code: response.status,
+                response_headers: header_entries(response.headers),
+                body: BASE64_STANDARD.encode(response.body),
+            }),
+        ),
+        RouteAction::Abort(reason) => (
+            "Fetch.failRequest",
+            serde_json::to_value(FailRequestParams {
+                request_id,
+                error_reason: abort_reason_to_cdp(reason),
+            }),
+        ),
+    };
+    // These params only hold strings and numbers, so serialization cannot fail.
+    (method, params.unwrap_or_default())
+}
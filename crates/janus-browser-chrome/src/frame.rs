@@ -0,0 +1,111 @@
+//! L2 Implementation of `janus_interfaces::Frame` for Chrome.
+//!
+//! The frame tree is tracked by the page actor; a handle only holds the frame's ID.
+//! Scripts run in the frame's main world, in the session of its renderer process
+//! when the frame is out-of-process.
+
+use crate::actors::{ChromePageActor, EvaluateInFrame, GetFrames};
+use crate::element::ChromeElementHandle;
+use crate::error::map_internal_to_api_error;
+use crate::frames::FrameInfo;
+use crate::protocol::RemoteObject;
+use actix::prelude::*;
+use async_trait::async_trait;
+use janus_interfaces::{ApiError, ElementHandle, Frame};
+use log::debug;
+use serde_json::Value;
+
+#[derive(Debug)]
+pub struct ChromeFrame {
+    actor_addr: Addr<ChromePageActor>,
+    page_id: String,
+    frame_id: String,
+    parent_id: Option<String>,
+}
+
+impl ChromeFrame {
+    pub(crate) fn new(actor_addr: Addr<ChromePageActor>, page_id: String, info: FrameInfo) -> Self {
+        Self {
+            actor_addr,
+            page_id,
+            frame_id: info.id,
+            parent_id: info.parent_id,
+        }
+    }
+
+    // Current state of the frame, or None once it is detached.
+    async fn info(&self) -> Result<Option<FrameInfo>, ApiError> {
+        let frames = self
+            .actor_addr
+            .send(GetFrames)
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error getting frames: {}", mb_err)))?;
+        Ok(frames.into_iter().find(|frame| frame.id == self.frame_id))
+    }
+
+    async fn attached_info(&self) -> Result<FrameInfo, ApiError> {
+        self.info()
+            .await?
+            .ok_or_else(|| ApiError::InvalidParameters(format!("Frame {} is detached", self.frame_id)))
+    }
+
+    async fn evaluate(&self, script: String, return_by_value: bool) -> Result<(RemoteObject, Option<String>), ApiError> {
+        self.actor_addr
+            .send(EvaluateInFrame {
+                frame_id: self.frame_id.clone(),
+                script,
+                return_by_value,
+            })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error evaluating in frame: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+}
+
+#[async_trait]
+impl Frame for ChromeFrame {
+    fn id(&self) -> String {
+        self.frame_id.clone()
+    }
+
+    fn parent_id(&self) -> Option<String> {
+        self.parent_id.clone()
+    }
+
+    async fn name(&self) -> Result<String, ApiError> {
+        Ok(self.attached_info().await?.name)
+    }
+
+    async fn url(&self) -> Result<String, ApiError> {
+        Ok(self.attached_info().await?.url)
+    }
+
+    async fn content(&self) -> Result<String, ApiError> {
+        debug!("ChromeFrame ({})::content", self.frame_id);
+        let (object, _) = self.evaluate("document.documentElement.outerHTML".to_string(), true).await?;
+        object.value.as_str().map(String::from).ok_or_else(|| {
+            ApiError::InternalError("Failed to get string content from evaluation".to_string())
+        })
+    }
+
+    async fn evaluate_script(&self, script: &str) -> Result<Value, ApiError> {
+        debug!("ChromeFrame ({})::evaluate_script", self.frame_id);
+        Ok(self.evaluate(script.to_string(), true).await?.0.value)
+    }
+
+    async fn query_selector(&self, selector: &str) -> Result<Option<Box<dyn ElementHandle>>, ApiError> {
+        debug!("ChromeFrame ({})::query_selector {}", self.frame_id, selector);
+        let selector_literal = serde_json::to_string(selector)
+            .map_err(|e| ApiError::InternalError(format!("Failed to encode selector: {}", e)))?;
+        let script = format!("document.querySelector({})", selector_literal);
+        let (object, session_id) = self.evaluate(script, false).await?;
+        let handle =
+            ChromeElementHandle::from_remote_object(self.actor_addr.clone(), self.page_id.clone(), session_id, object)
+                .await?;
+        Ok(handle.map(|handle| Box::new(handle) as Box<dyn ElementHandle>))
+    }
+
+    async fn is_detached(&self) -> Result<bool, ApiError> {
+        Ok(self.info().await?.is_none())
+    }
+}
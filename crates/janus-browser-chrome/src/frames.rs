@@ -0,0 +1,177 @@
+//! Frame tree of a page, spanning the page's own session and the sessions of its
+//! out-of-process iframes, together with the main world execution context of each
+//! frame.
+
+use crate::protocol::{ExecutionContextDescription, Frame, FrameTree};
+use janus_core::error::InternalError;
+use std::collections::HashMap;
+
+/// Last known state of a frame.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FrameInfo {
+    pub(crate) id: String,
+    pub(crate) parent_id: Option<String>,
+    pub(crate) name: String,
+    pub(crate) url: String,
+    pub(crate) session_id: Option<String>, // None for the page's own session
+}
+
+/// Where to evaluate scripts for a frame: a session (None for the page's own) and an
+/// execution context (None for the default context of that session).
+pub(crate) type ExecutionTarget = (Option<String>, Option<i64>);
+
+#[derive(Debug, Default)]
+pub(crate) struct FrameTracker {
+    frames: Vec<FrameInfo>, // In attach order
+    // Frame ID -> session and ID of the frame's main world context
+    contexts: HashMap<String, (Option<String>, i64)>,
+}
+
+impl FrameTracker {
+    /// Records a `Page.getFrameTree` result of `session_id`.
+    pub(crate) fn load_tree(&mut self, tree: &FrameTree, session_id: Option<&str>) {
+        self.navigated(&tree.frame, session_id);
+        for child in &tree.child_frames {
+            self.load_tree(child, session_id);
+        }
+    }
+
+    pub(crate) fn attached(&mut self, frame_id: &str, parent_id: &str, session_id: Option<&str>) {
+        if self.get(frame_id).is_some() {
+            return;
+        }
+        self.frames.push(FrameInfo {
+            id: frame_id.to_string(),
+            parent_id: Some(parent_id.to_string()),
+            name: String::new(),
+            url: String::new(),
+            session_id: session_id.map(str::to_string),
+        });
+    }
+
+    pub(crate) fn navigated(&mut self, frame: &Frame, session_id: Option<&str>) {
+        let url = format!("{}{}", frame.url, frame.url_fragment.as_deref().unwrap_or_default());
+        let name = frame.name.clone().unwrap_or_default();
+        match self.frames.iter_mut().find(|info| info.id == frame.id) {
+            Some(info) => {
+                // The root frame of an iframe's own session has no parent there.
+                if frame.parent_id.is_some() {
+                    info.parent_id = frame.parent_id.clone();
+                }
+                info.name = name;
+                info.url = url;
+                info.session_id = session_id.map(str::to_string);
+            }
+            None => self.frames.push(FrameInfo {
+                id: frame.id.clone(),
+                parent_id: frame.parent_id.clone(),
+                name,
+                url,
+                session_id: session_id.map(str::to_string),
+            }),
+        }
+    }
+
+    /// Handles `Page.frameDetached`. A swapped frame lives on in another process,
+    /// whose session is attached next.
+    pub(crate) fn detached(&mut self, frame_id: &str, swapped: bool) {
+        if !swapped {
+            self.remove_subtrees(|frame| frame.id == frame_id);
+        }
+    }
+
+    /// Forgets the frames of an iframe session that went away.
+    pub(crate) fn session_detached(&mut self, session_id: &str) {
+        self.remove_subtrees(|frame| frame.session_id.as_deref() == Some(session_id));
+        self.contexts
+            .retain(|_, (context_session, _)| context_session.as_deref() != Some(session_id));
+    }
+
+    pub(crate) fn context_created(&mut self, context: &ExecutionContextDescription, session_id: Option<&str>) {
+        if let Some(aux_data) = &context.aux_data
+            && aux_data.is_default
+            && let Some(frame_id) = &aux_data.frame_id
+        {
+            self.contexts
+                .insert(frame_id.clone(), (session_id.map(str::to_string), context.id));
+        }
+    }
+
+    pub(crate) fn context_destroyed(&mut self, context_id: i64, session_id: Option<&str>) {
+        self.contexts
+            .retain(|_, (context_session, id)| !(*id == context_id && context_session.as_deref() == session_id));
+    }
+
+    pub(crate) fn contexts_cleared(&mut self, session_id: Option<&str>) {
+        self.contexts
+            .retain(|_, (context_session, _)| context_session.as_deref() != session_id);
+    }
+
+    /// IDs of the known main world contexts of the frames of `session_id`.
+    pub(crate) fn session_contexts(&self, session_id: Option<&str>) -> Vec<i64> {
+        self.contexts
+            .values()
+            .filter(|(context_session, _)| context_session.as_deref() == session_id)
+            .map(|(_, id)| *id)
+            .collect()
+    }
+
+    pub(crate) fn get(&self, frame_id: &str) -> Option<&FrameInfo> {
+        self.frames.iter().find(|frame| frame.id == frame_id)
+    }
+
+    /// All frames, parents before their children.
+    pub(crate) fn in_tree_order(&self) -> Vec<FrameInfo> {
+        let mut ordered = Vec::with_capacity(self.frames.len());
+        let roots = self
+            .frames
+            .iter()
+            .filter(|frame| frame.parent_id.as_deref().is_none_or(|parent| self.get(parent).is_none()));
+        for root in roots {
+            self.push_subtree(root, &mut ordered);
+        }
+        ordered
+    }
+
+    /// Where scripts for the frame run. Fails if the frame is gone, or is between
+    /// documents (its context is not known).
+    pub(crate) fn execution_target(&self, frame_id: &str) -> Result<ExecutionTarget, InternalError> {
+        let frame = self
+            .get(frame_id)
+            .ok_or_else(|| InternalError::InvalidParams(format!("Frame {} is detached", frame_id)))?;
+        if let Some((session_id, context_id)) = self.contexts.get(frame_id) {
+            return Ok((session_id.clone(), Some(*context_id)));
+        }
+        // The root frame of a session is evaluated in by default.
+        let parent_session = frame.parent_id.as_deref().and_then(|parent| self.get(parent)).map(|parent| &parent.session_id);
+        if frame.parent_id.is_none() || parent_session != Some(&frame.session_id) {
+            return Ok((frame.session_id.clone(), None));
+        }
+        Err(InternalError::InvalidParams(format!("Frame {} has no document yet", frame_id)))
+    }
+
+    fn push_subtree(&self, frame: &FrameInfo, ordered: &mut Vec<FrameInfo>) {
+        ordered.push(frame.clone());
+        for child in self.frames.iter().filter(|child| child.parent_id.as_deref() == Some(frame.id.as_str())) {
+            self.push_subtree(child, ordered);
+        }
+    }
+
+    // Removes the matching frames together with their descendants.
+    fn remove_subtrees(&mut self, matches: impl Fn(&FrameInfo) -> bool) {
+        let mut removed: Vec<String> = self.frames.iter().filter(|frame| matches(frame)).map(|frame| frame.id.clone()).collect();
+        let mut index = 0;
+        while index < removed.len() {
+            let parent = removed[index].clone();
+            removed.extend(
+                self.frames
+                    .iter()
+                    .filter(|frame| frame.parent_id.as_deref() == Some(parent.as_str()))
+                    .map(|frame| frame.id.clone()),
+            );
+            index += 1;
+        }
+        self.frames.retain(|frame| !removed.contains(&frame.id));
+        self.contexts.retain(|frame_id, _| !removed.contains(frame_id));
+    }
+}
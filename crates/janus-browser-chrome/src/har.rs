@@ -0,0 +1,512 @@
+//! HAR recording from the page's `Network.*` events, and HAR replay on top of
+//! request interception.
+
+use crate::fetch;
+use crate::protocol::{
+    LoadingFailedParams, LoadingFinishedParams, Request, RequestWillBeSentParams, ResourceTiming,
+    Response, ResponseReceivedParams,
+};
+use base64::prelude::*;
+use janus_interfaces::{
+    AbortReason, ContinueOverrides, FulfillResponse, Har, HarCache, HarContent, HarCookie, HarCreator,
+    HarEntry, HarHeader, HarLog, HarNotFound, HarPage, HarPageTimings, HarPostData, HarQueryParam,
+    HarRecordOptions, HarReplayOptions, HarRequest, HarResponse, HarTimings, HarUrlMatch,
+    InterceptedRequest, RouteAction, RouteHandler,
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, PoisonError};
+use url::Url;
+
+const HAR_VERSION: &str = "1.2";
+
+// Headers that describe the encoded transfer; replayed bodies are already decoded.
+const TRANSFER_HEADERS: [&str; 3] = ["content-encoding", "content-length", "transfer-encoding"];
+
+#[derive(Debug)]
+struct RecordedPage {
+    page: HarPage,
+    loader_id: String,
+    start: f64, // Monotonic `Network` timestamp in seconds
+}
+
+#[derive(Debug)]
+struct RecordedEntry {
+    request_id: String,
+    entry: HarEntry,
+    start: f64, // Monotonic `Network` timestamp in seconds
+    timing: Option<ResourceTiming>,
+    has_response: bool,
+    awaiting_body: bool,
+}
+
+impl RecordedEntry {
+    fn set_response(&mut self, response: &Response) {
+        self.entry.response = har_response(response);
+        self.entry.request.http_version = self.entry.response.http_version.clone();
+        self.entry.server_ip_address = response.remote_ip_address.clone().filter(|ip| !ip.is_empty());
+        self.entry.connection = response.remote_port.map(|port| port.to_string());
+        self.timing = response.timing;
+        self.has_response = true;
+    }
+
+    fn finish(&mut self, timestamp: f64) {
+        self.entry.timings = har_timings(self.timing.as_ref(), self.start, timestamp);
+        self.entry.time = total_time(&self.entry.timings);
+    }
+}
+
+/// Collects the page's traffic while a HAR recording is in progress. Fed by the page
+/// actor with the events of the page's session.
+#[derive(Debug)]
+pub(crate) struct HarRecorder {
+    options: HarRecordOptions,
+    main_frame_id: String,
+    pages: Vec<RecordedPage>,
+    entries: Vec<RecordedEntry>,
+    in_flight: HashMap<String, usize>, // Request ID -> index into `entries`
+}
+
+impl HarRecorder {
+    pub(crate) fn new(options: HarRecordOptions, main_frame_id: String) -> Self {
+        Self {
+            options,
+            main_frame_id,
+            pages: Vec::new(),
+            entries: Vec::new(),
+            in_flight: HashMap::new(),
+        }
+    }
+
+    /// Starts an entry for the request. A redirect first completes the entry of the
+    /// previous hop, which shares the request ID.
+    pub(crate) fn request_will_be_sent(&mut self, params: &RequestWillBeSentParams) {
+        if let Some(redirect) = &params.redirect_response
+            && let Some(index) = self.in_flight.remove(&params.request_id)
+        {
+            let recorded = &mut self.entries[index];
+            recorded.set_response(redirect);
+            recorded.entry.response.redirect_url = params.request.url.clone();
+            recorded.finish(params.timestamp);
+        }
+        if let Some(pattern) = &self.options.url_pattern
+            && !fetch::url_pattern_matches(pattern, &params.request.url)
+        {
+            return;
+        }
+        let is_navigation = params.type_.as_deref() == Some("Document")
+            && params.frame_id.as_deref() == Some(self.main_frame_id.as_str())
+            && params.request_id == params.loader_id;
+        if is_navigation && params.redirect_response.is_none() {
+            self.pages.push(RecordedPage {
+                page: HarPage {
+                    started_date_time: format_date_time(params.wall_time),
+                    id: format!("page_{}", self.pages.len() + 1),
+                    title: params.request.url.clone(),
+                    page_timings: HarPageTimings::default(),
+                },
+                loader_id: params.loader_id.clone(),
+                start: params.timestamp,
+            });
+        }
+        let entry = HarEntry {
+            pageref: self.pages.last().map(|page| page.page.id.clone()),
+            started_date_time: format_date_time(params.wall_time),
+            time: 0.0,
+            request: har_request(&params.request),
+            response: HarResponse {
+                status: 0,
+                status_text: String::new(),
+                http_version: String::new(),
+                cookies: Vec::new(),
+                headers: Vec::new(),
+                content: HarContent::default(),
+                redirect_url: String::new(),
+                headers_size: -1,
+                body_size: -1,
+                error: None,
+            },
+            cache: HarCache::default(),
+            timings: HarTimings::default(),
+            server_ip_address: None,
+            connection: None,
+        };
+        self.in_flight.insert(params.request_id.clone(), self.entries.len());
+        self.entries.push(RecordedEntry {
+            request_id: params.request_id.clone(),
+            entry,
+            start: params.timestamp,
+            timing: None,
+            has_response: false,
+            awaiting_body: false,
+        });
+    }
+
+    pub(crate) fn response_received(&mut self, params: &ResponseReceivedParams) {
+        if let Some(&index) = self.in_flight.get(&params.request_id) {
+            self.entries[index].set_response(&params.response);
+        }
+    }
+
+    /// Completes the request's entry. Returns true if its body should be recorded.
+    pub(crate) fn loading_finished(&mut self, params: &LoadingFinishedParams) -> bool {
+        let Some(index) = self.in_flight.remove(&params.request_id) else {
+            return false;
+        };
+        let recorded = &mut self.entries[index];
+        recorded.entry.response.body_size = params.encoded_data_length as i64;
+        recorded.finish(params.timestamp);
+        recorded.awaiting_body = self.options.content;
+        recorded.awaiting_body
+    }
+
+    /// Completes the request's entry with the network error. Failed requests are kept
+    /// (with status 0) so that replay fails them too.
+    pub(crate) fn loading_failed(&mut self, params: &LoadingFailedParams) {
+        let Some(index) = self.in_flight.remove(&params.request_id) else {
+            return;
+        };
+        let recorded = &mut self.entries[index];
+        recorded.entry.response.error = Some(params.error_text.clone());
+        recorded.has_response = true;
+        recorded.finish(params.timestamp);
+    }
+
+    /// Records the page timings from a main frame `Page.lifecycleEvent`.
+    pub(crate) fn lifecycle_event(&mut self, loader_id: &str, name: &str, timestamp: f64) {
+        let Some(page) = self.pages.iter_mut().rev().find(|page| page.loader_id == loader_id) else {
+            return;
+        };
+        let elapsed = (timestamp - page.start) * 1000.0;
+        match name {
+            "DOMContentLoaded" => page.page.page_timings.on_content_load = elapsed,
+            "load" => page.page.page_timings.on_load = elapsed,
+            _ => {}
+        }
+    }
+
+    pub(crate) fn set_body(&mut self, request_id: &str, body: Vec<u8>) {
+        let Some(recorded) = self
+            .entries
+            .iter_mut()
+            .rev()
+            .find(|recorded| recorded.awaiting_body && recorded.request_id == request_id)
+        else {
+            return;
+        };
+        let content = &mut recorded.entry.response.content;
+        content.size = body.len() as i64;
+        match String::from_utf8(body) {
+            Ok(text) => content.text = Some(text),
+            Err(e) => {
+                content.text = Some(BASE64_STANDARD.encode(e.into_bytes()));
+                content.encoding = Some("base64".to_string());
+            }
+        }
+        recorded.awaiting_body = false;
+    }
+
+    /// Finished requests whose body should be recorded but has not been read yet.
+    pub(crate) fn missing_bodies(&self) -> Vec<String> {
+        self.entries
+            .iter()
+            .filter(|recorded| recorded.awaiting_body)
+            .map(|recorded| recorded.request_id.clone())
+            .collect()
+    }
+
+    /// The recorded HAR. Requests still waiting for their response are left out.
+    pub(crate) fn into_har(self) -> Har {
+        Har {
+            log: HarLog {
+                version: HAR_VERSION.to_string(),
+                creator: HarCreator {
+                    name: "janus".to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                },
+                pages: self.pages.into_iter().map(|page| page.page).collect(),
+                entries: self
+                    .entries
+                    .into_iter()
+                    .filter(|recorded| recorded.has_response)
+                    .map(|recorded| recorded.entry)
+                    .collect(),
+            },
+        }
+    }
+}
+
+/// Formats seconds since the Unix epoch as an ISO 8601 UTC timestamp with milliseconds.
+pub(crate) fn format_date_time(epoch_seconds: f64) -> String {
+    let millis = (epoch_seconds * 1000.0).round() as i64;
+    let (days, ms_of_day) = (millis.div_euclid(86_400_000), millis.rem_euclid(86_400_000));
+    // Civil date from days since 1970-01-01 (Howard Hinnant's `civil_from_days`)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        ms_of_day / 3_600_000,
+        ms_of_day / 60_000 % 60,
+        ms_of_day / 1000 % 60,
+        ms_of_day % 1000
+    )
+}
+
+/// HAR timings from a `Network.ResourceTiming`; `start` and `end` are the monotonic
+/// timestamps (in seconds) of the request and of the end of its response.
+pub(crate) fn har_timings(timing: Option<&ResourceTiming>, start: f64, end: f64) -> HarTimings {
+    let total = ((end - start) * 1000.0).max(0.0);
+    let Some(timing) = timing else {
+        // Served from memory, a data URL, ...: no phases to report
+        return HarTimings {
+            receive: total,
+            ..HarTimings::default()
+        };
+    };
+    let span = |from: f64, to: f64| if from >= 0.0 && to >= from { to - from } else { -1.0 };
+    let first_phase = [timing.dns_start, timing.connect_start, timing.send_start]
+        .into_iter()
+        .find(|ms| *ms >= 0.0)
+        .unwrap_or(0.0);
+    HarTimings {
+        blocked: ((timing.request_time - start) * 1000.0 + first_phase).max(0.0),
+        dns: span(timing.dns_start, timing.dns_end),
+        connect: span(timing.connect_start, timing.connect_end),
+        send: span(timing.send_start, timing.send_end).max(0.0),
+        wait: span(timing.send_end, timing.receive_headers_end).max(0.0),
+        receive: ((end - timing.request_time) * 1000.0 - timing.receive_headers_end).max(0.0),
+        ssl: span(timing.ssl_start, timing.ssl_end),
+    }
+}
+
+fn total_time(timings: &HarTimings) -> f64 {
+    // `ssl` is part of `connect`
+    [timings.blocked, timings.dns, timings.connect, timings.send, timings.wait, timings.receive]
+        .into_iter()
+        .filter(|ms| *ms > 0.0)
+        .sum()
+}
+
+fn header_value<'a>(headers: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+// CDP joins repeated headers with newlines; HAR lists them separately.
+fn har_headers(headers: &HashMap<String, String>) -> Vec<HarHeader> {
+    let mut har_headers: Vec<HarHeader> = headers
+        .iter()
+        .flat_map(|(name, values)| {
+            values.split('\n').map(|value| HarHeader {
+                name: name.clone(),
+                value: value.to_string(),
+            })
+        })
+        .collect();
+    har_headers.sort_by(|a, b| a.name.cmp(&b.name));
+    har_headers
+}
+
+fn http_version(protocol: Option<&str>) -> String {
+    match protocol {
+        Some("h2") => "HTTP/2".to_string(),
+        Some(protocol) if protocol.starts_with("h3") => "HTTP/3".to_string(),
+        Some(protocol) => protocol.to_ascii_uppercase(),
+        None => String::new(),
+    }
+}
+
+fn har_request(request: &Request) -> HarRequest {
+    let cookies = header_value(&request.headers, "cookie")
+        .map(|header| {
+            header
+                .split(';')
+                .filter_map(|pair| pair.trim().split_once('='))
+                .map(|(name, value)| HarCookie {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                    ..Default::default()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let query_string = Url::parse(&request.url)
+        .map(|url| {
+            url.query_pairs()
+                .map(|(name, value)| HarQueryParam {
+                    name: name.into_owned(),
+                    value: value.into_owned(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    HarRequest {
+        method: request.method.clone(),
+        url: request.url.clone(),
+        http_version: String::new(), // Known once the response arrives
+        cookies,
+        headers: har_headers(&request.headers),
+        query_string,
+        post_data: request.post_data.as_ref().map(|text| HarPostData {
+            mime_type: header_value(&request.headers, "content-type")
+                .unwrap_or_default()
+                .to_string(),
+            text: text.clone(),
+        }),
+        headers_size: -1,
+        body_size: request.post_data.as_ref().map_or(0, |text| text.len() as i64),
+    }
+}
+
+fn parse_set_cookie(header: &str) -> Option<HarCookie> {
+    let mut attributes = header.split(';').map(str::trim);
+    let (name, value) = attributes.next()?.split_once('=')?;
+    let mut cookie = HarCookie {
+        name: name.to_string(),
+        value: value.to_string(),
+        ..Default::default()
+    };
+    for attribute in attributes {
+        let (key, value) = attribute.split_once('=').unwrap_or((attribute, ""));
+        match key.to_ascii_lowercase().as_str() {
+            "path" => cookie.path = Some(value.to_string()),
+            "domain" => cookie.domain = Some(value.to_string()),
+            "expires" => cookie.expires = Some(value.to_string()),
+            "httponly" => cookie.http_only = Some(true),
+            "secure" => cookie.secure = Some(true),
+            _ => {}
+        }
+    }
+    Some(cookie)
+}
+
+fn har_response(response: &Response) -> HarResponse {
+    let headers = har_headers(&response.headers);
+    let cookies = headers
+        .iter()
+        .filter(|header| header.name.eq_ignore_ascii_case("set-cookie"))
+        .filter_map(|header| parse_set_cookie(&header.value))
+        .collect();
+    HarResponse {
+        status: u16::try_from(response.status).unwrap_or_default(),
+        status_text: response.status_text.clone(),
+        http_version: http_version(response.protocol.as_deref()),
+        cookies,
+        headers,
+        content: HarContent {
+            mime_type: response.mime_type.clone(),
+            ..Default::default()
+        },
+        redirect_url: String::new(),
+        headers_size: -1,
+        body_size: -1,
+        error: None,
+    }
+}
+
+fn without_fragment(url: &str) -> &str {
+    url.split_once('#').map_or(url, |(url, _)| url)
+}
+
+fn split_query(url: &str) -> (&str, &str) {
+    url.split_once('?').unwrap_or((url, ""))
+}
+
+/// Whether a request for `requested` may be answered with an entry recorded for `recorded`.
+pub(crate) fn urls_match(recorded: &str, requested: &str, url_match: HarUrlMatch) -> bool {
+    let (recorded, requested) = (without_fragment(recorded), without_fragment(requested));
+    let ((recorded_base, recorded_query), (requested_base, requested_query)) =
+        (split_query(recorded), split_query(requested));
+    match url_match {
+        HarUrlMatch::Exact => recorded == requested,
+        HarUrlMatch::IgnoreQuery => recorded_base == requested_base,
+        HarUrlMatch::IgnoreQueryOrder => {
+            let sorted = |query: &str| {
+                let mut params: Vec<String> = query
+                    .split('&')
+                    .filter(|param| !param.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                params.sort_unstable();
+                params
+            };
+            recorded_base == requested_base && sorted(recorded_query) == sorted(requested_query)
+        }
+    }
+}
+
+fn entry_matches(entry: &HarEntry, request: &InterceptedRequest, options: &HarReplayOptions) -> bool {
+    let recorded_body = entry.request.post_data.as_ref().map_or("", |data| data.text.as_str());
+    (!options.match_method || entry.request.method.eq_ignore_ascii_case(&request.method))
+        && (!options.match_body || recorded_body == request.post_data.as_deref().unwrap_or_default())
+        && urls_match(&entry.request.url, &request.url, options.url_match)
+}
+
+/// Response replaying `entry`; requests that failed when recorded fail again.
+pub(crate) fn replay_action(entry: &HarEntry) -> RouteAction {
+    let response = &entry.response;
+    if response.error.is_some() || response.status == 0 {
+        return RouteAction::Abort(AbortReason::Failed);
+    }
+    let body = match (&response.content.text, response.content.encoding.as_deref()) {
+        (Some(text), Some("base64")) => BASE64_STANDARD.decode(text).unwrap_or_default(),
+        (Some(text), _) => text.clone().into_bytes(),
+        (None, _) => Vec::new(),
+    };
+    let headers = response
+        .headers
+        .iter()
+        .filter(|header| {
+            !header.name.starts_with(':')
+                && !TRANSFER_HEADERS.iter().any(|name| header.name.eq_ignore_ascii_case(name))
+        })
+        .map(|header| (header.name.clone(), header.value.clone()))
+        .collect();
+    RouteAction::Fulfill(FulfillResponse {
+        status: response.status,
+        headers,
+        body,
+    })
+}
+
+/// Route handler answering requests from the entries of `har`. Each request gets the
+/// first matching entry not served yet, or the last matching one.
+pub(crate) fn replay_handler(har: Har, options: HarReplayOptions) -> RouteHandler {
+    let entries = har.log.entries;
+    let served: Mutex<HashSet<usize>> = Mutex::new(HashSet::new());
+    Box::new(move |request| {
+        let matching: Vec<usize> = entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry_matches(entry, &request, &options))
+            .map(|(index, _)| index)
+            .collect();
+        let mut served = served.lock().unwrap_or_else(PoisonError::into_inner);
+        let index = matching
+            .iter()
+            .copied()
+            .find(|index| !served.contains(index))
+            .or(matching.last().copied());
+        match index {
+            Some(index) => {
+                served.insert(index);
+                replay_action(&entries[index])
+            }
+            None => match options.not_found {
+                HarNotFound::Abort => RouteAction::Abort(AbortReason::Failed),
+                HarNotFound::Fallback => RouteAction::Continue(ContinueOverrides::default()),
+            },
+        }
+    })
+}
@@ -0,0 +1,234 @@
+//! Input helpers for `Input.dispatch*`: the US keyboard layout and the mouse /
+//! modifier bookkeeping a page needs to produce realistic, trusted events.
+
+use janus_interfaces::{KeyModifiers, MouseButton};
+
+/// CDP modifier bits.
+pub(crate) const MODIFIER_ALT: u32 = 1;
+pub(crate) const MODIFIER_CTRL: u32 = 2;
+pub(crate) const MODIFIER_META: u32 = 4;
+pub(crate) const MODIFIER_SHIFT: u32 = 8;
+
+/// One physical key of the US layout.
+struct KeyDefinition {
+    code: &'static str,
+    key: &'static str,
+    shift_key: Option<&'static str>,
+    key_code: u32,
+    location: u32, // 0 = standard, 1 = left, 2 = right, 3 = numpad
+}
+
+const fn key(
+    code: &'static str,
+    key: &'static str,
+    shift_key: Option<&'static str>,
+    key_code: u32,
+) -> KeyDefinition {
+    KeyDefinition {
+        code,
+        key,
+        shift_key,
+        key_code,
+        location: 0,
+    }
+}
+
+const fn modifier(code: &'static str, key: &'static str, key_code: u32, location: u32) -> KeyDefinition {
+    KeyDefinition {
+        code,
+        key,
+        shift_key: None,
+        key_code,
+        location,
+    }
+}
+
+#[rustfmt::skip]
+const US_KEYBOARD_LAYOUT: &[KeyDefinition] = &[
+    // Letters
+    key("KeyA", "a", Some("A"), 65), key("KeyB", "b", Some("B"), 66),
+    key("KeyC", "c", Some("C"), 67), key("KeyD", "d", Some("D"), 68),
+    key("KeyE", "e", Some("E"), 69), key("KeyF", "f", Some("F"), 70),
+    key("KeyG", "g", Some("G"), 71), key("KeyH", "h", Some("H"), 72),
+    key("KeyI", "i", Some("I"), 73), key("KeyJ", "j", Some("J"), 74),
+    key("KeyK", "k", Some("K"), 75), key("KeyL", "l", Some("L"), 76),
+    key("KeyM", "m", Some("M"), 77), key("KeyN", "n", Some("N"), 78),
+    key("KeyO", "o", Some("O"), 79), key("KeyP", "p", Some("P"), 80),
+    key("KeyQ", "q", Some("Q"), 81), key("KeyR", "r", Some("R"), 82),
+    key("KeyS", "s", Some("S"), 83), key("KeyT", "t", Some("T"), 84),
+    key("KeyU", "u", Some("U"), 85), key("KeyV", "v", Some("V"), 86),
+    key("KeyW", "w", Some("W"), 87), key("KeyX", "x", Some("X"), 88),
+    key("KeyY", "y", Some("Y"), 89), key("KeyZ", "z", Some("Z"), 90),
+    // Digit row
+    key("Digit0", "0", Some(")"), 48), key("Digit1", "1", Some("!"), 49),
+    key("Digit2", "2", Some("@"), 50), key("Digit3", "3", Some("#"), 51),
+    key("Digit4", "4", Some("$"), 52), key("Digit5", "5", Some("%"), 53),
+    key("Digit6", "6", Some("^"), 54), key("Digit7", "7", Some("&"), 55),
+    key("Digit8", "8", Some("*"), 56), key("Digit9", "9", Some("("), 57),
+    // Punctuation
+    key("Minus", "-", Some("_"), 189), key("Equal", "=", Some("+"), 187),
+    key("BracketLeft", "[", Some("{"), 219), key("BracketRight", "]", Some("}"), 221),
+    key("Backslash", "\\", Some("|"), 220), key("Semicolon", ";", Some(":"), 186),
+    key("Quote", "'", Some("\""), 222), key("Comma", ",", Some("<"), 188),
+    key("Period", ".", Some(">"), 190), key("Slash", "/", Some("?"), 191),
+    key("Backquote", "`", Some("~"), 192), key("Space", " ", None, 32),
+    // Editing and navigation
+    key("Enter", "Enter", None, 13), key("Tab", "Tab", None, 9),
+    key("Backspace", "Backspace", None, 8), key("Delete", "Delete", None, 46),
+    key("Escape", "Escape", None, 27), key("Insert", "Insert", None, 45),
+    key("ArrowUp", "ArrowUp", None, 38), key("ArrowDown", "ArrowDown", None, 40),
+    key("ArrowLeft", "ArrowLeft", None, 37), key("ArrowRight", "ArrowRight", None, 39),
+    key("Home", "Home", None, 36), key("End", "End", None, 35),
+    key("PageUp", "PageUp", None, 33), key("PageDown", "PageDown", None, 34),
+    key("CapsLock", "CapsLock", None, 20),
+    // Function keys
+    key("F1", "F1", None, 112), key("F2", "F2", None, 113), key("F3", "F3", None, 114),
+    key("F4", "F4", None, 115), key("F5", "F5", None, 116), key("F6", "F6", None, 117),
+    key("F7", "F7", None, 118), key("F8", "F8", None, 119), key("F9", "F9", None, 120),
+    key("F10", "F10", None, 121), key("F11", "F11", None, 122), key("F12", "F12", None, 123),
+    // Modifiers (left side first, so lookups by key name resolve to it)
+    modifier("ShiftLeft", "Shift", 16, 1), modifier("ShiftRight", "Shift", 16, 2),
+    modifier("ControlLeft", "Control", 17, 1), modifier("ControlRight", "Control", 17, 2),
+    modifier("AltLeft", "Alt", 18, 1), modifier("AltRight", "Alt", 18, 2),
+    modifier("MetaLeft", "Meta", 91, 1), modifier("MetaRight", "Meta", 92, 2),
+];
+
+/// A key resolved against the layout and the current Shift state.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct KeyDescription {
+    pub key: String,
+    pub code: String,
+    pub key_code: u32,
+    /// Text the key produces, if any.
+    pub text: Option<String>,
+    pub location: u32,
+}
+
+/// Resolves a key name (`"a"`, `"A"`, `"Enter"`) or code (`"KeyA"`) on the US layout.
+/// A shifted key name such as `"A"` or `"!"` resolves to the shifted key regardless of `shift`.
+pub(crate) fn describe_key(key_or_code: &str, shift: bool) -> Option<KeyDescription> {
+    let (definition, shifted) = US_KEYBOARD_LAYOUT
+        .iter()
+        .find(|def| def.key == key_or_code)
+        .map(|def| (def, shift))
+        .or_else(|| {
+            US_KEYBOARD_LAYOUT
+                .iter()
+                .find(|def| def.shift_key == Some(key_or_code))
+                .map(|def| (def, true))
+        })
+        .or_else(|| {
+            US_KEYBOARD_LAYOUT
+                .iter()
+                .find(|def| def.code == key_or_code)
+                .map(|def| (def, shift))
+        })?;
+
+    let key = match (shifted, definition.shift_key) {
+        (true, Some(shift_key)) => shift_key,
+        _ => definition.key,
+    };
+    let text = match key {
+        "Enter" => Some("\r".to_string()),
+        _ if key.chars().count() == 1 => Some(key.to_string()),
+        _ => None,
+    };
+    Some(KeyDescription {
+        key: key.to_string(),
+        code: definition.code.to_string(),
+        key_code: definition.key_code,
+        text,
+        location: definition.location,
+    })
+}
+
+/// CDP modifier bit for a modifier key name, or 0 for other keys.
+pub(crate) fn modifier_bit(key: &str) -> u32 {
+    match key {
+        "Alt" => MODIFIER_ALT,
+        "Control" => MODIFIER_CTRL,
+        "Meta" => MODIFIER_META,
+        "Shift" => MODIFIER_SHIFT,
+        _ => 0,
+    }
+}
+
+pub(crate) fn modifiers_to_cdp(modifiers: KeyModifiers) -> u32 {
+    let mut bits = 0;
+    if modifiers.alt {
+        bits |= MODIFIER_ALT;
+    }
+    if modifiers.ctrl {
+        bits |= MODIFIER_CTRL;
+    }
+    if modifiers.meta {
+        bits |= MODIFIER_META;
+    }
+    if modifiers.shift {
+        bits |= MODIFIER_SHIFT;
+    }
+    bits
+}
+
+pub(crate) fn mouse_button_to_cdp(button: MouseButton) -> &'static str {
+    match button {
+        MouseButton::Left => "left",
+        MouseButton::Middle => "middle",
+        MouseButton::Right => "right",
+        MouseButton::Back => "back",
+        MouseButton::Forward => "forward",
+    }
+}
+
+/// Bit of `button` in the `buttons` field of `Input.dispatchMouseEvent`.
+pub(crate) fn mouse_button_bit(button: MouseButton) -> u32 {
+    match button {
+        MouseButton::Left => 1,
+        MouseButton::Right => 2,
+        MouseButton::Middle => 4,
+        MouseButton::Back => 8,
+        MouseButton::Forward => 16,
+    }
+}
+
+/// Splits a key combination like `"Control+Shift+A"` into its keys.
+/// A trailing `+` is the plus key itself (`"Shift++"`).
+pub(crate) fn split_key_combination(combination: &str) -> Vec<&str> {
+    let mut keys: Vec<&str> = combination.split('+').collect();
+    if keys.len() >= 2 && keys[keys.len() - 1].is_empty() && keys[keys.len() - 2].is_empty() {
+        keys.truncate(keys.len() - 2);
+        keys.push("+");
+    }
+    keys
+}
+
+/// Input state of a page: held modifiers and mouse buttons, and the pointer position.
+#[derive(Debug, Default)]
+pub(crate) struct InputState {
+    pub modifiers: u32,
+    pub buttons: u32,
+    pub mouse_x: f64,
+    pub mouse_y: f64,
+}
+
+impl InputState {
+    /// Button reported with `mouseMoved` events: the first held button, as while dragging.
+    pub(crate) fn held_button(&self) -> &'static str {
+        [
+            MouseButton::Left,
+            MouseButton::Right,
+            MouseButton::Middle,
+            MouseButton::Back,
+            MouseButton::Forward,
+        ]
+        .into_iter()
+        .find(|button| self.buttons & mouse_button_bit(*button) != 0)
+        .map(mouse_button_to_cdp)
+        .unwrap_or("none")
+    }
+
+    /// Whether Ctrl, Alt or Meta is held; such combinations produce no text input.
+    pub(crate) fn has_command_modifier(&self) -> bool {
+        self.modifiers & (MODIFIER_ALT | MODIFIER_CTRL | MODIFIER_META) != 0
+    }
+}
@@ -0,0 +1,202 @@
+//! L2 Implementation of `janus_interfaces::JsHandle` for Chrome, and conversion of
+//! `JsArg`s into `Runtime.callFunctionOn` arguments.
+//!
+//! Object values are held as a `Runtime.RemoteObjectId`; primitive values come back
+//! inline and are kept in the handle.
+//!
+//! Also converts results into `JsValue`s for `Page::evaluate_value`.
+
+use crate::actors::{CallFunctionOn, ChromePageActor, GetProperties, ReleaseObject};
+use crate::error::map_internal_to_api_error;
+use crate::protocol::{CallArgument, RemoteObject};
+use actix::prelude::*;
+use async_trait::async_trait;
+use janus_interfaces::{ApiError, JsArg, JsHandle, JsValue};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[derive(Debug)]
+pub struct ChromeJsHandle {
+    actor_addr: Addr<ChromePageActor>,
+    session_id: Option<String>, // Session of the out-of-process frame holding the object
+    object: RemoteObject,
+    disposed: AtomicBool,
+}
+
+impl ChromeJsHandle {
+    /// Wraps a remote object returned by the page, or by the out-of-process frame
+    /// of `session_id`.
+    pub(crate) fn new(actor_addr: Addr<ChromePageActor>, session_id: Option<String>, object: RemoteObject) -> Self {
+        Self {
+            actor_addr,
+            session_id,
+            object,
+            disposed: AtomicBool::new(false),
+        }
+    }
+
+    // Object ID of a live object handle.
+    fn live_object_id(&self) -> Result<Option<&str>, ApiError> {
+        if self.disposed.load(Ordering::SeqCst) {
+            return Err(ApiError::InvalidParameters(format!("JS handle {} is disposed", self.description())));
+        }
+        Ok(self.object.object_id.as_deref())
+    }
+
+    // Calls `function_declaration` with the object as `this`.
+    async fn call(
+        &self,
+        object_id: &str,
+        function_declaration: &str,
+        args: Vec<Value>,
+        return_by_value: bool,
+    ) -> Result<RemoteObject, ApiError> {
+        self.actor_addr
+            .send(CallFunctionOn {
+                session_id: self.session_id.clone(),
+                object_id: Some(object_id.to_string()),
+                function_declaration: function_declaration.to_string(),
+                args: args.into_iter().map(|value| CallArgument { value: Some(value), object_id: None }).collect(),
+                return_by_value,
+            })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error calling function on object: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    fn wrap(&self, object: RemoteObject) -> Box<dyn JsHandle> {
+        Box::new(ChromeJsHandle::new(self.actor_addr.clone(), self.session_id.clone(), object))
+    }
+}
+
+#[async_trait]
+impl JsHandle for ChromeJsHandle {
+    fn description(&self) -> String {
+        match &self.object.description {
+            Some(description) => description.clone(),
+            None if self.object.value.is_null() => self.object.subtype.clone().unwrap_or_else(|| self.object.type_.clone()),
+            None => self.object.value.to_string(),
+        }
+    }
+
+    fn remote_object_id(&self) -> Option<String> {
+        if self.disposed.load(Ordering::SeqCst) {
+            return None;
+        }
+        self.object.object_id.clone()
+    }
+
+    async fn get_property(&self, name: &str) -> Result<Box<dyn JsHandle>, ApiError> {
+        let object_id = self
+            .live_object_id()?
+            .ok_or_else(|| ApiError::InvalidParameters(format!("JS handle {} is not an object", self.description())))?;
+        let object = self
+            .call(object_id, "function(name) { return this[name]; }", vec![json!(name)], false)
+            .await?;
+        Ok(self.wrap(object))
+    }
+
+    async fn get_properties(&self) -> Result<HashMap<String, Box<dyn JsHandle>>, ApiError> {
+        let Some(object_id) = self.live_object_id()? else {
+            return Ok(HashMap::new());
+        };
+        let properties = self
+            .actor_addr
+            .send(GetProperties { session_id: self.session_id.clone(), object_id: object_id.to_string() })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error getting properties: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)?;
+        Ok(properties
+            .into_iter()
+            .filter(|property| property.enumerable)
+            .filter_map(|property| Some((property.name, self.wrap(property.value?))))
+            .collect())
+    }
+
+    async fn json_value(&self) -> Result<Value, ApiError> {
+        match self.live_object_id()? {
+            Some(object_id) => Ok(self.call(object_id, SERIALIZE_VALUE, vec![], true).await?.value),
+            None => Ok(self.object.value.clone()),
+        }
+    }
+
+    async fn dispose(&self) -> Result<(), ApiError> {
+        if self.disposed.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+        let Some(object_id) = self.object.object_id.clone() else {
+            return Ok(());
+        };
+        self.actor_addr
+            .send(ReleaseObject { session_id: self.session_id.clone(), object_id })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error releasing object: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+}
+
+impl Drop for ChromeJsHandle {
+    fn drop(&mut self) {
+        // Fire-and-forget release; the page may already be gone, which is fine.
+        if !self.disposed.swap(true, Ordering::SeqCst)
+            && let Some(object_id) = self.object.object_id.take()
+        {
+            self.actor_addr.do_send(ReleaseObject { session_id: self.session_id.clone(), object_id });
+        }
+    }
+}
+
+/// Serializes an object result by value; `Date`s become ISO strings.
+pub(crate) const SERIALIZE_VALUE: &str = "function() {
+    if (Object.prototype.toString.call(this) === '[object Date]') {
+        return isNaN(this) ? null : this.toISOString();
+    }
+    return this;
+}";
+
+/// Converts an inline (primitive) result; `None` for objects, which are serialized in
+/// the page with [`SERIALIZE_VALUE`] first.
+pub(crate) fn js_value_from_remote(object: &RemoteObject) -> Option<JsValue> {
+    if object.type_ == "undefined" {
+        return Some(JsValue::Undefined);
+    }
+    if let Some(unserializable) = object.unserializable_value.as_deref() {
+        return Some(match unserializable.strip_suffix('n') {
+            Some(digits) => JsValue::BigInt(digits.to_string()),
+            None => JsValue::Number(match unserializable {
+                "Infinity" => f64::INFINITY,
+                "-Infinity" => f64::NEG_INFINITY,
+                "-0" => -0.0,
+                _ => f64::NAN,
+            }),
+        });
+    }
+    match object.object_id {
+        Some(_) => None,
+        None => Some(JsValue::Json(object.value.clone())),
+    }
+}
+
+/// Converts function arguments: values are sent as JSON, handles as the objects they
+/// refer to (or, for primitive handles, their values).
+pub(crate) async fn call_arguments(args: Vec<JsArg<'_>>) -> Result<Vec<CallArgument>, ApiError> {
+    let mut arguments = Vec::with_capacity(args.len());
+    for arg in args {
+        let argument = match arg {
+            JsArg::Value(value) => CallArgument { value: Some(value), object_id: None },
+            JsArg::Element(element) => {
+                let object_id = element.remote_object_id().ok_or_else(|| {
+                    ApiError::InvalidParameters(format!("Element handle {} is disposed", element.description()))
+                })?;
+                CallArgument { value: None, object_id: Some(object_id) }
+            }
+            JsArg::Handle(handle) => match handle.remote_object_id() {
+                Some(object_id) => CallArgument { value: None, object_id: Some(object_id) },
+                None => CallArgument { value: Some(handle.json_value().await?), object_id: None },
+            },
+        };
+        arguments.push(argument);
+    }
+    Ok(arguments)
+}
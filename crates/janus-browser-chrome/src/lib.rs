@@ -7,16 +7,988 @@ use actix::Addr; // Re-export if needed internally
 
 pub mod actors;
 pub mod browser;
+pub mod context;
+mod bindings;
+mod cookies;
+pub mod discovery;
+mod downloads;
+pub mod element;
+mod emulation;
+mod events;
+mod fetch;
+pub mod frame;
+mod frames;
+mod har;
 pub mod error; // Add error module
+mod input;
+pub mod js_handle;
+pub mod locator;
+mod navigation;
+mod pdf;
+mod screenshot;
 pub mod page;
+pub mod process;
 pub mod protocol;
+mod upload;
+mod wait;
 
 pub use browser::ChromeBrowser; // Expose the L2 implementation struct
+pub use context::ChromeBrowserContext;
+pub use discovery::DevToolsHttpClient;
+pub use process::ChromeProcess;
 
 #[cfg(test)]
 mod tests {
+    use crate::bindings::{BindingCall, binding_script, deliver_arguments, parse_binding_call};
+    use crate::context::permission_to_cdp;
+    use crate::cookies::{cookie_from_cdp, cookie_matches_url, filter_matches};
+    use crate::discovery::{VersionInfo, parse_host_port, parse_http_response};
+    use crate::downloads::{
+        DownloadEvent, DownloadWaiter, download_behavior_params, download_progress_from_cdp, download_started_from_cdp,
+        saved_path,
+    };
+    use crate::element::parse_bounding_box;
+    use crate::actors::SetEmulation;
+    use crate::emulation::{context_emulation, device_overrides, emulated_media_params, geolocation_params};
+    use crate::events::{
+        console_message_from_cdp, dialog_info_from_cdp, network_request_from_cdp, page_error_from_cdp,
+        target_info_from_cdp, unexpected_dialog_error,
+    };
+    use crate::fetch::{route_action_command, url_pattern_matches};
+    use crate::frames::FrameTracker;
+    use crate::har::{HarRecorder, format_date_time, replay_handler, urls_match};
+    use crate::input::{describe_key, split_key_combination};
+    use crate::js_handle::js_value_from_remote;
+    use crate::locator::{LOCATOR_SCRIPT, Step, parse_selector, parse_strict_violation, script_arguments};
+    use crate::navigation::{DocumentResponse, MainFrameState};
+    use crate::pdf::print_to_pdf_params;
+    use crate::process::{build_args, parse_devtools_active_port, parse_devtools_listening_line};
+    use crate::protocol::{
+        CallArgument, CallFunctionOnParams, ConsoleApiCalledParams, Cookie as CdpCookie, CreateTargetParams, DownloadProgressParams, DownloadWillBeginParams,
+        ExceptionThrownParams, ExecutionContextCreatedParams, FrameNavigatedParams, GetFrameTreeResult, GetPropertiesResult, GrantPermissionsParams, HandleJavaScriptDialogParams, JavascriptDialogOpeningParams,
+        LoadingFinishedParams, RequestWillBeSentParams, ResponseReceivedParams, TargetCreatedParams,
+    };
+    use crate::screenshot::{quad_to_clip, screenshot_params};
+    use crate::upload::input_file_paths;
+    use crate::wait::{WaitSlice, parse_wait_slice, polling_arguments, slice_error, wait_script};
+    use janus_core::config::BrowserLaunchConfig;
+    use janus_interfaces::{
+        AbortReason, ApiError, BoundingBox, BrowserContextOptions, Clip, ColorScheme, ConsoleLogLevel, CookieFilter, DeviceDescriptor,
+        DeviceRegistry, DialogType, DownloadBehavior, DownloadState, FulfillResponse, Geolocation, Har, HarRecordOptions, HarReplayOptions,
+        HarUrlMatch, InterceptedRequest, JsValue, MediaEmulation, MediaType, NavigationResult, PdfOptions,
+        Permission, Polling, ReducedMotion, RouteAction, SameSite, ScreenshotFormat, ScreenshotOptions, TargetType,
+    };
+    use serde_json::Value;
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn test_parse_devtools_listening_line() {
+        assert_eq!(
+            parse_devtools_listening_line(
+                "DevTools listening on ws://127.0.0.1:40123/devtools/browser/3f2a-11"
+            ),
+            Some("ws://127.0.0.1:40123/devtools/browser/3f2a-11".to_string())
+        );
+        assert_eq!(parse_devtools_listening_line("[0101/000000.1:ERROR] something"), None);
+    }
+
+    #[test]
+    fn test_parse_devtools_active_port() {
+        assert_eq!(
+            parse_devtools_active_port("40123\n/devtools/browser/3f2a-11\n", "127.0.0.1"),
+            Some("ws://127.0.0.1:40123/devtools/browser/3f2a-11".to_string())
+        );
+        // Chrome writes the file in two steps; a partial file must not parse.
+        assert_eq!(parse_devtools_active_port("40123\n", "127.0.0.1"), None);
+        assert_eq!(parse_devtools_active_port("", "127.0.0.1"), None);
+    }
+
+    #[test]
+    fn test_build_args() {
+        let config = BrowserLaunchConfig {
+            args: Some(vec!["--window-size=800,600".to_string()]),
+            ..Default::default()
+        };
+        let args = build_args(&config, Path::new("/tmp/profile"));
+        assert!(args.contains(&"--remote-debugging-port=0".to_string()));
+        assert!(args.contains(&"--user-data-dir=/tmp/profile".to_string()));
+        assert!(args.contains(&"--headless=new".to_string()));
+        assert!(args.contains(&"--window-size=800,600".to_string()));
+
+        let headful = BrowserLaunchConfig {
+            headless: Some(false),
+            ..Default::default()
+        };
+        assert!(!build_args(&headful, Path::new("/tmp/profile")).contains(&"--headless=new".to_string()));
+    }
+
+    #[test]
+    fn test_parse_host_port() {
+        assert_eq!(
+            parse_host_port("ws://127.0.0.1:9222/devtools/browser"),
+            Some(("127.0.0.1".to_string(), 9222))
+        );
+        assert_eq!(
+            parse_host_port("http://localhost:9333"),
+            Some(("localhost".to_string(), 9333))
+        );
+        assert_eq!(parse_host_port("http://[::1]:9222/json"), Some(("::1".to_string(), 9222)));
+        assert_eq!(parse_host_port("ws://localhost/devtools"), None);
+    }
+
+    #[test]
+    fn test_parse_json_version_response() {
+        let raw = "HTTP/1.1 200 OK\r\nContent-Type: application/json; charset=UTF-8\r\n\r\n{\
+            \"Browser\": \"HeadlessChrome/124.0.6367.60\", \"Protocol-Version\": \"1.3\",\
+            \"User-Agent\": \"Mozilla/5.0\", \"V8-Version\": \"12.4.254.12\",\
+            \"WebKit-Version\": \"537.36\",\
+            \"webSocketDebuggerUrl\": \"ws://127.0.0.1:9222/devtools/browser/b0b8a4fb\"}";
+        let (status, body) = parse_http_response(raw.as_bytes()).unwrap();
+        assert_eq!(status, 200);
+        let version: VersionInfo = serde_json::from_str(&body).unwrap();
+        assert_eq!(version.protocol_version, "1.3");
+        assert_eq!(
+            version.web_socket_debugger_url,
+            "ws://127.0.0.1:9222/devtools/browser/b0b8a4fb"
+        );
+
+        let chunked = "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\n[{}, \r\n3\r\n{}]\r\n0\r\n\r\n";
+        assert_eq!(parse_http_response(chunked.as_bytes()).unwrap().1, "[{}, {}]");
+        // A chunk boundary may split a UTF-8 character.
+        let split = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n1\r\n\xC3\r\n1\r\n\xA9\r\n0\r\n\r\n";
+        assert_eq!(parse_http_response(split).unwrap().1, "\u{e9}");
+    }
+
+    #[test]
+    fn test_console_message_from_cdp() {
+        let params: ConsoleApiCalledParams = serde_json::from_value(serde_json::json!({
+            "type": "warning",
+            "args": [
+                { "type": "string", "value": "count:" },
+                { "type": "number", "value": 3, "description": "3" },
+                { "type": "undefined" }
+            ],
+            "executionContextId": 1,
+            "timestamp": 1700000000000.0,
+            "stackTrace": {
+                "callFrames": [{
+                    "functionName": "main", "scriptId": "7",
+                    "url": "https://example.com/app.js", "lineNumber": 9, "columnNumber": 4
+                }]
+            }
+        }))
+        .unwrap();
+        let message = console_message_from_cdp(&params);
+        assert_eq!(message.level, ConsoleLogLevel::Warning);
+        assert_eq!(message.text, "count: 3 undefined");
+        assert_eq!(message.url.as_deref(), Some("https://example.com/app.js"));
+        assert_eq!(message.line_number, Some(9));
+    }
+
+    #[test]
+    fn test_page_error_from_cdp() {
+        let params: ExceptionThrownParams = serde_json::from_value(serde_json::json!({
+            "timestamp": 1700000000000.0,
+            "exceptionDetails": {
+                "exceptionId": 1, "text": "Uncaught", "lineNumber": 0, "columnNumber": 6,
+                "url": "https://example.com/", "executionContextId": 1,
+                "exception": {
+                    "type": "object", "subtype": "error", "className": "TypeError",
+                    "description": "TypeError: x is not a function\n    at <anonymous>:1:7"
+                }
+            }
+        }))
+        .unwrap();
+        let error = page_error_from_cdp(&params.exception_details);
+        assert_eq!(error.message, "Uncaught TypeError: x is not a function");
+        assert_eq!(error.url.as_deref(), Some("https://example.com/"));
+        assert_eq!(error.column_number, Some(6));
+    }
+
+    #[test]
+    fn test_dialog_from_cdp() {
+        let params: JavascriptDialogOpeningParams = serde_json::from_value(serde_json::json!({
+            "url": "https://example.com/", "message": "Your name?", "type": "prompt",
+            "hasBrowserHandler": false, "defaultPrompt": "Ada"
+        }))
+        .unwrap();
+        let dialog = dialog_info_from_cdp(params);
+        assert_eq!(dialog.dialog_type, DialogType::Prompt);
+        assert_eq!(dialog.default_prompt.as_deref(), Some("Ada"));
+
+        let error = unexpected_dialog_error(&dialog);
+        assert_eq!(error.message, "Unexpected Prompt dialog: Your name?");
+        assert_eq!(error.url.as_deref(), Some("https://example.com/"));
+
+        let params = serde_json::from_value(serde_json::json!({
+            "url": "https://example.com/", "message": "Leave?", "type": "someNewType"
+        }))
+        .unwrap();
+        assert_eq!(dialog_info_from_cdp(params).dialog_type, DialogType::Alert);
+
+        let params = HandleJavaScriptDialogParams { accept: false, prompt_text: None };
+        assert_eq!(serde_json::to_value(params).unwrap(), serde_json::json!({ "accept": false }));
+    }
+
+    #[test]
+    fn test_target_info_from_cdp() {
+        let params: TargetCreatedParams = serde_json::from_value(serde_json::json!({
+            "targetInfo": {
+                "targetId": "B2C1", "type": "page", "title": "", "url": "https://example.com/popup",
+                "attached": false, "openerId": "A7F0", "canAccessOpener": true,
+                "browserContextId": "CTX1"
+            }
+        }))
+        .unwrap();
+        let info = target_info_from_cdp(&params.target_info);
+        assert_eq!(info.target_type, TargetType::Page);
+        assert_eq!(info.opener_id.as_deref(), Some("A7F0"));
+        assert_eq!(info.browser_context_id.as_deref(), Some("CTX1"));
+
+        let worker: TargetCreatedParams = serde_json::from_value(serde_json::json!({
+            "targetInfo": {
+                "targetId": "W1", "type": "service_worker", "title": "sw.js",
+                "url": "https://example.com/sw.js", "attached": false
+            }
+        }))
+        .unwrap();
+        assert_eq!(target_info_from_cdp(&worker.target_info).target_type, TargetType::ServiceWorker);
+    }
+
+    #[test]
+    fn test_describe_key() {
+        let a = describe_key("a", false).unwrap();
+        assert_eq!((a.key.as_str(), a.code.as_str(), a.key_code), ("a", "KeyA", 65));
+        assert_eq!(a.text.as_deref(), Some("a"));
+        // Shift held, shifted name, or code all resolve to the same physical key.
+        assert_eq!(describe_key("a", true).unwrap().key, "A");
+        assert_eq!(describe_key("!", false).unwrap().code, "Digit1");
+        assert_eq!(describe_key("KeyA", false).unwrap().key, "a");
+        let enter = describe_key("Enter", false).unwrap();
+        assert_eq!((enter.key_code, enter.text.as_deref()), (13, Some("\r")));
+        let shift = describe_key("Shift", false).unwrap();
+        assert_eq!((shift.code.as_str(), shift.location, shift.text), ("ShiftLeft", 1, None));
+        assert_eq!(describe_key("é", false), None);
+    }
+
+    #[test]
+    fn test_split_key_combination() {
+        assert_eq!(split_key_combination("Control+Shift+A"), vec!["Control", "Shift", "A"]);
+        assert_eq!(split_key_combination("Enter"), vec!["Enter"]);
+        assert_eq!(split_key_combination("Shift++"), vec!["Shift", "+"]);
+        assert_eq!(split_key_combination("+"), vec!["+"]);
+    }
+
+    #[test]
+    fn test_parse_bounding_box() {
+        assert_eq!(
+            parse_bounding_box(serde_json::json!({ "x": 8, "y": 20.5, "width": 100, "height": 18 })),
+            Some(BoundingBox { x: 8.0, y: 20.5, width: 100.0, height: 18.0 })
+        );
+        // Detached / unrendered elements report null.
+        assert_eq!(parse_bounding_box(serde_json::Value::Null), None);
+    }
+
+    #[test]
+    fn test_main_frame_navigation_tracking() {
+        let mut frame = MainFrameState::default();
+        assert!(frame.record_lifecycle_event("L1", "init"));
+        frame.record_lifecycle_event("L1", "DOMContentLoaded");
+        frame.record_document_response(DocumentResponse {
+            loader_id: "L1".to_string(),
+            url: "https://example.com/".to_string(),
+            status: Some(200),
+        });
+        assert!(frame.has_reached("L1", "DOMContentLoaded"));
+        assert!(!frame.has_reached("L1", "load"));
+
+        // A new document resets the lifecycle; late events of the old loader are ignored.
+        assert!(frame.record_lifecycle_event("L2", "init"));
+        assert!(!frame.record_lifecycle_event("L1", "load"));
+        assert!(!frame.has_reached("L1", "load"));
+        assert!(!frame.has_reached("L2", "DOMContentLoaded"));
+
+        // Without a matching document response, the requested URL is reported.
+        assert_eq!(
+            frame.navigation_result("L2", "data:text/html,hi"),
+            NavigationResult {
+                loader_id: Some("L2".to_string()),
+                url: "data:text/html,hi".to_string(),
+                status: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_screenshot_params() {
+        let options = ScreenshotOptions {
+            quality: Some(80),
+            clip: Some(Clip { x: 0.0, y: 100.0, width: 800.0, height: 600.0 }),
+            ..Default::default()
+        };
+        let params = serde_json::to_value(screenshot_params(ScreenshotFormat::Webp, &options).unwrap()).unwrap();
+        assert_eq!(
+            params,
+            serde_json::json!({
+                "format": "webp",
+                "quality": 80,
+                "clip": { "x": 0.0, "y": 100.0, "width": 800.0, "height": 600.0, "scale": 1.0 },
+            })
+        );
+        // Png has no quality setting.
+        assert!(screenshot_params(ScreenshotFormat::Png, &options).is_err());
+    }
+
+    #[test]
+    fn test_quad_to_clip() {
+        let quad = [10.0, 20.0, 110.0, 20.0, 110.0, 70.0, 10.0, 70.0];
+        assert_eq!(
+            quad_to_clip(&quad, 0.0, 500.0),
+            Some(Clip { x: 10.0, y: 520.0, width: 100.0, height: 50.0 })
+        );
+        assert_eq!(quad_to_clip(&[0.0; 8], 0.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_print_to_pdf_params() {
+        let options = PdfOptions {
+            landscape: Some(true),
+            print_background: Some(true),
+            margin_top: Some(0.5),
+            page_ranges: Some("1-3".to_string()),
+            ..Default::default()
+        };
+        let params = serde_json::to_value(print_to_pdf_params(options).unwrap()).unwrap();
+        assert_eq!(
+            params,
+            serde_json::json!({
+                "landscape": true,
+                "printBackground": true,
+                "marginTop": 0.5,
+                "pageRanges": "1-3",
+                "transferMode": "ReturnAsStream",
+            })
+        );
+        let invalid = PdfOptions { scale: Some(3.0), ..Default::default() };
+        assert!(print_to_pdf_params(invalid).is_err());
+    }
+
+    #[test]
+    fn test_cookie_url_matching() {
+        let cdp: CdpCookie = serde_json::from_value(serde_json::json!({
+            "name": "sid", "value": "abc", "domain": ".example.com", "path": "/app",
+            "expires": -1, "size": 6, "httpOnly": true, "secure": true, "session": true,
+            "sameSite": "Lax", "priority": "Medium"
+        }))
+        .unwrap();
+        let cookie = cookie_from_cdp(cdp);
+        assert_eq!(cookie.expires, None);
+        assert_eq!(cookie.same_site, Some(SameSite::Lax));
+
+        let matches = |url: &str| cookie_matches_url(&cookie, &url::Url::parse(url).unwrap());
+        assert!(matches("https://example.com/app"));
+        assert!(matches("https://www.example.com/app/settings"));
+        assert!(!matches("http://example.com/app")); // secure
+        assert!(!matches("https://example.com/application"));
+        assert!(!matches("https://notexample.com/app"));
+
+        let filter = CookieFilter {
+            name: "sid".to_string(),
+            url: Some("https://example.com/app".to_string()),
+            ..Default::default()
+        };
+        assert!(filter_matches(&filter, &cookie));
+        let filter = CookieFilter { name: "sid".to_string(), path: Some("/".to_string()), ..Default::default() };
+        assert!(!filter_matches(&filter, &cookie));
+    }
+
+    #[test]
+    fn test_url_pattern_matches() {
+        assert!(url_pattern_matches("*", "https://example.com/"));
+        assert!(url_pattern_matches("*/api/*", "https://example.com/api/users?page=2"));
+        assert!(url_pattern_matches("https://example.com/?.png", "https://example.com/a.png"));
+        assert!(!url_pattern_matches("https://example.com/?.png", "https://example.com/ab.png"));
+        assert!(!url_pattern_matches("*/api/*", "https://example.com/static/app.js"));
+        assert!(url_pattern_matches("*a\\*b", "https://example.com/a*b")); // escaped '*'
+        assert!(!url_pattern_matches("*a\\*b", "https://example.com/axb"));
+    }
+
+    #[test]
+    fn test_route_action_command() {
+        let response = FulfillResponse {
+            status: 201,
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: b"{}".to_vec(),
+        };
+        let (method, params) =
+            route_action_command("interception-1".to_string(), RouteAction::Fulfill(response));
+        assert_eq!(method, "Fetch.fulfillRequest");
+        assert_eq!(
+            params,
+            serde_json::json!({
+                "requestId": "interception-1",
+                "responseCode": 201,
+                "responseHeaders": [{ "name": "Content-Type", "value": "application/json" }],
+                "body": "e30=",
+            })
+        );
+        let (method, params) = route_action_command(
+            "interception-2".to_string(),
+            RouteAction::Abort(AbortReason::BlockedByClient),
+        );
+        assert_eq!(method, "Fetch.failRequest");
+        assert_eq!(params["errorReason"], "BlockedByClient");
+    }
+
+    #[test]
+    fn test_network_request_with_redirect() {
+        let params: RequestWillBeSentParams = serde_json::from_value(serde_json::json!({
+            "requestId": "1000.2",
+            "loaderId": "L1",
+            "documentURL": "https://example.com/",
+            "request": {
+                "url": "https://example.com/new",
+                "urlFragment": "#top",
+                "method": "GET",
+                "headers": { "Accept": "text/html" }
+            },
+            "timestamp": 12.5,
+            "wallTime": 1700000000.0,
+            "redirectResponse": {
+                "url": "https://example.com/old",
+                "status": 301,
+                "statusText": "Moved Permanently",
+                "headers": { "Location": "/new" },
+                "mimeType": "text/html",
+                "remotePort": 443
+            },
+            "type": "Document",
+            "frameId": "F1"
+        }))
+        .unwrap();
+        let request = network_request_from_cdp(&params);
+        assert_eq!(request.url, "https://example.com/new#top");
+        assert_eq!(request.resource_type.as_deref(), Some("Document"));
+        assert_eq!(request.headers.get("Accept").map(String::as_str), Some("text/html"));
+        let redirect = request.redirect_response.unwrap();
+        assert_eq!(redirect.request_id, "1000.2");
+        assert_eq!(redirect.status, 301);
+        assert_eq!(redirect.url, "https://example.com/old");
+        assert_eq!(redirect.remote_port, Some(443));
+        assert!(redirect.timing.is_none());
+    }
+
+    #[test]
+    fn test_har_recorder_redirect() {
+        let mut recorder = HarRecorder::new(HarRecordOptions { content: true, url_pattern: None }, "F1".to_string());
+        let request = |url: &str, redirect: Value| {
+            serde_json::from_value::<RequestWillBeSentParams>(serde_json::json!({
+                "requestId": "L1", "loaderId": "L1", "documentURL": url,
+                "request": { "url": url, "method": "GET", "headers": {} },
+                "timestamp": 8.0, "wallTime": 1700000000.0,
+                "redirectResponse": redirect, "type": "Document", "frameId": "F1"
+            }))
+            .unwrap()
+        };
+        recorder.request_will_be_sent(&request("http://example.com/", Value::Null));
+        recorder.request_will_be_sent(&request(
+            "https://example.com/",
+            serde_json::json!({ "url": "http://example.com/", "status": 301, "headers": { "Location": "https://example.com/" } }),
+        ));
+        let response: ResponseReceivedParams = serde_json::from_value(serde_json::json!({
+            "requestId": "L1", "loaderId": "L1", "timestamp": 8.0625, "type": "Document", "frameId": "F1",
+            "response": {
+                "url": "https://example.com/", "status": 200, "statusText": "OK", "mimeType": "text/html",
+                "protocol": "h2", "headers": { "Set-Cookie": "a=1; Path=/; HttpOnly\nb=2" },
+                "timing": {
+                    "requestTime": 8.0, "dnsStart": 1.0, "dnsEnd": 3.0, "connectStart": 3.0, "connectEnd": 11.0,
+                    "sslStart": 5.0, "sslEnd": 11.0, "sendStart": 11.0, "sendEnd": 12.0, "receiveHeadersEnd": 60.0
+                }
+            }
+        }))
+        .unwrap();
+        recorder.response_received(&response);
+        let finished: LoadingFinishedParams = serde_json::from_value(
+            serde_json::json!({ "requestId": "L1", "timestamp": 8.125, "encodedDataLength": 512.0 }),
+        )
+        .unwrap();
+        assert!(recorder.loading_finished(&finished));
+        assert_eq!(recorder.missing_bodies(), vec!["L1".to_string()]);
+        recorder.set_body("L1", b"<html></html>".to_vec());
+        recorder.lifecycle_event("L1", "load", 8.25);
+
+        let har = recorder.into_har();
+        assert_eq!(har.log.pages.len(), 1);
+        assert_eq!(har.log.pages[0].page_timings.on_load, 250.0);
+        let [redirect, document] = &har.log.entries[..] else {
+            panic!("expected two entries, got {:?}", har.log.entries);
+        };
+        assert_eq!(redirect.response.status, 301);
+        assert_eq!(redirect.response.redirect_url, "https://example.com/");
+        assert_eq!(document.pageref.as_deref(), Some("page_1"));
+        assert_eq!(document.started_date_time, "2023-11-14T22:13:20.000Z");
+        assert_eq!(document.response.http_version, "HTTP/2");
+        assert_eq!(document.response.cookies.len(), 2);
+        assert_eq!(document.response.content.text.as_deref(), Some("<html></html>"));
+        let timings = document.timings;
+        assert_eq!((timings.blocked, timings.dns, timings.connect, timings.ssl), (1.0, 2.0, 8.0, 6.0));
+        assert_eq!((timings.send, timings.wait, timings.receive), (1.0, 48.0, 65.0));
+        assert_eq!(document.time, 125.0);
+        assert_eq!(format_date_time(951782400.5), "2000-02-29T00:00:00.500Z");
+    }
+
+    #[test]
+    fn test_har_replay() {
+        assert!(urls_match("https://a.test/x?b=2&a=1", "https://a.test/x?a=1&b=2#top", HarUrlMatch::IgnoreQueryOrder));
+        assert!(!urls_match("https://a.test/x?b=2&a=1", "https://a.test/x?a=1&b=2", HarUrlMatch::Exact));
+        assert!(urls_match("https://a.test/x?page=1", "https://a.test/x?page=2", HarUrlMatch::IgnoreQuery));
+
+        let entry = |status: u16, text: &str| {
+            serde_json::json!({
+                "startedDateTime": "2023-11-14T22:13:20.000Z", "time": 1.0,
+                "request": {
+                    "method": "POST", "url": "https://a.test/api", "httpVersion": "HTTP/1.1",
+                    "postData": { "mimeType": "application/json", "text": "{}" },
+                    "headersSize": -1, "bodySize": 2
+                },
+                "response": {
+                    "status": status, "statusText": "", "httpVersion": "HTTP/1.1",
+                    "headers": [
+                        { "name": "Content-Type", "value": "application/json" },
+                        { "name": "Content-Encoding", "value": "gzip" }
+                    ],
+                    "content": { "size": 4, "mimeType": "application/json", "text": text },
+                    "redirectURL": "", "headersSize": -1, "bodySize": -1
+                },
+                "cache": {},
+                "timings": { "send": 0, "wait": 1, "receive": 0 }
+            })
+        };
+        let har: Har = serde_json::from_value(serde_json::json!({
+            "log": { "version": "1.2", "creator": { "name": "test", "version": "1" }, "entries": [entry(200, "[1]"), entry(500, "[2]")] }
+        }))
+        .unwrap();
+        let handler = replay_handler(har, HarReplayOptions::default());
+        let request = |method: &str, body: &str| InterceptedRequest {
+            url: "https://a.test/api".to_string(),
+            method: method.to_string(),
+            headers: Default::default(),
+            post_data: Some(body.to_string()),
+            resource_type: "Fetch".to_string(),
+            frame_id: None,
+        };
+        // Entries are served in order, then the last one repeats
+        for (status, body) in [(200, "[1]"), (500, "[2]"), (500, "[2]")] {
+            match handler(request("POST", "{}")) {
+                RouteAction::Fulfill(response) => {
+                    assert_eq!(response.status, status);
+                    assert_eq!(response.body, body.as_bytes());
+                    assert_eq!(response.headers, vec![("Content-Type".to_string(), "application/json".to_string())]);
+                }
+                other => panic!("unexpected action {:?}", other),
+            }
+        }
+        assert!(matches!(handler(request("GET", "{}")), RouteAction::Abort(_)));
+        assert!(matches!(handler(request("POST", "{\"a\":1}")), RouteAction::Abort(_)));
+    }
+
+    #[test]
+    fn test_browser_context_params() {
+        let params = CreateTargetParams { url: "about:blank".to_string(), browser_context_id: None };
+        assert_eq!(serde_json::to_value(params).unwrap(), serde_json::json!({ "url": "about:blank" }));
+        let params = CreateTargetParams {
+            url: "about:blank".to_string(),
+            browser_context_id: Some("CTX".to_string()),
+        };
+        assert_eq!(serde_json::to_value(params).unwrap()["browserContextId"], "CTX");
+
+        let params = GrantPermissionsParams {
+            permissions: [Permission::Geolocation, Permission::ClipboardRead]
+                .into_iter()
+                .map(permission_to_cdp)
+                .collect(),
+            origin: Some("https://example.com".to_string()),
+            browser_context_id: Some("CTX".to_string()),
+        };
+        assert_eq!(
+            serde_json::to_value(params).unwrap(),
+            serde_json::json!({
+                "permissions": ["geolocation", "clipboardReadWrite"],
+                "origin": "https://example.com",
+                "browserContextId": "CTX",
+            })
+        );
+    }
+
+    #[test]
+    fn test_device_overrides() {
+        let pixel = DeviceRegistry::default().get("Pixel 5").cloned().unwrap();
+        let overrides = device_overrides(pixel.clone()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&overrides.metrics).unwrap(),
+            serde_json::json!({ "width": 393, "height": 851, "deviceScaleFactor": 2.75, "mobile": true })
+        );
+        assert_eq!(
+            serde_json::to_value(&overrides.touch).unwrap(),
+            serde_json::json!({ "enabled": true, "maxTouchPoints": 5 })
+        );
+        assert!(overrides.user_agent.user_agent.contains("Pixel 5"));
+
+        let desktop = device_overrides(DeviceDescriptor {
+            is_mobile: false,
+            has_touch: false,
+            ..pixel.clone()
+        })
+        .unwrap();
+        assert_eq!(serde_json::to_value(&desktop.touch).unwrap(), serde_json::json!({ "enabled": false }));
+
+        let empty = DeviceDescriptor { width: 0, ..pixel.clone() };
+        assert!(device_overrides(empty).is_err());
+        let unscaled = DeviceDescriptor { device_scale_factor: 0.0, ..pixel };
+        assert!(device_overrides(unscaled).is_err());
+    }
+
+    #[test]
+    fn test_emulation_params() {
+        let berlin = Geolocation { latitude: 52.52, longitude: 13.405, accuracy: None };
+        assert_eq!(
+            serde_json::to_value(geolocation_params(berlin).unwrap()).unwrap(),
+            serde_json::json!({ "latitude": 52.52, "longitude": 13.405, "accuracy": 0.0 })
+        );
+        assert!(geolocation_params(Geolocation { latitude: 91.0, ..berlin }).is_err());
+        assert!(geolocation_params(Geolocation { longitude: -180.5, ..berlin }).is_err());
+        assert!(geolocation_params(Geolocation { accuracy: Some(-1.0), ..berlin }).is_err());
+
+        let media = MediaEmulation {
+            media_type: Some(MediaType::Print),
+            color_scheme: Some(ColorScheme::Dark),
+            reduced_motion: Some(ReducedMotion::Reduce),
+        };
+        assert_eq!(
+            serde_json::to_value(emulated_media_params(media)).unwrap(),
+            serde_json::json!({
+                "media": "print",
+                "features": [
+                    { "name": "prefers-color-scheme", "value": "dark" },
+                    { "name": "prefers-reduced-motion", "value": "reduce" },
+                ],
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(emulated_media_params(MediaEmulation::default())).unwrap(),
+            serde_json::json!({ "media": "", "features": [] })
+        );
+
+        let options = BrowserContextOptions {
+            timezone_id: Some("Europe/Berlin".into()),
+            geolocation: Some(berlin),
+            ..Default::default()
+        };
+        let emulation = context_emulation(&options).unwrap();
+        assert!(matches!(&emulation[..], [SetEmulation::Timezone(_), SetEmulation::Geolocation(Some(_))]));
+        assert!(context_emulation(&BrowserContextOptions::default()).unwrap().is_empty());
+        let invalid = BrowserContextOptions { geolocation: Some(Geolocation { latitude: 91.0, ..berlin }), ..Default::default() };
+        assert!(context_emulation(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_input_file_paths() {
+        let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let paths = input_file_paths(std::slice::from_ref(&manifest), false).unwrap();
+        assert_eq!(paths, vec![manifest.to_str().unwrap().to_string()]);
+        assert!(input_file_paths(&[], false).unwrap().is_empty());
+
+        let two = [manifest.clone(), manifest.clone()];
+        assert!(input_file_paths(&two, false).is_err());
+        assert_eq!(input_file_paths(&two, true).unwrap().len(), 2);
+        // Paths are resolved by the browser, which may run on another machine.
+        let remote = PathBuf::from("/srv/uploads/missing.toml");
+        assert_eq!(input_file_paths(&[remote], false).unwrap(), vec!["/srv/uploads/missing.toml".to_string()]);
+    }
+
+    #[test]
+    fn test_downloads() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let (params, download_dir) =
+            download_behavior_params(DownloadBehavior::Allow { download_path: dir.clone() }, Some("ctx".into())).unwrap();
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(json["behavior"], "allowAndName");
+        assert_eq!(json["browserContextId"], "ctx");
+        assert_eq!(json["downloadPath"], dir.to_str().unwrap());
+        assert_eq!(json["eventsEnabled"], true);
+        assert_eq!(download_dir, Some(dir.clone()));
+        let (params, download_dir) = download_behavior_params(DownloadBehavior::Deny, None).unwrap();
+        assert_eq!(serde_json::to_value(&params).unwrap(), serde_json::json!({"behavior": "deny", "eventsEnabled": true}));
+        assert!(download_dir.is_none());
+        let missing = DownloadBehavior::Allow { download_path: dir.join("Cargo.toml") };
+        assert!(download_behavior_params(missing, None).is_err());
+
+        let will_begin: DownloadWillBeginParams = serde_json::from_value(serde_json::json!({
+            "frameId": "F1", "guid": "g1", "url": "https://example.com/report.csv", "suggestedFilename": "report.csv"
+        }))
+        .unwrap();
+        let started = download_started_from_cdp(will_begin);
+        assert_eq!(started.suggested_filename, "report.csv");
+        let progress = |state: &str, received: f64| -> DownloadProgressParams {
+            serde_json::from_value(serde_json::json!({
+                "guid": "g1", "totalBytes": 0, "receivedBytes": received, "state": state
+            }))
+            .unwrap()
+        };
+        let in_progress = download_progress_from_cdp(progress("inProgress", 10.0), None).unwrap();
+        assert_eq!(in_progress.state, DownloadState::InProgress);
+        assert_eq!(in_progress.received_bytes, 10);
+        assert_eq!(in_progress.total_bytes, None);
+        assert!(download_progress_from_cdp(progress("unknown", 0.0), None).is_none());
+
+        // Only downloads of a context with a directory set have an expected path.
+        let dirs = HashMap::from([(None, dir.clone())]);
+        assert_eq!(saved_path(&dirs, Some(None), "g1"), Some(dir.join("g1")));
+        assert_eq!(saved_path(&dirs, Some(Some("ctx".to_string())), "g1"), None);
+        assert_eq!(saved_path(&dirs, None, "g1"), None);
+        let dirs = HashMap::from([(Some("ctx".to_string()), dir.join("ctx"))]);
+        assert_eq!(saved_path(&dirs, Some(Some("ctx".to_string())), "g1"), Some(dir.join("ctx").join("g1")));
+        assert_eq!(saved_path(&dirs, Some(None), "g1"), None);
+
+        let saved = dir.join("g1");
+        let completed = download_progress_from_cdp(progress("completed", 20.0), Some(saved.clone())).unwrap();
+        assert_eq!(completed.path.as_ref(), Some(&saved));
+        let mut waiter = DownloadWaiter::default();
+        assert!(waiter.on_event(DownloadEvent::Started(started.clone())).is_none());
+        assert!(waiter.on_event(DownloadEvent::Progress(in_progress)).is_none());
+        let download = waiter.on_event(DownloadEvent::Progress(completed)).unwrap().unwrap();
+        assert_eq!(download.path, saved);
+        assert_eq!(download.url, "https://example.com/report.csv");
+
+        let mut waiter = DownloadWaiter::default();
+        waiter.on_event(DownloadEvent::Started(started));
+        let canceled = download_progress_from_cdp(progress("canceled", 5.0), None).unwrap();
+        assert!(matches!(
+            waiter.on_event(DownloadEvent::Progress(canceled)),
+            Some(Err(ApiError::DownloadFailed { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_frame_tracker() {
+        let mut frames = FrameTracker::default();
+        let tree: GetFrameTreeResult = serde_json::from_value(serde_json::json!({
+            "frameTree": {
+                "frame": { "id": "MAIN", "url": "https://example.com/", "urlFragment": "#top" },
+                "childFrames": [{ "frame": { "id": "A", "parentId": "MAIN", "name": "ads", "url": "https://example.com/a" } }]
+            }
+        }))
+        .unwrap();
+        frames.load_tree(&tree.frame_tree, None);
+        frames.attached("B", "A", None);
+        let ids = |frames: &FrameTracker| frames.in_tree_order().into_iter().map(|frame| frame.id).collect::<Vec<_>>();
+        assert_eq!(ids(&frames), ["MAIN", "A", "B"]);
+        assert_eq!(frames.get("MAIN").unwrap().url, "https://example.com/#top");
+        assert_eq!(frames.get("A").unwrap().name, "ads");
+
+        // Main world contexts are used for evaluation; child frames need one.
+        assert_eq!(frames.execution_target("MAIN").unwrap(), (None, None));
+        assert!(frames.execution_target("A").is_err());
+        let created = |id: i64, frame_id: &str, is_default: bool| -> ExecutionContextCreatedParams {
+            serde_json::from_value(serde_json::json!({
+                "context": { "id": id, "auxData": { "frameId": frame_id, "isDefault": is_default } }
+            }))
+            .unwrap()
+        };
+        frames.context_created(&created(7, "A", false).context, None);
+        assert!(frames.execution_target("A").is_err());
+        frames.context_created(&created(8, "A", true).context, None);
+        assert_eq!(frames.execution_target("A").unwrap(), (None, Some(8)));
+        assert_eq!(frames.session_contexts(None), [8]);
+        assert!(frames.session_contexts(Some("S1")).is_empty());
+        frames.context_destroyed(8, None);
+        assert!(frames.execution_target("A").is_err());
+
+        // An out-of-process frame keeps its parent, and is evaluated in through its session.
+        frames.detached("B", true);
+        let navigated: FrameNavigatedParams = serde_json::from_value(serde_json::json!({
+            "frame": { "id": "B", "url": "https://other.example/" }
+        }))
+        .unwrap();
+        frames.navigated(&navigated.frame, Some("S1"));
+        assert_eq!(frames.get("B").unwrap().parent_id.as_deref(), Some("A"));
+        assert_eq!(frames.execution_target("B").unwrap(), (Some("S1".to_string()), None));
+        frames.session_detached("S1");
+        assert_eq!(ids(&frames), ["MAIN", "A"]);
+
+        frames.attached("B", "A", None);
+        frames.detached("A", false);
+        assert_eq!(ids(&frames), ["MAIN"]);
+        assert!(frames.execution_target("A").is_err());
+    }
+
+    #[test]
+    fn test_call_function_params() {
+        // Page functions run in a context; handles are passed by object ID.
+        let params = CallFunctionOnParams {
+            function_declaration: "(el, n) => el.children.length + n",
+            object_id: None,
+            execution_context_id: Some(3),
+            arguments: vec![
+                CallArgument { value: None, object_id: Some("-1.2.3".to_string()) },
+                CallArgument { value: Some(serde_json::json!(1)), object_id: None },
+            ],
+            return_by_value: Some(true),
+            await_promise: Some(true),
+        };
+        assert_eq!(
+            serde_json::to_value(params).unwrap(),
+            serde_json::json!({
+                "functionDeclaration": "(el, n) => el.children.length + n",
+                "executionContextId": 3,
+                "arguments": [{ "objectId": "-1.2.3" }, { "value": 1 }],
+                "returnByValue": true,
+                "awaitPromise": true
+            })
+        );
+
+        let properties: GetPropertiesResult = serde_json::from_value(serde_json::json!({
+            "result": [
+                { "name": "0", "value": { "type": "number", "value": 7, "description": "7" }, "enumerable": true },
+                { "name": "size", "get": { "type": "function" }, "enumerable": false }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(properties.result[0].value.as_ref().unwrap().value, 7);
+        assert!(properties.result[1].value.is_none() && !properties.result[1].enumerable);
+    }
+
+    #[test]
+    fn test_js_value_from_remote() {
+        let remote = |json: Value| js_value_from_remote(&serde_json::from_value(json).unwrap());
+        assert_eq!(remote(serde_json::json!({ "type": "undefined" })), Some(JsValue::Undefined));
+        assert_eq!(
+            remote(serde_json::json!({ "type": "bigint", "unserializableValue": "123n", "description": "123n" })),
+            Some(JsValue::BigInt("123".to_string()))
+        );
+        assert_eq!(
+            remote(serde_json::json!({ "type": "number", "unserializableValue": "-Infinity" })),
+            Some(JsValue::Number(f64::NEG_INFINITY))
+        );
+        assert!(matches!(
+            remote(serde_json::json!({ "type": "number", "unserializableValue": "NaN" })),
+            Some(JsValue::Number(n)) if n.is_nan()
+        ));
+        assert_eq!(
+            remote(serde_json::json!({ "type": "object", "subtype": "null", "value": null })),
+            Some(JsValue::Json(Value::Null))
+        );
+        // Objects are serialized in the page first.
+        assert_eq!(
+            remote(serde_json::json!({ "type": "object", "subtype": "date", "objectId": "1.2" })),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bindings() {
+        let script = binding_script("report\"Result");
+        assert!(script.contains(r#"const name = "report\"Result";"#));
+        assert_eq!(
+            parse_binding_call(r#"{"seq":2,"args":[{"passed":3},null]}"#),
+            Some(BindingCall { seq: 2, args: vec![serde_json::json!({ "passed": 3 }), Value::Null] })
+        );
+        assert_eq!(parse_binding_call("plain string"), None);
+
+        let resolved = serde_json::to_value(deliver_arguments("report", 2, Ok(serde_json::json!(true)))).unwrap();
+        assert_eq!(
+            resolved,
+            serde_json::json!([{ "value": "report" }, { "value": 2 }, { "value": null }, { "value": true }])
+        );
+        let rejected = serde_json::to_value(deliver_arguments("report", 3, Err("boom".to_string()))).unwrap();
+        assert_eq!(rejected[2], serde_json::json!({ "value": "boom" }));
+    }
+
+    #[test]
+    fn test_parse_selector() {
+        let css = |selector: &str| Step::Css { selector: selector.to_string() };
+        assert_eq!(parse_selector("form > .submit"), Ok(vec![css("form > .submit")]));
+        assert_eq!(parse_selector("input[type=submit]"), Ok(vec![css("input[type=submit]")]));
+        assert_eq!(
+            parse_selector("//div[@id='a'] >> xpath=./span"),
+            Ok(vec![
+                Step::Xpath { expression: "//div[@id='a']".to_string() },
+                Step::Xpath { expression: "./span".to_string() },
+            ])
+        );
+        assert_eq!(
+            parse_selector(r#"css=#login >> text=Sign in >> "Sign \"in\"""#),
+            Ok(vec![
+                css("#login"),
+                Step::Text { text: "Sign in".to_string(), exact: false },
+                Step::Text { text: "Sign \"in\"".to_string(), exact: true },
+            ])
+        );
+        // `>>` inside quotes does not chain.
+        assert_eq!(
+            parse_selector(r#"role=button[name="Next >> step"]"#),
+            Ok(vec![Step::Role { role: "button".to_string(), name: Some("Next >> step".to_string()) }])
+        );
+        // An apostrophe inside unquoted text is not a quote.
+        assert_eq!(
+            parse_selector("text=Don't save >> css=button"),
+            Ok(vec![
+                Step::Text { text: "Don't save".to_string(), exact: false },
+                Step::Css { selector: "button".to_string() },
+            ])
+        );
+        assert_eq!(parse_selector("role=heading"), Ok(vec![Step::Role { role: "heading".to_string(), name: None }]));
+        assert!(parse_selector("div >> ").is_err());
+        assert!(parse_selector("role=button[level=2]").is_err());
+
+        let filter = Step::Filter { has_text: Some("Total".to_string()), has: vec![css("b")] };
+        assert_eq!(
+            serde_json::to_value(vec![Step::Nth { index: -1 }, filter]).unwrap(),
+            serde_json::json!([
+                { "engine": "nth", "index": -1 },
+                { "engine": "filter", "hasText": "Total", "has": [{ "engine": "css", "selector": "b" }] },
+            ])
+        );
+
+        // The script builds strict mode violations from the prefix it is passed.
+        let args = script_arguments(&[css("button")], "click", true);
+        let violation = args[3].value.as_ref().and_then(Value::as_str).unwrap();
+        assert!(LOCATOR_SCRIPT.contains("return violation + elements.length + ' elements match: '"));
+        let reason = format!("{}{} elements match: {}", violation, 2, "button.primary, button");
+        assert_eq!(parse_strict_violation(&reason), Some("2 elements match: button.primary, button"));
+        assert_eq!(parse_strict_violation("not visible"), None);
+    }
+
+    #[test]
+    fn test_wait_for_function_slices() {
+        let script = wait_script("(min) => window.items.length >= min");
+        assert!(script.contains("const predicate = ((min) => window.items.length >= min);"));
+        assert_eq!(polling_arguments(Polling::RequestAnimationFrame), ("raf", 0));
+        assert_eq!(polling_arguments(Polling::Interval(250)), ("interval", 250));
+        assert_eq!(polling_arguments(Polling::Mutation), ("mutation", 0));
+
+        let slice = parse_wait_slice(serde_json::json!({ "done": false, "timeOrigin": 1700000000000.5 })).unwrap();
+        assert_eq!(slice, WaitSlice { done: false, value: Value::Null, time_origin: 1700000000000.5 });
+        assert!(parse_wait_slice(serde_json::json!("unexpected")).is_err());
+
+        // A lost context cancels the wait only once a slice ran in a document.
+        let destroyed = || ApiError::ProtocolError("Execution context was destroyed.".to_string());
+        assert!(matches!(slice_error(destroyed(), true), Some(ApiError::WaitCancelled(_))));
+        assert!(slice_error(destroyed(), false).is_none());
+        let missing = ApiError::ProtocolError("Cannot find context with specified id".to_string());
+        assert!(slice_error(missing, false).is_none());
+        // The next slice tells a navigation from a close.
+        let ambiguous = ApiError::ProtocolError("Inspected target navigated or closed".to_string());
+        assert!(slice_error(ambiguous, true).is_none());
+        let closed = ApiError::ProtocolError("Session with given id not found.".to_string());
+        assert!(matches!(slice_error(closed, true), Some(ApiError::TargetDetached)));
+        let other = ApiError::ProtocolError("Invalid parameters".to_string());
+        assert!(matches!(slice_error(other, true), Some(ApiError::ProtocolError(_))));
+    }
 }
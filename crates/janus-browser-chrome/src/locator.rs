@@ -0,0 +1,572 @@
+//! L2 Implementation of `janus_interfaces::Locator` for Chrome.
+//!
+//! Selectors are parsed into steps here, and resolved in the page's main world by
+//! [`LOCATOR_SCRIPT`], which also runs the actionability checks of an action. Actions
+//! poll the script with `wait_until` until it returns the element, then act on it through
+//! an element handle.
+
+use crate::actors::{CallFunctionOn, ChromePageActor, InsertText, ReleaseObject};
+use crate::element::ChromeElementHandle;
+use crate::error::map_internal_to_api_error;
+use crate::page::ChromePage;
+use crate::protocol::{CallArgument, RemoteObject};
+use actix::prelude::*;
+use async_trait::async_trait;
+use janus_interfaces::{ApiError, DEFAULT_LOCATOR_TIMEOUT_MS, Condition, ElementHandle, ElementState, Locator, LocatorFilter, Page, wait_until};
+use log::debug;
+use serde::Serialize;
+use serde_json::json;
+
+/// One step of a parsed selector, as consumed by [`LOCATOR_SCRIPT`].
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "engine", rename_all = "camelCase")]
+pub(crate) enum Step {
+    Css { selector: String },
+    Xpath { expression: String },
+    Text { text: String, exact: bool },
+    Role { role: String, name: Option<String> },
+    Nth { index: i32 },
+    Filter {
+        #[serde(rename = "hasText")]
+        has_text: Option<String>,
+        has: Vec<Step>, // Empty for no descendant condition
+    },
+}
+
+/// What an evaluation of [`LOCATOR_SCRIPT`] checks, and what it returns once it passes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Count,     // The number of elements
+    IsVisible, // Whether the element is visible, without waiting
+    IsEnabled, // Whether the element is enabled, once attached
+    Attached,  // The element
+    Visible,   // true
+    Hidden,    // true
+    Detached,  // true
+    Click,     // The element, once actionable
+    Hover,     // The element, once actionable
+    Fill,      // The element, once actionable
+}
+
+impl Mode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Mode::Count => "count",
+            Mode::IsVisible => "isVisible",
+            Mode::IsEnabled => "isEnabled",
+            Mode::Attached => "attached",
+            Mode::Visible => "visible",
+            Mode::Hidden => "hidden",
+            Mode::Detached => "detached",
+            Mode::Click => "click",
+            Mode::Hover => "hover",
+            Mode::Fill => "fill",
+        }
+    }
+}
+
+/// Marks the reason returned when several elements match a strict action; passed to
+/// [`LOCATOR_SCRIPT`] as its `violation` argument.
+const STRICT_VIOLATION: &str = "strict mode violation: ";
+
+/// Resolves `steps` from the document and checks the element for `mode`. Returns the
+/// result of the mode once the checks pass, or a string telling what is missing.
+pub(crate) const LOCATOR_SCRIPT: &str = r#"async function(steps, mode, strict, violation) {
+    const normalize = text => (text || '').replace(/\s+/g, ' ').trim();
+    const contains = (text, wanted) => normalize(text).toLowerCase().includes(normalize(wanted).toLowerCase());
+    const SKIPPED = ['SCRIPT', 'STYLE', 'HEAD', 'TITLE', 'NOSCRIPT', 'TEMPLATE'];
+    const descendants = root => [...root.querySelectorAll('*')].filter(el => !SKIPPED.includes(el.nodeName));
+
+    // Like `textContent`, without the text of SKIPPED descendants.
+    function textOf(el) {
+        const walker = document.createTreeWalker(el, NodeFilter.SHOW_ELEMENT | NodeFilter.SHOW_TEXT, {
+            acceptNode: node => SKIPPED.includes(node.nodeName) ? NodeFilter.FILTER_REJECT : NodeFilter.FILTER_ACCEPT,
+        });
+        let text = '';
+        for (let node = walker.nextNode(); node; node = walker.nextNode()) {
+            if (node.nodeType === Node.TEXT_NODE) text += node.data;
+        }
+        return text;
+    }
+
+    function roleOf(el) {
+        const explicit = el.getAttribute('role');
+        if (explicit) return explicit.trim().split(/\s+/)[0];
+        switch (el.nodeName) {
+            case 'A': case 'AREA': return el.hasAttribute('href') ? 'link' : null;
+            case 'BUTTON': return 'button';
+            case 'H1': case 'H2': case 'H3': case 'H4': case 'H5': case 'H6': return 'heading';
+            case 'INPUT':
+                if (['button', 'submit', 'reset', 'image'].includes(el.type)) return 'button';
+                if (el.type === 'checkbox' || el.type === 'radio') return el.type;
+                if (el.type === 'range') return 'slider';
+                if (el.type === 'number') return 'spinbutton';
+                if (el.type === 'search') return el.hasAttribute('list') ? 'combobox' : 'searchbox';
+                if (['text', 'email', 'tel', 'url'].includes(el.type)) return el.hasAttribute('list') ? 'combobox' : 'textbox';
+                return null;
+            case 'TEXTAREA': return 'textbox';
+            case 'SELECT': return el.multiple || el.size > 1 ? 'listbox' : 'combobox';
+            case 'OPTION': return 'option';
+            case 'IMG': return el.getAttribute('alt') === '' ? 'presentation' : 'img';
+            case 'UL': case 'OL': return 'list';
+            case 'LI': return 'listitem';
+            case 'NAV': return 'navigation';
+            case 'MAIN': return 'main';
+            case 'DIALOG': return 'dialog';
+            case 'FORM': return 'form';
+            case 'ARTICLE': return 'article';
+            case 'P': return 'paragraph';
+            case 'TABLE': return 'table';
+            case 'TR': return 'row';
+            case 'TD': return 'cell';
+            case 'TH': return 'columnheader';
+        }
+        return null;
+    }
+
+    function accessibleName(el) {
+        const labelledBy = el.getAttribute('aria-labelledby');
+        if (labelledBy) {
+            return normalize(labelledBy.split(/\s+/).map(id => document.getElementById(id)?.textContent).join(' '));
+        }
+        if (el.getAttribute('aria-label')) return normalize(el.getAttribute('aria-label'));
+        if (el.labels && el.labels.length) return normalize([...el.labels].map(label => label.textContent).join(' '));
+        if (el.nodeName === 'IMG') return normalize(el.getAttribute('alt'));
+        if (el.nodeName === 'INPUT' && ['button', 'submit', 'reset'].includes(el.type)) return normalize(el.value);
+        if (['INPUT', 'TEXTAREA', 'SELECT'].includes(el.nodeName)) {
+            return normalize(el.getAttribute('placeholder') || el.getAttribute('title'));
+        }
+        return normalize(el.textContent || el.getAttribute('title'));
+    }
+
+    function query(step, root) {
+        switch (step.engine) {
+            case 'css': return [...root.querySelectorAll(step.selector)];
+            case 'xpath': {
+                // Absolute paths are scoped to the element of the previous step.
+                const expression = root !== document && step.expression.startsWith('/') ? '.' + step.expression : step.expression;
+                const result = document.evaluate(expression, root, null, XPathResult.ORDERED_NODE_SNAPSHOT_TYPE, null);
+                const found = [];
+                for (let i = 0; i < result.snapshotLength; i++) {
+                    if (result.snapshotItem(i).nodeType === Node.ELEMENT_NODE) found.push(result.snapshotItem(i));
+                }
+                return found;
+            }
+            case 'text': {
+                const wanted = normalize(step.text);
+                const matches = descendants(root).filter(el =>
+                    step.exact ? normalize(textOf(el)) === wanted : contains(textOf(el), wanted));
+                // Only the innermost elements containing the text.
+                return matches.filter(el => !matches.some(other => other !== el && el.contains(other)));
+            }
+            case 'role':
+                return descendants(root).filter(el => roleOf(el) === step.role
+                    && !el.closest('[aria-hidden="true"]')
+                    && (step.name == null || contains(accessibleName(el), step.name)));
+        }
+        return [];
+    }
+
+    function resolve(steps, roots) {
+        let elements = roots;
+        for (const step of steps) {
+            if (step.engine === 'nth') {
+                const el = elements[step.index < 0 ? elements.length + step.index : step.index];
+                elements = el ? [el] : [];
+            } else if (step.engine === 'filter') {
+                elements = elements.filter(el => (step.hasText == null || contains(textOf(el), step.hasText))
+                    && (step.has.length === 0 || resolve(step.has, [el]).length > 0));
+            } else {
+                const found = new Set();
+                for (const root of elements) {
+                    for (const el of query(step, root)) found.add(el);
+                }
+                elements = [...found].sort((a, b) => a.compareDocumentPosition(b) & Node.DOCUMENT_POSITION_FOLLOWING ? -1 : 1);
+            }
+        }
+        return elements;
+    }
+
+    const isVisible = el => {
+        if (!el.isConnected || getComputedStyle(el).visibility !== 'visible') return false;
+        const r = el.getBoundingClientRect();
+        return r.width > 0 && r.height > 0;
+    };
+    const isEnabled = el => !el.matches(':disabled') && !el.closest('[aria-disabled="true"]');
+    const isEditable = el => el.isContentEditable || el.nodeName === 'TEXTAREA' && !el.readOnly
+        || el.nodeName === 'INPUT' && !el.readOnly
+            && !['checkbox', 'radio', 'button', 'submit', 'reset', 'file', 'image', 'range', 'color', 'hidden'].includes(el.type);
+    const describe = el => el.nodeName.toLowerCase() + (el.id ? '#' + el.id : '')
+        + [...el.classList].map(name => '.' + name).join('');
+    // Two animation frames, or a timeout in pages that are not rendering.
+    const nextFrame = () => new Promise(resolve => { requestAnimationFrame(resolve); setTimeout(resolve, 100); });
+
+    const elements = resolve(steps, [document]);
+    if (mode === 'count') return elements.length;
+    if (strict && elements.length > 1) {
+        return violation + elements.length + ' elements match: '
+            + elements.slice(0, 3).map(describe).join(', ') + (elements.length > 3 ? ', ...' : '');
+    }
+    const el = elements[0];
+    switch (mode) {
+        case 'isVisible': return !!el && isVisible(el);
+        case 'detached': return el ? 'attached' : true;
+        case 'hidden': return el && isVisible(el) ? 'visible' : true;
+    }
+    if (!el) return 'no element matches';
+    switch (mode) {
+        case 'attached': return el;
+        case 'isEnabled': return isEnabled(el);
+        case 'visible': return isVisible(el) ? true : 'not visible';
+    }
+    if (!isVisible(el)) return 'not visible';
+    if (mode !== 'hover' && !isEnabled(el)) return 'not enabled';
+    if (mode === 'fill') return isEditable(el) ? el : 'not editable';
+
+    el.scrollIntoView({ block: 'center', inline: 'center', behavior: 'instant' });
+    const before = el.getBoundingClientRect();
+    await nextFrame();
+    await nextFrame();
+    const r = el.getBoundingClientRect();
+    if (r.x !== before.x || r.y !== before.y || r.width !== before.width || r.height !== before.height) return 'not stable';
+    const root = el.getRootNode().elementFromPoint ? el.getRootNode() : el.ownerDocument;
+    const hit = root.elementFromPoint(r.left + r.width / 2, r.top + r.height / 2);
+    if (!hit || (hit !== el && !el.contains(hit))) return 'obscured by ' + (hit ? describe(hit) : 'nothing');
+    return el;
+}"#;
+
+// Focuses the element and selects its content, which the next text input replaces.
+const SELECT_CONTENT: &str = "function() {
+    this.focus();
+    if (this.isContentEditable) {
+        const range = document.createRange();
+        range.selectNodeContents(this);
+        getSelection().removeAllRanges();
+        getSelection().addRange(range);
+    } else {
+        this.select();
+    }
+}";
+
+/// Arguments of [`LOCATOR_SCRIPT`].
+pub(crate) fn script_arguments(steps: &[Step], mode: &str, strict: bool) -> Vec<CallArgument> {
+    [json!(steps), json!(mode), json!(strict), json!(STRICT_VIOLATION)]
+        .into_iter()
+        .map(|value| CallArgument { value: Some(value), object_id: None })
+        .collect()
+}
+
+/// What several elements matched, if `reason` (returned by [`LOCATOR_SCRIPT`]) is a
+/// strict mode violation.
+pub(crate) fn parse_strict_violation(reason: &str) -> Option<&str> {
+    reason.strip_prefix(STRICT_VIOLATION)
+}
+
+/// Parses a selector into steps; `Err` describes the problem.
+pub(crate) fn parse_selector(selector: &str) -> Result<Vec<Step>, String> {
+    split_chain(selector).into_iter().map(parse_step).collect()
+}
+
+const ENGINES: [&str; 4] = ["css", "xpath", "text", "role"];
+
+// Splits on `>>` outside quotes. A quote opens only at the start of a step's body or
+// inside `[...]`, so apostrophes in unquoted text (`text=Don't save`) are literal.
+fn split_chain(selector: &str) -> Vec<&str> {
+    let bytes = selector.as_bytes();
+    let mut parts = Vec::new();
+    let mut quote = None;
+    let mut brackets = 0usize;
+    let mut body_start = true; // Nothing but whitespace or `engine=` so far in the step
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let mut at_body_start = false;
+        match (quote, bytes[i]) {
+            (Some(_), b'\\') => i += 1,
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, c @ (b'"' | b'\'')) if body_start || brackets > 0 => quote = Some(c),
+            (None, b'>') if bytes.get(i + 1) == Some(&b'>') => {
+                parts.push(&selector[start..i]);
+                start = i + 2;
+                i += 1;
+                brackets = 0;
+                at_body_start = true;
+            }
+            (None, b'[') => brackets += 1,
+            (None, b']') => brackets = brackets.saturating_sub(1),
+            (None, b'=') => at_body_start = ENGINES.contains(&selector[start..i].trim()),
+            (None, c) => at_body_start = body_start && c.is_ascii_whitespace(),
+        }
+        body_start = at_body_start;
+        i += 1;
+    }
+    parts.push(&selector[start..]);
+    parts
+}
+
+fn parse_step(part: &str) -> Result<Step, String> {
+    let part = part.trim();
+    let (engine, body) = match part.split_once('=') {
+        Some((engine, body)) if ENGINES.contains(&engine.trim()) => (engine.trim(), body.trim()),
+        _ if part.starts_with("//") || part.starts_with("..") => ("xpath", part),
+        _ if part.starts_with('"') || part.starts_with('\'') => ("text", part),
+        _ => ("css", part),
+    };
+    if body.is_empty() {
+        return Err(format!("Empty {} selector in \"{}\"", engine, part));
+    }
+    Ok(match engine {
+        "xpath" => Step::Xpath { expression: body.to_string() },
+        "text" => match unquote(body) {
+            Some(text) => Step::Text { text, exact: true },
+            None => Step::Text { text: body.to_string(), exact: false },
+        },
+        "role" => parse_role(body)?,
+        _ => Step::Css { selector: body.to_string() },
+    })
+}
+
+// `button` or `button[name="Sign in"]`
+fn parse_role(body: &str) -> Result<Step, String> {
+    let (role, attribute) = match body.find('[') {
+        Some(index) => (body[..index].trim(), Some(&body[index..])),
+        None => (body.trim(), None),
+    };
+    if role.is_empty() || !role.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(format!("Invalid role in \"role={}\"", body));
+    }
+    let name = match attribute {
+        None => None,
+        Some(attribute) => {
+            let (key, value) = attribute
+                .strip_prefix('[')
+                .and_then(|attribute| attribute.strip_suffix(']'))
+                .and_then(|attribute| attribute.split_once('='))
+                .ok_or_else(|| format!("Invalid attribute in \"role={}\"", body))?;
+            if key.trim() != "name" {
+                return Err(format!("Unsupported attribute \"{}\" in \"role={}\"; only name is", key.trim(), body));
+            }
+            let value = value.trim();
+            Some(unquote(value).unwrap_or_else(|| value.to_string()))
+        }
+    };
+    Ok(Step::Role { role: role.to_string(), name })
+}
+
+// The content of a single- or double-quoted string, with `\` escapes resolved.
+fn unquote(text: &str) -> Option<String> {
+    let quote = text.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let inner = text.strip_prefix(quote)?.strip_suffix(quote)?;
+    let mut unquoted = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unquoted.extend(chars.next()),
+            c if c == quote => return None, // Ends before the last character
+            c => unquoted.push(c),
+        }
+    }
+    Some(unquoted)
+}
+
+#[derive(Debug, Clone)]
+pub struct ChromeLocator {
+    actor_addr: Addr<ChromePageActor>,
+    page_id: String,
+    steps: Result<Vec<Step>, String>, // Selector errors surface on the first action
+    description: String,
+    timeout_ms: u64,
+}
+
+impl ChromeLocator {
+    pub(crate) fn new(actor_addr: Addr<ChromePageActor>, page_id: String, selector: &str) -> Self {
+        Self {
+            actor_addr,
+            page_id,
+            steps: parse_selector(selector),
+            description: selector.to_string(),
+            timeout_ms: DEFAULT_LOCATOR_TIMEOUT_MS,
+        }
+    }
+
+    fn with_steps(&self, steps: Result<Vec<Step>, String>, description: String) -> Box<dyn Locator> {
+        let steps = match (&self.steps, steps) {
+            (Ok(own), Ok(steps)) => Ok(own.iter().cloned().chain(steps).collect()),
+            (Err(e), _) => Err(e.clone()),
+            (_, Err(e)) => Err(e),
+        };
+        Box::new(Self {
+            steps,
+            description: format!("{} >> {}", self.description, description),
+            ..self.clone()
+        })
+    }
+
+    // Evaluates the script once.
+    async fn evaluate(&self, mode: Mode, strict: bool) -> Result<RemoteObject, ApiError> {
+        let steps = self.steps.clone().map_err(ApiError::InvalidParameters)?;
+        let args = script_arguments(&steps, mode.as_str(), strict);
+        self.actor_addr
+            .send(CallFunctionOn {
+                session_id: None,
+                object_id: None,
+                function_declaration: LOCATOR_SCRIPT.to_string(),
+                args,
+                return_by_value: false,
+            })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error resolving locator: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    // Evaluates the script until its checks pass, within the timeout. A timeout reports
+    // the last reason the checks failed.
+    async fn poll(&self, mode: Mode, strict: bool) -> Result<RemoteObject, ApiError> {
+        wait_until(self.timeout_ms, || async {
+            let reason = match self.evaluate(mode, strict).await {
+                // Any string is the reason the checks did not pass yet.
+                Ok(result) => match result.value.as_str() {
+                    Some(reason) if result.object_id.is_none() => reason.to_string(),
+                    _ => return Ok(Condition::Met(result)),
+                },
+                // The page is navigating; its next document gets a new context.
+                Err(ApiError::ProtocolError(e)) => e,
+                Err(e) => return Err(e),
+            };
+            if let Some(err) = self.strict_violation(&reason) {
+                return Err(err);
+            }
+            Ok(Condition::Unmet(format!("{} {}", self.description, reason)))
+        })
+        .await
+    }
+
+    // The error for a reason of the script telling that several elements match.
+    fn strict_violation(&self, reason: &str) -> Option<ApiError> {
+        let violation = parse_strict_violation(reason)?;
+        Some(ApiError::InvalidParameters(format!("{}: {}", self.description, violation)))
+    }
+
+    // Waits for the checks of `mode` and returns a handle to the element.
+    async fn element(&self, mode: Mode) -> Result<ChromeElementHandle, ApiError> {
+        let object = self.poll(mode, true).await?;
+        ChromeElementHandle::from_remote_object(self.actor_addr.clone(), self.page_id.clone(), None, object)
+            .await?
+            .ok_or_else(|| ApiError::InternalError(format!("Locator {} did not resolve to an element", self.description)))
+    }
+}
+
+#[async_trait]
+impl Locator for ChromeLocator {
+    fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn locator(&self, selector: &str) -> Box<dyn Locator> {
+        self.with_steps(parse_selector(selector), selector.to_string())
+    }
+
+    fn nth(&self, index: i32) -> Box<dyn Locator> {
+        self.with_steps(Ok(vec![Step::Nth { index }]), format!("nth={}", index))
+    }
+
+    fn first(&self) -> Box<dyn Locator> {
+        self.nth(0)
+    }
+
+    fn last(&self) -> Box<dyn Locator> {
+        self.nth(-1)
+    }
+
+    fn filter(&self, filter: LocatorFilter) -> Box<dyn Locator> {
+        let has = match &filter.has {
+            Some(selector) => parse_selector(selector),
+            None => Ok(Vec::new()),
+        };
+        let description = format!("filter({:?})", filter);
+        let steps = has.map(|has| vec![Step::Filter { has_text: filter.has_text, has }]);
+        self.with_steps(steps, description)
+    }
+
+    fn with_timeout(&self, timeout_ms: u64) -> Box<dyn Locator> {
+        Box::new(Self { timeout_ms, ..self.clone() })
+    }
+
+    async fn count(&self) -> Result<usize, ApiError> {
+        let result = self.evaluate(Mode::Count, false).await?;
+        Ok(result.value.as_u64().unwrap_or_default() as usize)
+    }
+
+    async fn click(&self) -> Result<(), ApiError> {
+        debug!("Locator ({})::click", self.description);
+        self.element(Mode::Click).await?.click().await
+    }
+
+    async fn hover(&self) -> Result<(), ApiError> {
+        self.element(Mode::Hover).await?.hover().await
+    }
+
+    async fn fill(&self, text: &str) -> Result<(), ApiError> {
+        debug!("Locator ({})::fill", self.description);
+        let element = self.element(Mode::Fill).await?;
+        element.call_value(SELECT_CONTENT, vec![]).await?;
+        let page = ChromePage::new(self.actor_addr.clone(), self.page_id.clone());
+        if text.is_empty() {
+            page.keyboard_press("Delete").await
+        } else {
+            page.send_input(InsertText(text.to_string())).await
+        }
+    }
+
+    async fn type_text(&self, text: &str) -> Result<(), ApiError> {
+        self.element(Mode::Attached).await?.type_text(text).await
+    }
+
+    async fn focus(&self) -> Result<(), ApiError> {
+        self.element(Mode::Attached).await?.focus().await
+    }
+
+    async fn text_content(&self) -> Result<Option<String>, ApiError> {
+        self.element(Mode::Attached).await?.text_content().await
+    }
+
+    async fn inner_html(&self) -> Result<String, ApiError> {
+        self.element(Mode::Attached).await?.inner_html().await
+    }
+
+    async fn get_attribute(&self, name: &str) -> Result<Option<String>, ApiError> {
+        self.element(Mode::Attached).await?.get_attribute(name).await
+    }
+
+    async fn is_visible(&self) -> Result<bool, ApiError> {
+        let result = self.evaluate(Mode::IsVisible, true).await?;
+        if let Some(err) = result.value.as_str().and_then(|reason| self.strict_violation(reason)) {
+            return Err(err);
+        }
+        Ok(result.value.as_bool().unwrap_or(false))
+    }
+
+    async fn is_enabled(&self) -> Result<bool, ApiError> {
+        Ok(self.poll(Mode::IsEnabled, true).await?.value.as_bool().unwrap_or(false))
+    }
+
+    async fn element_handle(&self) -> Result<Box<dyn ElementHandle>, ApiError> {
+        Ok(Box::new(self.element(Mode::Attached).await?))
+    }
+
+    async fn wait_for(&self, state: ElementState) -> Result<(), ApiError> {
+        let mode = match state {
+            ElementState::Attached => Mode::Attached,
+            ElementState::Detached => Mode::Detached,
+            ElementState::Visible => Mode::Visible,
+            ElementState::Hidden => Mode::Hidden,
+        };
+        let result = self.poll(mode, true).await?;
+        if let Some(object_id) = result.object_id {
+            // The attached element itself; not needed.
+            self.actor_addr.do_send(ReleaseObject { session_id: None, object_id });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+//! Bookkeeping for navigations that wait on the main frame's lifecycle events.
+//!
+//! `Page.navigate` replies with the loader that fetches the new document; the
+//! navigation is finished once that loader reports the lifecycle event matching
+//! the requested `WaitUntil`.
+
+use janus_interfaces::{NavigationResult, WaitUntil};
+use std::collections::HashSet;
+
+/// Name of the `Page.lifecycleEvent` that completes a navigation for `wait_until`.
+pub(crate) fn lifecycle_event_name(wait_until: WaitUntil) -> &'static str {
+    match wait_until {
+        WaitUntil::Commit => "init", // Emitted when the new document is committed
+        WaitUntil::DomContentLoaded => "DOMContentLoaded",
+        WaitUntil::Load => "load",
+        WaitUntil::NetworkIdle => "networkIdle",
+    }
+}
+
+/// Main document response of a loader, from `Network.responseReceived`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DocumentResponse {
+    pub loader_id: String,
+    pub url: String,
+    pub status: Option<u16>,
+}
+
+/// Lifecycle progress of the main frame's current document.
+#[derive(Debug, Default)]
+pub(crate) struct MainFrameState {
+    loader_id: Option<String>,
+    lifecycle_events: HashSet<String>,
+    document_response: Option<DocumentResponse>,
+}
+
+impl MainFrameState {
+    /// Records a lifecycle event of the main frame. `init` starts a new document;
+    /// late events of a previous loader are ignored.
+    /// Returns true if the event started a new document.
+    pub(crate) fn record_lifecycle_event(&mut self, loader_id: &str, name: &str) -> bool {
+        let is_current = self.loader_id.as_deref() == Some(loader_id);
+        if name == "init" && !is_current {
+            self.loader_id = Some(loader_id.to_string());
+            self.lifecycle_events.clear();
+            self.lifecycle_events.insert(name.to_string());
+            return true;
+        }
+        if is_current {
+            self.lifecycle_events.insert(name.to_string());
+        }
+        false
+    }
+
+    pub(crate) fn record_document_response(&mut self, response: DocumentResponse) {
+        self.document_response = Some(response);
+    }
+
+    pub(crate) fn current_loader(&self) -> Option<&str> {
+        self.loader_id.as_deref()
+    }
+
+    /// Whether `loader_id` is the current document and has reached `event_name`.
+    pub(crate) fn has_reached(&self, loader_id: &str, event_name: &str) -> bool {
+        self.loader_id.as_deref() == Some(loader_id) && self.lifecycle_events.contains(event_name)
+    }
+
+    /// Result of the navigation performed by `loader_id`. Falls back to the requested
+    /// URL when no HTTP response was seen (e.g. `about:blank`, `data:` URLs).
+    pub(crate) fn navigation_result(&self, loader_id: &str, requested_url: &str) -> NavigationResult {
+        let response = self
+            .document_response
+            .as_ref()
+            .filter(|response| response.loader_id == loader_id);
+        NavigationResult {
+            loader_id: Some(loader_id.to_string()),
+            url: response
+                .map(|response| response.url.clone())
+                .unwrap_or_else(|| requested_url.to_string()),
+            status: response.and_then(|response| response.status),
+        }
+    }
+}
@@ -1,14 +1,48 @@
 //! L2 Implementation of `janus_interfaces::Page` for Chrome.
 
-use crate::actors::{ChromePageActor, ClosePage, EvaluateScript, Navigate};
+use crate::actors::{
+    AddInitScript, AddPageEventHandler, AddRoute, CallFunctionOn, CaptureScreenshot, ChromePageActor, ClosePage, CloseStream,
+    DeleteCookies, EmulateDevice, EvaluateHandle, EvaluateScript, ExposeFunction, GetCookies, GetFrames, GetLayoutMetrics, GetResponseBody,
+    HandleDialog, InsertText, KeyInput, MouseInput, Navigate, NavigateAndWait, PrintToPdf, ReadStream,
+    RemoveInitScript, RemovePageEventHandler, RemoveRoute, RequestClose, SetCookies, SetDialogPolicy, SetEmulation,
+    SetFileChooserFiles, SyncFileChooserInterception, StartHarRecording, StopHarRecording, TouchTap,
+};
+use crate::bindings::Binding;
+use crate::cookies;
+use crate::element::ChromeElementHandle;
+use crate::emulation;
 use crate::error::map_internal_to_api_error; // Need error mapping
+use crate::events::PageEventHandler;
+use crate::fetch::Route;
+use crate::frame::ChromeFrame;
+use crate::frames::FrameInfo;
+use crate::har;
+use crate::input;
+use crate::js_handle::{self, ChromeJsHandle};
+use crate::locator::ChromeLocator;
+use crate::pdf;
+use crate::protocol::{
+    CallArgument, HandleJavaScriptDialogParams, LayoutMetrics, RemoteObject, SetLocaleOverrideParams, SetTimezoneOverrideParams,
+};
+use crate::screenshot;
+use crate::upload;
+use crate::wait;
+use actix::dev::ToEnvelope;
 use actix::prelude::*;
 use async_trait::async_trait;
+use janus_core::error::InternalError;
 use janus_interfaces::{
-    ApiError, ElementHandle, Page, ScreenshotFormat, ScreenshotOptions, Value,
+    ApiError, Clip, ConsoleMessage, Cookie, CookieFilter, DeviceDescriptor, DialogInfo, DialogPolicy, ElementHandle, EventHandler, ExposedFunction, FileChooser, Frame, Geolocation, Har,
+    HarRecordOptions, HarReplayOptions, InitScriptId, JsArg, JsHandle, JsValue, LifecycleEvent, Locator, MediaEmulation,
+    MouseOptions, NavigationResult, NetworkRequest, NetworkResponse, Page, PageCloseOptions, PageError, PdfOptions,
+    Polling, RequestFailed, RequestFinished, RouteHandler, ScreenshotFormat, ScreenshotOptions, SubscriptionId,
+    Value, WaitUntil,
 };
 use log::debug;
-
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::time::Instant;
 
 // Represents a handle to a specific Chrome page/target
 #[derive(Debug)]
@@ -21,6 +55,113 @@ impl ChromePage {
     pub(crate) fn new(actor_addr: Addr<ChromePageActor>, page_id: String) -> Self {
         Self { actor_addr, page_id }
     }
+
+    async fn add_event_handler(&self, handler: PageEventHandler) -> Result<SubscriptionId, ApiError> {
+        debug!("ChromePage ({}) registering {:?}", self.page_id, handler);
+        self.actor_addr.send(AddPageEventHandler(handler))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error registering event handler: {}", mb_err)))
+    }
+
+    pub(crate) async fn layout_metrics(&self) -> Result<LayoutMetrics, ApiError> {
+        self.actor_addr
+            .send(GetLayoutMetrics)
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error getting layout metrics: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn page_cookies(&self, urls: Vec<String>) -> Result<Vec<Cookie>, ApiError> {
+        let cookies = self
+            .actor_addr
+            .send(GetCookies { urls })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error getting cookies: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)?;
+        Ok(cookies.into_iter().map(cookies::cookie_from_cdp).collect())
+    }
+
+    async fn delete_page_cookies(&self, filter: CookieFilter) -> Result<(), ApiError> {
+        self.actor_addr
+            .send(DeleteCookies(cookies::delete_params_from_filter(filter)))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error deleting cookies: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    // Copies an `IO` stream into `writer` until EOF; returns the number of bytes written.
+    async fn read_stream_into(
+        &self,
+        handle: &str,
+        writer: &mut (dyn AsyncWrite + Send + Unpin),
+    ) -> Result<u64, ApiError> {
+        let mut written = 0u64;
+        loop {
+            let chunk = self
+                .actor_addr
+                .send(ReadStream { handle: handle.to_string(), size: Some(pdf::PDF_READ_CHUNK_SIZE) })
+                .await
+                .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error reading stream: {}", mb_err)))?
+                .map_err(map_internal_to_api_error)?;
+            writer.write_all(&chunk.data).await?;
+            written += chunk.data.len() as u64;
+            if chunk.eof {
+                break;
+            }
+        }
+        writer.flush().await?;
+        Ok(written)
+    }
+
+    async fn frame_infos(&self) -> Result<Vec<FrameInfo>, ApiError> {
+        self.actor_addr
+            .send(GetFrames)
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error getting frames: {}", mb_err)))
+    }
+
+    // Calls a function in the main world of the page's main frame.
+    async fn call_page_function(
+        &self,
+        function_declaration: &str,
+        args: Vec<JsArg<'_>>,
+        return_by_value: bool,
+    ) -> Result<RemoteObject, ApiError> {
+        let args = js_handle::call_arguments(args).await?;
+        self.actor_addr
+            .send(CallFunctionOn {
+                session_id: None,
+                object_id: None,
+                function_declaration: function_declaration.to_string(),
+                args,
+                return_by_value,
+            })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error calling function: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    // Sets one emulation override through the page actor.
+    async fn set_emulation(&self, msg: SetEmulation) -> Result<(), ApiError> {
+        self.actor_addr
+            .send(msg)
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error setting emulation: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    // Sends one of the input messages (`MouseInput`, `KeyInput`, ...) to the page actor.
+    pub(crate) async fn send_input<M>(&self, msg: M) -> Result<(), ApiError>
+    where
+        M: Message<Result = Result<(), InternalError>> + Send + 'static,
+        ChromePageActor: Handler<M>,
+        <ChromePageActor as Actor>::Context: ToEnvelope<ChromePageActor, M>,
+    {
+        self.actor_addr.send(msg)
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error dispatching input: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
 }
 
 #[async_trait]
@@ -33,6 +174,27 @@ impl Page for ChromePage {
             .map_err(map_internal_to_api_error)
     }
 
+    async fn navigate_and_wait(
+        &self,
+        url: &str,
+        wait_until: WaitUntil,
+        timeout_ms: u64,
+    ) -> Result<NavigationResult, ApiError> {
+        debug!(
+            "ChromePage ({})::navigate_and_wait to URL: {} ({:?}, {} ms)",
+            self.page_id, url, wait_until, timeout_ms
+        );
+        self.actor_addr
+            .send(NavigateAndWait {
+                url: url.to_string(),
+                wait_until,
+                timeout: Duration::from_millis(timeout_ms),
+            })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error navigating: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
     async fn reload(&self) -> Result<(), ApiError> {
         debug!("ChromePage ({})::reload requested.", self.page_id);
         // Send Page.reload command
@@ -76,6 +238,17 @@ impl Page for ChromePage {
             .map_err(map_internal_to_api_error)
     }
 
+    async fn close_with_options(&self, options: PageCloseOptions) -> Result<(), ApiError> {
+        if !options.run_before_unload {
+            return self.close().await;
+        }
+        debug!("ChromePage ({})::close_with_options running beforeunload", self.page_id);
+        self.actor_addr.send(RequestClose)
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error closing page: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
     fn id(&self) -> String {
         self.page_id.clone()
     }
@@ -102,31 +275,121 @@ impl Page for ChromePage {
             .map_err(map_internal_to_api_error)
     }
 
+    async fn evaluate_value(&self, script: &str) -> Result<JsValue, ApiError> {
+        debug!("ChromePage ({})::evaluate_value", self.page_id);
+        let object = self.actor_addr.send(EvaluateHandle { script: script.to_string() })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error evaluating script: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)?;
+        if let Some(value) = js_handle::js_value_from_remote(&object) {
+            return Ok(value);
+        }
+        // Objects are serialized in a second call, which also turns Dates into strings.
+        let handle = ChromeJsHandle::new(self.actor_addr.clone(), None, object);
+        Ok(JsValue::Json(handle.json_value().await?))
+    }
+
     // --- Methods below are placeholders for Phase 2 ---
 
     async fn call_function(
         &self,
-        _function_declaration: &str,
-        _args: Vec<Value>,
+        function_declaration: &str,
+        args: Vec<JsArg<'_>>,
     ) -> Result<Value, ApiError> {
-        warn!("ChromePage::call_function not implemented yet.");
-        Err(ApiError::NotSupported("call_function".to_string()))
+        debug!("ChromePage ({})::call_function", self.page_id);
+        Ok(self.call_page_function(function_declaration, args, true).await?.value)
+    }
+
+    async fn call_function_handle(
+        &self,
+        function_declaration: &str,
+        args: Vec<JsArg<'_>>,
+    ) -> Result<Box<dyn JsHandle>, ApiError> {
+        debug!("ChromePage ({})::call_function_handle", self.page_id);
+        let object = self.call_page_function(function_declaration, args, false).await?;
+        Ok(Box::new(ChromeJsHandle::new(self.actor_addr.clone(), None, object)))
     }
 
-    async fn query_selector(&self, _selector: &str) -> Result<Option<ElementHandle>, ApiError> {
-        warn!("ChromePage::query_selector not implemented yet.");
-        Err(ApiError::NotSupported("query_selector".to_string()))
-        // Implementation: Send DOM.querySelector command, parse result (NodeId), create ElementHandle
+    async fn query_selector(&self, selector: &str) -> Result<Option<Box<dyn ElementHandle>>, ApiError> {
+        debug!("ChromePage ({})::query_selector {}", self.page_id, selector);
+        let selector_literal = serde_json::to_string(selector)
+            .map_err(|e| ApiError::InternalError(format!("Failed to encode selector: {}", e)))?;
+        let script = format!("document.querySelector({})", selector_literal);
+        let object = self.actor_addr.send(EvaluateHandle { script })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error querying selector: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)?;
+        let handle = ChromeElementHandle::from_remote_object(self.actor_addr.clone(), self.page_id.clone(), None, object).await?;
+        Ok(handle.map(|handle| Box::new(handle) as Box<dyn ElementHandle>))
+    }
+
+    fn locator(&self, selector: &str) -> Box<dyn Locator> {
+        Box::new(ChromeLocator::new(self.actor_addr.clone(), self.page_id.clone(), selector))
     }
 
     async fn wait_for_selector(
         &self,
-        _selector: &str,
-        _timeout_ms: u64,
-    ) -> Result<ElementHandle, ApiError> {
-        warn!("ChromePage::wait_for_selector not implemented yet.");
-        Err(ApiError::NotSupported("wait_for_selector".to_string()))
-        // Implementation: Combine polling evaluate_script or DOM mutation observers
+        selector: &str,
+        timeout_ms: u64,
+    ) -> Result<Box<dyn ElementHandle>, ApiError> {
+        self.locator(selector).first().with_timeout(timeout_ms).element_handle().await
+    }
+
+    async fn wait_for_function(
+        &self,
+        function_declaration: &str,
+        args: Vec<JsArg<'_>>,
+        polling: Polling,
+        timeout_ms: u64,
+    ) -> Result<Value, ApiError> {
+        debug!("ChromePage ({})::wait_for_function", self.page_id);
+        let script = wait::wait_script(function_declaration);
+        let (polling, interval_ms) = wait::polling_arguments(polling);
+        let args = js_handle::call_arguments(args).await?;
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+        let mut time_origin = None;
+        let mut last_observed = None;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(ApiError::Timeout { last_observed });
+            }
+            let slice_ms = (remaining.as_millis() as u64).min(wait::WAIT_SLICE_MS);
+            let arguments = [json!(polling), json!(interval_ms), json!(slice_ms)]
+                .into_iter()
+                .map(|value| CallArgument { value: Some(value), object_id: None })
+                .chain(args.iter().cloned())
+                .collect();
+            let call = self.actor_addr.send(CallFunctionOn {
+                session_id: None,
+                object_id: None,
+                function_declaration: script.clone(),
+                args: arguments,
+                return_by_value: true,
+            });
+            let result = match tokio::time::timeout(Duration::from_millis(slice_ms) + wait::WAIT_SLICE_GRACE, call).await {
+                Err(_) => return Err(ApiError::Timeout { last_observed }),
+                // The page actor stops when its target is destroyed.
+                Ok(Err(_)) => return Err(ApiError::TargetDetached),
+                Ok(Ok(Ok(result))) => result,
+                Ok(Ok(Err(e))) => match wait::slice_error(map_internal_to_api_error(e), time_origin.is_some()) {
+                    Some(err) => return Err(err),
+                    None => {
+                        tokio::time::sleep(wait::WAIT_RETRY_DELAY).await;
+                        continue;
+                    }
+                },
+            };
+            let slice = wait::parse_wait_slice(result.value)?;
+            if time_origin.is_some_and(|origin| origin != slice.time_origin) {
+                return Err(ApiError::WaitCancelled("the page navigated".to_string()));
+            }
+            if slice.done {
+                return Ok(slice.value);
+            }
+            time_origin = Some(slice.time_origin);
+            last_observed = Some(slice.value.to_string());
+        }
     }
 
     async fn url(&self) -> Result<String, ApiError> {
@@ -157,11 +420,487 @@ impl Page for ChromePage {
 
     async fn take_screenshot(
         &self,
-        _format: ScreenshotFormat,
-        _options: ScreenshotOptions,
+        format: ScreenshotFormat,
+        mut options: ScreenshotOptions,
     ) -> Result<Vec<u8>, ApiError> {
-        warn!("ChromePage::take_screenshot not implemented yet.");
-        Err(ApiError::NotSupported("take_screenshot".to_string()))
-        // Implementation: Send Page.captureScreenshot command
+        debug!("ChromePage ({})::take_screenshot {:?} {:?}", self.page_id, format, options);
+        if options.clip.is_none() && options.full_page == Some(true) {
+            let metrics = self.layout_metrics().await?;
+            options.clip = Some(Clip {
+                x: 0.0,
+                y: 0.0,
+                width: metrics.css_content_size.width,
+                height: metrics.css_content_size.height,
+            });
+            options.capture_beyond_viewport.get_or_insert(true);
+        }
+        let params = screenshot::screenshot_params(format, &options)?;
+        self.actor_addr
+            .send(CaptureScreenshot(params))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error taking screenshot: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn pdf(&self, options: PdfOptions) -> Result<Vec<u8>, ApiError> {
+        let mut buffer = Vec::new();
+        self.pdf_to_writer(options, &mut buffer).await?;
+        Ok(buffer)
+    }
+
+    async fn pdf_to_writer(
+        &self,
+        options: PdfOptions,
+        writer: &mut (dyn AsyncWrite + Send + Unpin),
+    ) -> Result<u64, ApiError> {
+        debug!("ChromePage ({})::pdf_to_writer {:?}", self.page_id, options);
+        let params = pdf::print_to_pdf_params(options)?;
+        let handle = self
+            .actor_addr
+            .send(PrintToPdf(params))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error printing to PDF: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)?;
+        let result = self.read_stream_into(&handle, writer).await;
+        // Release the stream even if reading or writing failed.
+        let closed = self
+            .actor_addr
+            .send(CloseStream { handle })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error closing stream: {}", mb_err)))?
+            .map_err(map_internal_to_api_error);
+        let written = result?;
+        closed?;
+        Ok(written)
+    }
+
+    async fn main_frame(&self) -> Result<Box<dyn Frame>, ApiError> {
+        let info = self
+            .frame_infos()
+            .await?
+            .into_iter()
+            .find(|frame| frame.id == self.page_id)
+            .unwrap_or_else(|| FrameInfo {
+                // The frame tree is not loaded yet; the main frame has the target's ID.
+                id: self.page_id.clone(),
+                parent_id: None,
+                name: String::new(),
+                url: String::new(),
+                session_id: None,
+            });
+        Ok(Box::new(ChromeFrame::new(self.actor_addr.clone(), self.page_id.clone(), info)))
+    }
+
+    async fn frames(&self) -> Result<Vec<Box<dyn Frame>>, ApiError> {
+        debug!("ChromePage ({})::frames", self.page_id);
+        Ok(self
+            .frame_infos()
+            .await?
+            .into_iter()
+            .map(|info| Box::new(ChromeFrame::new(self.actor_addr.clone(), self.page_id.clone(), info)) as Box<dyn Frame>)
+            .collect())
+    }
+
+    async fn add_init_script(&self, source: &str) -> Result<InitScriptId, ApiError> {
+        debug!("ChromePage ({})::add_init_script", self.page_id);
+        self.actor_addr
+            .send(AddInitScript { source: source.to_string() })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error adding init script: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn remove_init_script(&self, id: InitScriptId) -> Result<(), ApiError> {
+        let removed = self
+            .actor_addr
+            .send(RemoveInitScript { identifier: id.clone() })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error removing init script: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)?;
+        if removed {
+            Ok(())
+        } else {
+            Err(ApiError::InvalidParameters(format!("Unknown init script: {}", id)))
+        }
+    }
+
+    async fn expose_function(&self, name: &str, function: ExposedFunction) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::expose_function {}", self.page_id, name);
+        let binding = Binding { name: name.to_string(), function };
+        self.actor_addr
+            .send(ExposeFunction(binding))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error exposing function: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn cookies(&self, urls: &[String]) -> Result<Vec<Cookie>, ApiError> {
+        debug!("ChromePage ({})::cookies for {:?}", self.page_id, urls);
+        self.page_cookies(urls.to_vec()).await
+    }
+
+    async fn set_cookies(&self, cookies: Vec<Cookie>) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::set_cookies ({} cookies)", self.page_id, cookies.len());
+        let needs_url = |cookie: &Cookie| cookie.url.is_none() && cookie.domain.is_none();
+        let page_url = match cookies.iter().any(needs_url) {
+            true => Some(self.url().await?),
+            false => None,
+        };
+        let params = cookies
+            .into_iter()
+            .map(|mut cookie| {
+                if needs_url(&cookie) {
+                    cookie.url = page_url.clone();
+                }
+                cookies::cookie_to_cdp(cookie)
+            })
+            .collect();
+        self.actor_addr
+            .send(SetCookies(params))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error setting cookies: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn delete_cookies(&self, mut filter: CookieFilter) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::delete_cookies {:?}", self.page_id, filter);
+        if filter.url.is_none() && filter.domain.is_none() {
+            filter.url = Some(self.url().await?);
+        }
+        self.delete_page_cookies(filter).await
+    }
+
+    async fn clear_cookies(&self) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::clear_cookies", self.page_id);
+        for cookie in self.page_cookies(Vec::new()).await? {
+            self.delete_page_cookies(CookieFilter {
+                name: cookie.name,
+                url: None,
+                domain: cookie.domain,
+                path: cookie.path,
+            })
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn on_console_message(
+        &self,
+        handler: EventHandler<ConsoleMessage>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(PageEventHandler::Console(handler)).await
+    }
+
+    async fn on_lifecycle_event(
+        &self,
+        handler: EventHandler<LifecycleEvent>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(PageEventHandler::Lifecycle(handler)).await
+    }
+
+    async fn on_page_error(
+        &self,
+        handler: EventHandler<PageError>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(PageEventHandler::PageError(handler)).await
+    }
+
+    async fn on_dialog(&self, handler: EventHandler<DialogInfo>) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(PageEventHandler::Dialog(handler)).await
+    }
+
+    async fn on_load(
+        &self,
+        handler: Box<dyn Fn() + Send + Sync + 'static>,
+    ) -> Result<SubscriptionId, ApiError> {
+        // In CDP the main frame's ID is the target ID, which is our page ID.
+        let main_frame_id = self.page_id.clone();
+        self.on_lifecycle_event(Box::new(move |event: LifecycleEvent| {
+            if event.name == "load" && event.frame_id == main_frame_id {
+                handler();
+            }
+        }))
+        .await
+    }
+
+    async fn on_request(
+        &self,
+        handler: EventHandler<NetworkRequest>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(PageEventHandler::Request(handler)).await
+    }
+
+    async fn on_response(
+        &self,
+        handler: EventHandler<NetworkResponse>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(PageEventHandler::Response(handler)).await
+    }
+
+    async fn on_request_finished(
+        &self,
+        handler: EventHandler<RequestFinished>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(PageEventHandler::RequestFinished(handler)).await
+    }
+
+    async fn on_request_failed(
+        &self,
+        handler: EventHandler<RequestFailed>,
+    ) -> Result<SubscriptionId, ApiError> {
+        self.add_event_handler(PageEventHandler::RequestFailed(handler)).await
+    }
+
+    async fn on_file_chooser(
+        &self,
+        handler: EventHandler<FileChooser>,
+    ) -> Result<SubscriptionId, ApiError> {
+        let id = self.add_event_handler(PageEventHandler::FileChooser(handler)).await?;
+        self.actor_addr
+            .send(SyncFileChooserInterception)
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error intercepting file choosers: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)?;
+        Ok(id)
+    }
+
+    async fn unsubscribe(&self, id: SubscriptionId) -> Result<(), ApiError> {
+        let removed = self.actor_addr.send(RemovePageEventHandler(id))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error unsubscribing: {}", mb_err)))?;
+        if removed {
+            Ok(())
+        } else {
+            Err(ApiError::InvalidParameters(format!("Unknown subscription ID: {}", id)))
+        }
+    }
+
+    async fn response_body(&self, request_id: &str) -> Result<Vec<u8>, ApiError> {
+        debug!("ChromePage ({})::response_body {}", self.page_id, request_id);
+        self.actor_addr
+            .send(GetResponseBody { request_id: request_id.to_string() })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error getting response body: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn route(&self, pattern: &str, handler: RouteHandler) -> Result<SubscriptionId, ApiError> {
+        debug!("ChromePage ({})::route {}", self.page_id, pattern);
+        let route = Route { pattern: pattern.to_string(), handler };
+        self.actor_addr
+            .send(AddRoute(route))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error adding route: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn unroute(&self, id: SubscriptionId) -> Result<(), ApiError> {
+        let removed = self
+            .actor_addr
+            .send(RemoveRoute(id))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error removing route: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)?;
+        if removed {
+            Ok(())
+        } else {
+            Err(ApiError::InvalidParameters(format!("Unknown route ID: {}", id)))
+        }
+    }
+
+    async fn start_har_recording(&self, options: HarRecordOptions) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::start_har_recording", self.page_id);
+        let started = self
+            .actor_addr
+            .send(StartHarRecording(options))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error starting HAR recording: {}", mb_err)))?;
+        if started {
+            Ok(())
+        } else {
+            Err(ApiError::InvalidParameters("A HAR recording is already in progress".to_string()))
+        }
+    }
+
+    async fn stop_har_recording(&self) -> Result<Har, ApiError> {
+        debug!("ChromePage ({})::stop_har_recording", self.page_id);
+        let mut recorder = self
+            .actor_addr
+            .send(StopHarRecording)
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error stopping HAR recording: {}", mb_err)))?
+            .ok_or_else(|| ApiError::InvalidParameters("No HAR recording in progress".to_string()))?;
+        // Bodies that were not read while recording
+        for request_id in recorder.missing_bodies() {
+            match self.response_body(&request_id).await {
+                Ok(body) => recorder.set_body(&request_id, body),
+                Err(e) => debug!("ChromePage ({}): no body for request {}: {}", self.page_id, request_id, e),
+            }
+        }
+        Ok(recorder.into_har())
+    }
+
+    async fn route_from_har(
+        &self,
+        har: Har,
+        options: HarReplayOptions,
+    ) -> Result<SubscriptionId, ApiError> {
+        debug!("ChromePage ({})::route_from_har", self.page_id);
+        let pattern = options.url_pattern.clone().unwrap_or_else(|| "*".to_string());
+        self.route(&pattern, har::replay_handler(har, options)).await
+    }
+
+    async fn emulate(&self, device: DeviceDescriptor) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::emulate {}", self.page_id, device.name);
+        let overrides = emulation::device_overrides(device)?;
+        self.actor_addr
+            .send(EmulateDevice(overrides))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error emulating device: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn set_timezone(&self, timezone_id: Option<&str>) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::set_timezone {:?}", self.page_id, timezone_id);
+        let params = SetTimezoneOverrideParams { timezone_id: timezone_id.unwrap_or_default().to_string() };
+        self.set_emulation(SetEmulation::Timezone(params)).await
+    }
+
+    async fn set_locale(&self, locale: Option<&str>) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::set_locale {:?}", self.page_id, locale);
+        let params = SetLocaleOverrideParams { locale: locale.map(str::to_string) };
+        self.set_emulation(SetEmulation::Locale(params)).await
+    }
+
+    async fn set_geolocation(&self, geolocation: Option<Geolocation>) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::set_geolocation {:?}", self.page_id, geolocation);
+        let params = geolocation.map(emulation::geolocation_params).transpose()?;
+        self.set_emulation(SetEmulation::Geolocation(params)).await
+    }
+
+    async fn emulate_media(&self, media: MediaEmulation) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::emulate_media {:?}", self.page_id, media);
+        self.set_emulation(SetEmulation::Media(emulation::emulated_media_params(media))).await
+    }
+
+    async fn set_dialog_policy(&self, policy: DialogPolicy) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::set_dialog_policy {:?}", self.page_id, policy);
+        self.actor_addr
+            .send(SetDialogPolicy(policy))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error setting dialog policy: {}", mb_err)))
+    }
+
+    async fn accept_dialog(&self, prompt_text: Option<&str>) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::accept_dialog", self.page_id);
+        let params = HandleJavaScriptDialogParams { accept: true, prompt_text: prompt_text.map(str::to_string) };
+        self.actor_addr
+            .send(HandleDialog(params))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error accepting dialog: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn dismiss_dialog(&self) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::dismiss_dialog", self.page_id);
+        let params = HandleJavaScriptDialogParams { accept: false, prompt_text: None };
+        self.actor_addr
+            .send(HandleDialog(params))
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error dismissing dialog: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn set_file_chooser_files(&self, chooser: &FileChooser, files: &[PathBuf]) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::set_file_chooser_files {} ({} files)", self.page_id, chooser.id, files.len());
+        let files = upload::input_file_paths(files, chooser.multiple)?;
+        self.actor_addr
+            .send(SetFileChooserFiles { id: chooser.id, files })
+            .await
+            .map_err(|mb_err| ApiError::InternalError(format!("Mailbox error setting chooser files: {}", mb_err)))?
+            .map_err(map_internal_to_api_error)
+    }
+
+    async fn click(&self, selector: &str) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::click on {}", self.page_id, selector);
+        let element = self.query_selector(selector).await?.ok_or_else(|| {
+            ApiError::InvalidParameters(format!("No element matches selector: {}", selector))
+        })?;
+        let result = element.click().await;
+        element.dispose().await?;
+        result
+    }
+
+    async fn type_text(&self, selector: &str, text: &str) -> Result<(), ApiError> {
+        debug!("ChromePage ({})::type_text into {}", self.page_id, selector);
+        let element = self.query_selector(selector).await?.ok_or_else(|| {
+            ApiError::InvalidParameters(format!("No element matches selector: {}", selector))
+        })?;
+        let result = element.type_text(text).await;
+        element.dispose().await?;
+        result
+    }
+
+    async fn mouse_move(&self, x: f64, y: f64) -> Result<(), ApiError> {
+        self.send_input(MouseInput::Move { x, y }).await
+    }
+
+    async fn mouse_down(&self, x: f64, y: f64, options: MouseOptions) -> Result<(), ApiError> {
+        self.send_input(MouseInput::Down { x, y, options }).await
+    }
+
+    async fn mouse_up(&self, x: f64, y: f64, options: MouseOptions) -> Result<(), ApiError> {
+        self.send_input(MouseInput::Up { x, y, options }).await
+    }
+
+    async fn mouse_click(&self, x: f64, y: f64, options: MouseOptions) -> Result<(), ApiError> {
+        self.mouse_move(x, y).await?;
+        self.mouse_down(x, y, options).await?;
+        self.mouse_up(x, y, options).await
+    }
+
+    async fn mouse_wheel(&self, x: f64, y: f64, delta_x: f64, delta_y: f64) -> Result<(), ApiError> {
+        self.send_input(MouseInput::Wheel { x, y, delta_x, delta_y }).await
+    }
+
+    async fn keyboard_down(&self, key: &str) -> Result<(), ApiError> {
+        self.send_input(KeyInput::Down(key.to_string())).await
+    }
+
+    async fn keyboard_up(&self, key: &str) -> Result<(), ApiError> {
+        self.send_input(KeyInput::Up(key.to_string())).await
+    }
+
+    async fn keyboard_press(&self, key: &str) -> Result<(), ApiError> {
+        let keys = input::split_key_combination(key);
+        for key in &keys {
+            self.keyboard_down(key).await?;
+        }
+        for key in keys.iter().rev() {
+            self.keyboard_up(key).await?;
+        }
+        Ok(())
+    }
+
+    async fn keyboard_type(&self, text: &str) -> Result<(), ApiError> {
+        for ch in text.chars() {
+            let key = match ch {
+                '\n' | '\r' => "Enter".to_string(),
+                '\t' => "Tab".to_string(),
+                _ => ch.to_string(),
+            };
+            if input::describe_key(&key, false).is_some() {
+                self.keyboard_down(&key).await?;
+                self.keyboard_up(&key).await?;
+            } else {
+                // Not on the US layout (e.g. "é", emoji): insert as composed text.
+                self.send_input(InsertText(key)).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn touch_tap(&self, x: f64, y: f64) -> Result<(), ApiError> {
+        self.send_input(TouchTap { x, y }).await
     }
 }
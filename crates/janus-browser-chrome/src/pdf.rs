@@ -0,0 +1,50 @@
+//! Translation of L1 PDF options into `Page.printToPDF` parameters.
+
+use crate::protocol::PrintToPdfParams;
+use janus_interfaces::{ApiError, PdfOptions};
+
+/// Bytes requested per `IO.read` when streaming a PDF.
+pub(crate) const PDF_READ_CHUNK_SIZE: i64 = 1 << 20;
+
+/// Builds the print parameters, always requesting the result as an `IO` stream.
+pub(crate) fn print_to_pdf_params(options: PdfOptions) -> Result<PrintToPdfParams, ApiError> {
+    if let Some(scale) = options.scale
+        && !(0.1..=2.0).contains(&scale)
+    {
+        return Err(ApiError::InvalidParameters(format!(
+            "scale must be between 0.1 and 2.0, got {}",
+            scale
+        )));
+    }
+    let lengths = [
+        ("paper_width", options.paper_width),
+        ("paper_height", options.paper_height),
+        ("margin_top", options.margin_top),
+        ("margin_bottom", options.margin_bottom),
+        ("margin_left", options.margin_left),
+        ("margin_right", options.margin_right),
+    ];
+    if let Some((name, value)) = lengths
+        .iter()
+        .find_map(|(name, value)| value.filter(|value| *value < 0.0).map(|value| (name, value)))
+    {
+        return Err(ApiError::InvalidParameters(format!("{} must not be negative, got {}", name, value)));
+    }
+    Ok(PrintToPdfParams {
+        landscape: options.landscape,
+        display_header_footer: options.display_header_footer,
+        print_background: options.print_background,
+        scale: options.scale,
+        paper_width: options.paper_width,
+        paper_height: options.paper_height,
+        margin_top: options.margin_top,
+        margin_bottom: options.margin_bottom,
+        margin_left: options.margin_left,
+        margin_right: options.margin_right,
+        page_ranges: options.page_ranges,
+        header_template: options.header_template,
+        footer_template: options.footer_template,
+        prefer_css_page_size: options.prefer_css_page_size,
+        transfer_mode: Some("ReturnAsStream"),
+    })
+}
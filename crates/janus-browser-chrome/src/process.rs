@@ -0,0 +1,352 @@
+//! Launching and owning a local Chrome / Chromium process.
+//!
+//! The browser is started with `--remote-debugging-port=0` so Chrome picks a free
+//! port itself. The resulting WebSocket endpoint is discovered either from the
+//! `DevToolsActivePort` file written into the profile directory, or from the
+//! "DevTools listening on ..." line Chrome prints to stderr, whichever comes first.
+
+use janus_core::config::BrowserLaunchConfig;
+use janus_interfaces::ApiError;
+use log::{debug, info, trace, warn};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::{Child, Command};
+use tokio::sync::oneshot;
+
+/// Name of the file Chrome writes into the user data dir once the debugging port is open.
+const DEVTOOLS_ACTIVE_PORT_FILE: &str = "DevToolsActivePort";
+
+/// Prefix of the stderr line announcing the browser WebSocket endpoint.
+const DEVTOOLS_LISTENING_PREFIX: &str = "DevTools listening on ";
+
+/// How often the `DevToolsActivePort` file is polled while waiting for startup.
+const ACTIVE_PORT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Executable names searched on `PATH` when no `executable_path` is configured.
+const DEFAULT_EXECUTABLE_NAMES: &[&str] = &[
+    "google-chrome",
+    "google-chrome-stable",
+    "chromium",
+    "chromium-browser",
+    "chrome",
+];
+
+/// Well-known install locations checked after `PATH`.
+const DEFAULT_EXECUTABLE_PATHS: &[&str] = &[
+    "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
+    "/Applications/Chromium.app/Contents/MacOS/Chromium",
+    "C:\\Program Files\\Google\\Chrome\\Application\\chrome.exe",
+    "C:\\Program Files (x86)\\Google\\Chrome\\Application\\chrome.exe",
+];
+
+/// Flags passed to every launched instance, before any user-provided `args`.
+const DEFAULT_ARGS: &[&str] = &[
+    "--no-first-run",
+    "--no-default-browser-check",
+    "--disable-background-networking",
+    "--disable-sync",
+    "--disable-popup-blocking",
+];
+
+/// A Chrome process started and owned by Janus.
+///
+/// The process is killed when [`ChromeProcess::shutdown`] is called or, as a
+/// fallback, when the value is dropped. A profile directory created by Janus
+/// (because no `user_data_dir` was configured) is removed at the same time.
+#[derive(Debug)]
+pub struct ChromeProcess {
+    child: Option<Child>,
+    ws_url: String,
+    user_data_dir: PathBuf,
+    // True if the profile dir was created by us and must be removed on shutdown.
+    owns_user_data_dir: bool,
+}
+
+impl ChromeProcess {
+    /// Spawns a browser according to `config` and waits (up to `startup_timeout`)
+    /// for it to report its DevTools WebSocket endpoint.
+    pub async fn launch(
+        config: &BrowserLaunchConfig,
+        startup_timeout: Duration,
+    ) -> Result<Self, ApiError> {
+        let executable = match &config.executable_path {
+            Some(path) => path.clone(),
+            None => find_default_executable().ok_or_else(|| {
+                ApiError::LaunchError(
+                    "No Chrome executable found; set `executable_path` in the launch config"
+                        .to_string(),
+                )
+            })?,
+        };
+
+        let (user_data_dir, owns_user_data_dir) = match &config.user_data_dir {
+            Some(dir) => (dir.clone(), false),
+            None => (create_temp_profile_dir()?, true),
+        };
+
+        // A stale file from a previous run would point us at a dead port.
+        let active_port_file = user_data_dir.join(DEVTOOLS_ACTIVE_PORT_FILE);
+        if active_port_file.exists() {
+            let _ = std::fs::remove_file(&active_port_file);
+        }
+
+        let args = build_args(config, &user_data_dir);
+        info!("Launching browser: {} {:?}", executable.display(), args);
+
+        let mut command = Command::new(&executable);
+        command
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        if let Some(env_vars) = &config.env_vars {
+            command.envs(env_vars);
+        }
+
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                if owns_user_data_dir {
+                    let _ = std::fs::remove_dir_all(&user_data_dir);
+                }
+                return Err(ApiError::LaunchError(format!(
+                    "Failed to spawn {}: {}",
+                    executable.display(),
+                    e
+                )));
+            }
+        };
+
+        // Keep draining stderr for the lifetime of the process so Chrome never blocks
+        // on a full pipe; the first "DevTools listening on" line is reported back.
+        let (stderr_tx, stderr_rx) = oneshot::channel::<String>();
+        if let Some(stderr) = child.stderr.take() {
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(stderr).lines();
+                let mut stderr_tx = Some(stderr_tx);
+                while let Ok(Some(line)) = lines.next_line().await {
+                    trace!("[chrome stderr] {}", line);
+                    if let Some(url) = parse_devtools_listening_line(&line)
+                        && let Some(tx) = stderr_tx.take()
+                    {
+                        let _ = tx.send(url);
+                    }
+                }
+            });
+        }
+
+        let mut process = Self {
+            child: Some(child),
+            ws_url: String::new(),
+            user_data_dir,
+            owns_user_data_dir,
+        };
+
+        match tokio::time::timeout(
+            startup_timeout,
+            process.wait_for_endpoint(&active_port_file, stderr_rx),
+        )
+        .await
+        {
+            Ok(Ok(ws_url)) => {
+                info!("Browser DevTools endpoint: {}", ws_url);
+                process.ws_url = ws_url;
+                Ok(process)
+            }
+            Ok(Err(e)) => {
+                process.shutdown().await;
+                Err(e)
+            }
+            Err(_) => {
+                process.shutdown().await;
+                Err(ApiError::LaunchError(format!(
+                    "Browser did not report a DevTools endpoint within {:?}",
+                    startup_timeout
+                )))
+            }
+        }
+    }
+
+    /// The browser-level DevTools WebSocket URL (`ws://127.0.0.1:<port>/devtools/browser/<id>`).
+    pub fn ws_url(&self) -> &str {
+        &self.ws_url
+    }
+
+    /// The profile directory the browser was started with.
+    pub fn user_data_dir(&self) -> &Path {
+        &self.user_data_dir
+    }
+
+    /// OS process ID, if the process is still owned.
+    pub fn pid(&self) -> Option<u32> {
+        self.child.as_ref().and_then(|child| child.id())
+    }
+
+    /// Kills the browser, waits for it to exit and removes a temporary profile.
+    /// Safe to call more than once.
+    pub async fn shutdown(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            match child.try_wait() {
+                Ok(Some(status)) => debug!("Browser process already exited: {}", status),
+                _ => {
+                    if let Err(e) = child.start_kill() {
+                        warn!("Failed to kill browser process: {}", e);
+                    }
+                    match child.wait().await {
+                        Ok(status) => info!("Browser process exited: {}", status),
+                        Err(e) => warn!("Failed to reap browser process: {}", e),
+                    }
+                }
+            }
+        }
+        self.remove_owned_profile();
+    }
+
+    async fn wait_for_endpoint(
+        &mut self,
+        active_port_file: &Path,
+        mut stderr_rx: oneshot::Receiver<String>,
+    ) -> Result<String, ApiError> {
+        let mut stderr_open = true;
+        loop {
+            if let Some(child) = self.child.as_mut()
+                && let Ok(Some(status)) = child.try_wait()
+            {
+                return Err(ApiError::LaunchError(format!(
+                    "Browser exited during startup: {}",
+                    status
+                )));
+            }
+
+            if let Ok(contents) = tokio::fs::read_to_string(active_port_file).await
+                && let Some(url) = parse_devtools_active_port(&contents, "127.0.0.1")
+            {
+                debug!("Endpoint found via {}", active_port_file.display());
+                return Ok(url);
+            }
+
+            if stderr_open {
+                tokio::select! {
+                    url = &mut stderr_rx => match url {
+                        Ok(url) => {
+                            debug!("Endpoint found via stderr");
+                            return Ok(url);
+                        }
+                        // stderr closed without the line; rely on the port file only.
+                        Err(_) => stderr_open = false,
+                    },
+                    _ = tokio::time::sleep(ACTIVE_PORT_POLL_INTERVAL) => {}
+                }
+            } else {
+                tokio::time::sleep(ACTIVE_PORT_POLL_INTERVAL).await;
+            }
+        }
+    }
+
+    fn remove_owned_profile(&mut self) {
+        if self.owns_user_data_dir {
+            self.owns_user_data_dir = false;
+            if let Err(e) = std::fs::remove_dir_all(&self.user_data_dir) {
+                warn!(
+                    "Failed to remove temporary profile {}: {}",
+                    self.user_data_dir.display(),
+                    e
+                );
+            }
+        }
+    }
+}
+
+impl Drop for ChromeProcess {
+    fn drop(&mut self) {
+        // Best effort only: prefer `shutdown()`, which also reaps the process.
+        if let Some(child) = self.child.as_mut() {
+            let _ = child.start_kill();
+        }
+        self.remove_owned_profile();
+    }
+}
+
+/// Builds the command line for the browser from the launch config.
+pub(crate) fn build_args(config: &BrowserLaunchConfig, user_data_dir: &Path) -> Vec<String> {
+    let mut args: Vec<String> = DEFAULT_ARGS.iter().map(|s| s.to_string()).collect();
+    args.push(format!(
+        "--remote-debugging-port={}",
+        config.remote_debugging_port.unwrap_or(0)
+    ));
+    if let Some(address) = &config.remote_debugging_address {
+        args.push(format!("--remote-debugging-address={}", address));
+    }
+    args.push(format!("--user-data-dir={}", user_data_dir.display()));
+    if config.headless.unwrap_or(true) {
+        args.push("--headless=new".to_string());
+    }
+    if let Some(extra) = &config.args {
+        args.extend(extra.iter().cloned());
+    }
+    args.push("about:blank".to_string());
+    args
+}
+
+/// Extracts the WebSocket URL from a "DevTools listening on ws://..." stderr line.
+pub(crate) fn parse_devtools_listening_line(line: &str) -> Option<String> {
+    let url = line.trim().strip_prefix(DEVTOOLS_LISTENING_PREFIX)?.trim();
+    if url.starts_with("ws://") || url.starts_with("wss://") {
+        Some(url.to_string())
+    } else {
+        None
+    }
+}
+
+/// Parses the `DevToolsActivePort` file: the port on the first line and the
+/// browser target path on the second.
+pub(crate) fn parse_devtools_active_port(contents: &str, host: &str) -> Option<String> {
+    let mut lines = contents.lines();
+    let port: u16 = lines.next()?.trim().parse().ok()?;
+    let path = lines.next()?.trim();
+    if port == 0 || !path.starts_with('/') {
+        return None;
+    }
+    Some(format!("ws://{}:{}{}", host, port, path))
+}
+
+fn find_default_executable() -> Option<PathBuf> {
+    if let Some(path_var) = std::env::var_os("PATH") {
+        for dir in std::env::split_paths(&path_var) {
+            for name in DEFAULT_EXECUTABLE_NAMES {
+                let candidate = dir.join(name);
+                if candidate.is_file() {
+                    return Some(candidate);
+                }
+            }
+        }
+    }
+    DEFAULT_EXECUTABLE_PATHS
+        .iter()
+        .map(PathBuf::from)
+        .find(|candidate| candidate.is_file())
+}
+
+fn create_temp_profile_dir() -> Result<PathBuf, ApiError> {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let dir = std::env::temp_dir().join(format!(
+        "janus-profile-{}-{}",
+        std::process::id(),
+        nanos
+    ));
+    std::fs::create_dir_all(&dir).map_err(|e| {
+        ApiError::LaunchError(format!(
+            "Failed to create temporary profile {}: {}",
+            dir.display(),
+            e
+        ))
+    })?;
+    debug!("Created temporary profile dir {}", dir.display());
+    Ok(dir)
+}
@@ -3,14 +3,83 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 
 // --- Commands ---
 
 // Example: Target.createTarget command parameters
 #[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct CreateTargetParams {
     pub url: String,
-    // Add other options like width, height, browserContextId etc. later
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browser_context_id: Option<String>, // Default context if unset
+    // Add other options like width, height etc. later
+}
+
+// Target.createBrowserContext command parameters
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBrowserContextParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_server: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_bypass_list: Option<String>,
+}
+
+// Target.disposeBrowserContext command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DisposeBrowserContextParams {
+    pub browser_context_id: String,
+}
+
+// Browser.grantPermissions command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GrantPermissionsParams {
+    pub permissions: Vec<&'static str>, // Browser.PermissionType
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>, // All origins if unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browser_context_id: Option<String>,
+}
+
+// Browser.setPermission command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SetPermissionParams {
+    pub permission: PermissionDescriptor,
+    pub setting: &'static str, // "granted", "denied" or "prompt"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>, // All origins if unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browser_context_id: Option<String>, // Default context if unset
+}
+
+#[derive(Serialize, Debug)]
+pub struct PermissionDescriptor {
+    pub name: &'static str,
+}
+
+// Browser.setDownloadBehavior command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDownloadBehaviorParams {
+    pub behavior: &'static str, // "deny", "allow", "allowAndName" or "default"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browser_context_id: Option<String>, // Default context if unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_path: Option<String>, // Required for "allow" and "allowAndName"
+    pub events_enabled: bool,
+}
+
+// Browser.resetPermissions / Storage.getCookies / Storage.clearCookies command parameters
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserContextParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browser_context_id: Option<String>, // Default context if unset
 }
 
 // Example: Target.attachToTarget command parameters
@@ -21,6 +90,15 @@ pub struct AttachToTargetParams {
     pub flatten: Option<bool>, // Recommended for easier session management
 }
 
+// Target.setAutoAttach command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SetAutoAttachParams {
+    pub auto_attach: bool,
+    pub wait_for_debugger_on_start: bool,
+    pub flatten: bool, // Child sessions share the connection, like attachToTarget
+}
+
 // Example: Page.navigate command parameters
 #[derive(Serialize, Debug)]
 pub struct NavigateParams<'a> {
@@ -42,12 +120,374 @@ pub struct EvaluateParams<'a> {
                                      // Add timeout etc. later
 }
 
+// Runtime.callFunctionOn command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFunctionOnParams<'a> {
+    pub function_declaration: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<&'a str>, // Bound as `this`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_context_id: Option<i64>, // Where to call, when there is no object
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<CallArgument>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_by_value: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub await_promise: Option<bool>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CallArgument {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<String>,
+}
+
+// Runtime.releaseObject / DOM.describeNode command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectIdParams<'a> {
+    pub object_id: &'a str,
+}
+
+// Page.addScriptToEvaluateOnNewDocument command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AddScriptToEvaluateOnNewDocumentParams<'a> {
+    pub source: &'a str,
+}
+
+// Page.removeScriptToEvaluateOnNewDocument command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveScriptToEvaluateOnNewDocumentParams<'a> {
+    pub identifier: &'a str,
+}
+
+// Runtime.addBinding command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AddBindingParams<'a> {
+    pub name: &'a str,
+}
+
+// Runtime.getProperties command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPropertiesParams<'a> {
+    pub object_id: &'a str,
+    pub own_properties: bool,
+}
+
 // Example: Target.setDiscoverTargets command parameters
 #[derive(Serialize, Debug)]
 pub struct SetDiscoverTargetsParams {
     pub discover: bool,
 }
 
+// Input.dispatchMouseEvent command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DispatchMouseEventParams {
+    #[serde(rename = "type")]
+    pub type_: &'static str, // "mousePressed", "mouseReleased", "mouseMoved" or "mouseWheel"
+    pub x: f64,
+    pub y: f64,
+    pub modifiers: u32, // Bit field: Alt=1, Ctrl=2, Meta=4, Shift=8
+    pub button: &'static str, // "none", "left", "middle", "right", "back", "forward"
+    pub buttons: u32,         // Bit field of currently pressed buttons
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub click_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta_x: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta_y: Option<f64>,
+}
+
+// Input.dispatchKeyEvent command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DispatchKeyEventParams {
+    #[serde(rename = "type")]
+    pub type_: &'static str, // "keyDown", "rawKeyDown" or "keyUp"
+    pub modifiers: u32,
+    pub key: String,
+    pub code: String,
+    pub windows_virtual_key_code: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmodified_text: Option<String>,
+    pub location: u32,
+    pub auto_repeat: bool,
+}
+
+// Input.dispatchTouchEvent command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DispatchTouchEventParams {
+    #[serde(rename = "type")]
+    pub type_: &'static str, // "touchStart", "touchEnd", "touchMove" or "touchCancel"
+    pub touch_points: Vec<TouchPoint>,
+    pub modifiers: u32,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TouchPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+// Input.insertText command parameters
+#[derive(Serialize, Debug)]
+pub struct InsertTextParams<'a> {
+    pub text: &'a str,
+}
+
+// Page.captureScreenshot command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureScreenshotParams {
+    pub format: &'static str, // "png", "jpeg" or "webp"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clip: Option<Viewport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_surface: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_beyond_viewport: Option<bool>,
+}
+
+// DOM.getBoxModel command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBoxModelParams {
+    pub backend_node_id: i64,
+}
+
+// Page.printToPDF command parameters
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintToPdfParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub landscape: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_header_footer: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub print_background: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paper_width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paper_height: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_top: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_bottom: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_left: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_right: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_ranges: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_css_page_size: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_mode: Option<&'static str>, // "ReturnAsBase64" (default) or "ReturnAsStream"
+}
+
+// IO.read command parameters
+#[derive(Serialize, Debug)]
+pub struct IoReadParams<'a> {
+    pub handle: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>, // Maximum number of bytes to read
+}
+
+// IO.close command parameters
+#[derive(Serialize, Debug)]
+pub struct IoCloseParams<'a> {
+    pub handle: &'a str,
+}
+
+// Network.getCookies command parameters
+#[derive(Serialize, Debug)]
+pub struct GetCookiesParams {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<String>, // Defaults to the URLs of the page and its frames
+}
+
+// Network.setCookies / Storage.setCookies command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCookiesParams {
+    pub cookies: Vec<CookieParam>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browser_context_id: Option<String>, // Storage.setCookies only
+}
+
+// Network.deleteCookies command parameters
+#[derive(Serialize, Debug)]
+pub struct DeleteCookiesParams {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+// Fetch.enable command parameters
+#[derive(Serialize, Debug)]
+pub struct FetchEnableParams {
+    pub patterns: Vec<RequestPattern>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestPattern {
+    pub url_pattern: String, // Wildcards: '*' any run of characters, '?' one character
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_stage: Option<&'static str>, // "Request" (default) or "Response"
+}
+
+// Fetch.continueRequest command parameters
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContinueRequestParams {
+    pub request_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_data: Option<String>, // Base64-encoded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Vec<HeaderEntry>>,
+}
+
+// Fetch.fulfillRequest command parameters
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillRequestParams {
+    pub request_id: String,
+    pub response_code: u16,
+    pub response_headers: Vec<HeaderEntry>,
+    pub body: String, // Base64-encoded
+}
+
+// Fetch.failRequest command parameters
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FailRequestParams {
+    pub request_id: String,
+    pub error_reason: &'static str, // Network.ErrorReason
+}
+
+// Network.getResponseBody command parameters
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetResponseBodyParams<'a> {
+    pub request_id: &'a str,
+}
+
+// Page.handleJavaScriptDialog command parameters
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HandleJavaScriptDialogParams {
+    pub accept: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_text: Option<String>,
+}
+
+// Page.setInterceptFileChooserDialog command parameters
+#[derive(Serialize, Debug)]
+pub struct SetInterceptFileChooserDialogParams {
+    pub enabled: bool,
+}
+
+// DOM.setFileInputFiles command parameters; the input is given by either ID
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetFileInputFilesParams {
+    pub files: Vec<String>, // Absolute paths
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backend_node_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<String>,
+}
+
+// Emulation.setDeviceMetricsOverride command parameters
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDeviceMetricsOverrideParams {
+    pub width: u32,
+    pub height: u32,
+    pub device_scale_factor: f64,
+    pub mobile: bool,
+}
+
+// Emulation.setTouchEmulationEnabled command parameters
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetTouchEmulationEnabledParams {
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_touch_points: Option<u32>,
+}
+
+// Network.setUserAgentOverride command parameters
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetUserAgentOverrideParams {
+    pub user_agent: String,
+}
+
+// Emulation.setTimezoneOverride command parameters
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetTimezoneOverrideParams {
+    pub timezone_id: String, // Empty restores the system time zone
+}
+
+// Emulation.setLocaleOverride command parameters
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SetLocaleOverrideParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>, // None restores the default
+}
+
+// Emulation.setGeolocationOverride command parameters
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SetGeolocationOverrideParams {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub accuracy: f64,
+}
+
+// Emulation.setEmulatedMedia command parameters
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SetEmulatedMediaParams {
+    pub media: &'static str, // "screen", "print", or empty for no override
+    pub features: Vec<MediaFeature>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MediaFeature {
+    pub name: &'static str,
+    pub value: &'static str,
+}
+
 // --- Results ---
 
 // Example: Target.createTarget result
@@ -57,6 +497,13 @@ pub struct CreateTargetResult {
     pub target_id: String,
 }
 
+// Target.createBrowserContext result
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBrowserContextResult {
+    pub browser_context_id: String,
+}
+
 // Example: Target.attachToTarget result
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -64,6 +511,13 @@ pub struct AttachToTargetResult {
     pub session_id: String,
 }
 
+// Page.getFrameTree result
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFrameTreeResult {
+    pub frame_tree: FrameTree,
+}
+
 // Example: Runtime.evaluate result
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -73,6 +527,92 @@ pub struct EvaluateResult {
     pub exception_details: Option<ExceptionDetails>,
 }
 
+// Page.navigate result
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NavigateResult {
+    pub frame_id: String,
+    #[serde(default)]
+    pub loader_id: Option<String>, // Absent for same-document navigations
+    #[serde(default)]
+    pub error_text: Option<String>, // e.g. "net::ERR_NAME_NOT_RESOLVED"
+}
+
+// Page.captureScreenshot result
+#[derive(Deserialize, Debug)]
+pub struct CaptureScreenshotResult {
+    pub data: String, // Base64-encoded image
+}
+
+// Network.getResponseBody result
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetResponseBodyResult {
+    pub body: String,
+    pub base64_encoded: bool,
+}
+
+// Network.getCookies / Storage.getCookies result
+#[derive(Deserialize, Debug)]
+pub struct GetCookiesResult {
+    pub cookies: Vec<Cookie>,
+}
+
+// Page.printToPDF result
+#[derive(Deserialize, Debug)]
+pub struct PrintToPdfResult {
+    #[serde(default)]
+    pub data: String, // Base64-encoded PDF; empty when streamed
+    #[serde(default)]
+    pub stream: Option<String>, // IO.StreamHandle with transferMode "ReturnAsStream"
+}
+
+// IO.read result
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IoReadResult {
+    #[serde(default)]
+    pub base64_encoded: bool,
+    pub data: String,
+    pub eof: bool,
+}
+
+// Page.getLayoutMetrics result (CSS pixel variants only)
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutMetrics {
+    pub css_layout_viewport: LayoutViewport,
+    pub css_content_size: Rect,
+}
+
+// DOM.getBoxModel result
+#[derive(Deserialize, Debug)]
+pub struct GetBoxModelResult {
+    pub model: BoxModel,
+}
+
+// Page.addScriptToEvaluateOnNewDocument result
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AddScriptToEvaluateOnNewDocumentResult {
+    pub identifier: String,
+}
+
+// Runtime.getProperties result
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPropertiesResult {
+    pub result: Vec<PropertyDescriptor>,
+    #[serde(default)]
+    pub exception_details: Option<ExceptionDetails>,
+}
+
+// DOM.describeNode result
+#[derive(Deserialize, Debug)]
+pub struct DescribeNodeResult {
+    pub node: Node,
+}
+
 // --- Events ---
 
 // Example: Target.targetCreated event parameters
@@ -104,6 +644,200 @@ pub struct TargetDestroyedParams {
     pub target_id: String,
 }
 
+// Runtime.consoleAPICalled event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsoleApiCalledParams {
+    #[serde(rename = "type")]
+    pub type_: String, // e.g., "log", "warning", "error", "assert"
+    #[serde(default)]
+    pub args: Vec<RemoteObject>,
+    pub execution_context_id: i64,
+    pub timestamp: f64,
+    #[serde(default)]
+    pub stack_trace: Option<StackTrace>,
+}
+
+// Runtime.exceptionThrown event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExceptionThrownParams {
+    pub timestamp: f64,
+    pub exception_details: ExceptionDetails,
+}
+
+// Page.lifecycleEvent event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleEventParams {
+    pub frame_id: String,
+    pub loader_id: String,
+    pub name: String,
+    pub timestamp: f64,
+}
+
+// Network.responseReceived event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseReceivedParams {
+    pub request_id: String,
+    pub loader_id: String,
+    pub timestamp: f64,
+    #[serde(rename = "type")]
+    pub type_: String, // e.g. "Document", "Script", "XHR"
+    pub response: Response,
+    #[serde(default)]
+    pub frame_id: Option<String>,
+}
+
+// Network.requestWillBeSent event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestWillBeSentParams {
+    pub request_id: String,
+    pub loader_id: String,
+    #[serde(rename = "documentURL")]
+    pub document_url: String,
+    pub request: Request,
+    pub timestamp: f64,
+    pub wall_time: f64,
+    #[serde(default)]
+    pub redirect_response: Option<Response>,
+    #[serde(rename = "type", default)]
+    pub type_: Option<String>,
+    #[serde(default)]
+    pub frame_id: Option<String>,
+}
+
+// Network.loadingFinished event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadingFinishedParams {
+    pub request_id: String,
+    pub timestamp: f64,
+    pub encoded_data_length: f64,
+}
+
+// Network.loadingFailed event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadingFailedParams {
+    pub request_id: String,
+    pub timestamp: f64,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub error_text: String,
+    #[serde(default)]
+    pub canceled: Option<bool>,
+    #[serde(default)]
+    pub blocked_reason: Option<String>,
+}
+
+// Fetch.requestPaused event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestPausedParams {
+    pub request_id: String,
+    pub request: Request,
+    pub frame_id: String,
+    pub resource_type: String,
+    #[serde(default)]
+    pub response_status_code: Option<i64>, // Set when paused at the response stage
+    #[serde(default)]
+    pub network_id: Option<String>,
+}
+
+// Page.javascriptDialogOpening event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JavascriptDialogOpeningParams {
+    pub url: String,
+    pub message: String,
+    #[serde(rename = "type")]
+    pub type_: String, // "alert", "confirm", "prompt" or "beforeunload"
+    #[serde(default)]
+    pub has_browser_handler: bool,
+    #[serde(default)]
+    pub default_prompt: Option<String>,
+}
+
+// Page.fileChooserOpened event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FileChooserOpenedParams {
+    pub frame_id: String,
+    pub mode: String, // "selectSingle" or "selectMultiple"
+    #[serde(default)]
+    pub backend_node_id: Option<i64>, // Absent for `showOpenFilePicker`
+}
+
+// Runtime.bindingCalled event parameters
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingCalledParams {
+    pub name: String,
+    pub payload: String,
+    pub execution_context_id: i64,
+}
+
+// Page.frameAttached event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameAttachedParams {
+    pub frame_id: String,
+    pub parent_frame_id: String,
+}
+
+// Page.frameNavigated event parameters
+#[derive(Deserialize, Debug)]
+pub struct FrameNavigatedParams {
+    pub frame: Frame,
+}
+
+// Page.frameDetached event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameDetachedParams {
+    pub frame_id: String,
+    #[serde(default)]
+    pub reason: Option<String>, // "remove", or "swap" when the frame moves to another process
+}
+
+// Runtime.executionContextCreated event parameters
+#[derive(Deserialize, Debug)]
+pub struct ExecutionContextCreatedParams {
+    pub context: ExecutionContextDescription,
+}
+
+// Runtime.executionContextDestroyed event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionContextDestroyedParams {
+    pub execution_context_id: i64,
+}
+
+// Browser.downloadWillBegin event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadWillBeginParams {
+    pub frame_id: String,
+    pub guid: String,
+    pub url: String,
+    pub suggested_filename: String,
+}
+
+// Browser.downloadProgress event parameters
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadProgressParams {
+    pub guid: String,
+    pub total_bytes: f64, // 0 if unknown
+    pub received_bytes: f64,
+    pub state: String, // "inProgress", "completed" or "canceled"
+    #[serde(default)]
+    pub file_path: Option<String>, // Only sent by recent versions, once completed
+}
+
 // --- Common Nested Types ---
 
 #[derive(Deserialize, Debug, Clone)]
@@ -116,10 +850,12 @@ pub struct TargetInfo {
     pub url: String,
     pub attached: bool,
     #[serde(default)]
+    pub opener_id: Option<String>, // Set for popups opened via window.open / target=_blank
+    #[serde(default)]
     pub browser_context_id: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct RemoteObject {
     #[serde(rename = "type")]
@@ -128,12 +864,219 @@ pub struct RemoteObject {
     pub description: Option<String>, // String representation
     #[serde(default)]
     pub value: Value, // Primitive value or preview if not object
-                                     // object_id if it's an object handle (needed for interaction)
+    #[serde(default)]
+    pub unserializable_value: Option<String>, // e.g. "NaN", "-0", "Infinity", "12n"
+    #[serde(default)]
+    pub object_id: Option<String>, // Handle to the object, unless returned by value
+    #[serde(default)]
+    pub class_name: Option<String>,
                                      // preview, custom_preview if object/function
 }
 
+// Runtime.PropertyDescriptor (accessor fields omitted)
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyDescriptor {
+    pub name: String,
+    #[serde(default)]
+    pub value: Option<RemoteObject>, // None for accessor properties
+    #[serde(default)]
+    pub enumerable: bool,
+}
+
+// Page.FrameTree
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameTree {
+    pub frame: Frame,
+    #[serde(default)]
+    pub child_frames: Vec<FrameTree>,
+}
+
+// Page.Frame
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Frame {
+    pub id: String,
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub url: String,
+    #[serde(default)]
+    pub url_fragment: Option<String>, // Including the '#'
+}
+
+// Runtime.ExecutionContextDescription
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionContextDescription {
+    pub id: i64,
+    #[serde(default)]
+    pub aux_data: Option<ExecutionContextAuxData>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionContextAuxData {
+    #[serde(default)]
+    pub frame_id: Option<String>,
+    #[serde(default)]
+    pub is_default: bool, // The frame's main world, rather than an isolated world
+}
+
+// Network.Request
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    pub url: String,
+    #[serde(default)]
+    pub url_fragment: Option<String>,
+    pub method: String,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    #[serde(default)]
+    pub post_data: Option<String>,
+}
+
+// Fetch.HeaderEntry
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct HeaderEntry {
+    pub name: String,
+    pub value: String,
+}
+
+// Network.Response
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub url: String,
+    pub status: i64,
+    #[serde(default)]
+    pub status_text: String,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    #[serde(default)]
+    pub mime_type: String,
+    #[serde(default)]
+    pub remote_ip_address: Option<String>,
+    #[serde(default)]
+    pub remote_port: Option<i64>,
+    #[serde(default)]
+    pub from_disk_cache: bool,
+    #[serde(default)]
+    pub from_service_worker: bool,
+    #[serde(default)]
+    pub encoded_data_length: f64,
+    #[serde(default)]
+    pub timing: Option<ResourceTiming>,
+    #[serde(default)]
+    pub protocol: Option<String>,
+}
+
+// Network.ResourceTiming; all but request_time are milliseconds relative to it
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceTiming {
+    pub request_time: f64,
+    pub dns_start: f64,
+    pub dns_end: f64,
+    pub connect_start: f64,
+    pub connect_end: f64,
+    pub ssl_start: f64,
+    pub ssl_end: f64,
+    pub send_start: f64,
+    pub send_end: f64,
+    pub receive_headers_end: f64,
+}
+
+// Network.Cookie
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    pub domain: String,
+    pub path: String,
+    pub expires: f64, // Unix time in seconds; -1 for session cookies
+    pub http_only: bool,
+    pub secure: bool,
+    pub session: bool,
+    #[serde(default)]
+    pub same_site: Option<String>, // "Strict", "Lax" or "None"
+}
+
+// Network.CookieParam
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CookieParam {
+    pub name: String,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    pub secure: bool,
+    pub http_only: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub same_site: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<f64>,
+}
+
+// Page.Viewport: a capture area in document coordinates
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub scale: f64,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutViewport {
+    pub page_x: f64, // Scroll offset of the viewport within the document
+    pub page_y: f64,
+    pub client_width: f64,
+    pub client_height: f64,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+// DOM.BoxModel; each quad is [x1, y1, x2, y2, x3, y3, x4, y4] in viewport coordinates
+#[derive(Deserialize, Debug)]
+pub struct BoxModel {
+    pub content: Vec<f64>,
+    pub padding: Vec<f64>,
+    pub border: Vec<f64>,
+    pub margin: Vec<f64>,
+    pub width: i64,
+    pub height: i64,
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
+pub struct Node {
+    pub node_id: i64,
+    pub backend_node_id: i64,
+    pub node_type: i64,
+    pub node_name: String,
+    #[serde(default)]
+    pub frame_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ExceptionDetails {
     pub exception_id: i64,
     pub text: String, // Short description, e.g., "Uncaught"
@@ -143,5 +1086,25 @@ pub struct ExceptionDetails {
     pub url: Option<String>,
     pub exception: Option<RemoteObject>, // Detailed exception object
     pub execution_context_id: i64,
-    // stack_trace might be here
+    #[serde(default)]
+    pub stack_trace: Option<StackTrace>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StackTrace {
+    #[serde(default)]
+    pub description: Option<String>,
+    pub call_frames: Vec<CallFrame>,
+    // parent (async) stack traces are ignored for now
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFrame {
+    pub function_name: String,
+    pub script_id: String,
+    pub url: String,
+    pub line_number: i64,   // 0-based
+    pub column_number: i64, // 0-based
 }
@@ -0,0 +1,77 @@
+//! Translation of L1 screenshot options into `Page.captureScreenshot` parameters.
+
+use crate::protocol::{CaptureScreenshotParams, Viewport};
+use janus_interfaces::{ApiError, Clip, ScreenshotFormat, ScreenshotOptions};
+
+pub(crate) fn format_to_cdp(format: ScreenshotFormat) -> &'static str {
+    match format {
+        ScreenshotFormat::Png => "png",
+        ScreenshotFormat::Jpeg => "jpeg",
+        ScreenshotFormat::Webp => "webp",
+    }
+}
+
+/// Builds the capture parameters. `options.clip` must already be resolved (full page
+/// and element screenshots fill it in before calling this).
+pub(crate) fn screenshot_params(
+    format: ScreenshotFormat,
+    options: &ScreenshotOptions,
+) -> Result<CaptureScreenshotParams, ApiError> {
+    let format = options.format.unwrap_or(format);
+    if let Some(quality) = options.quality {
+        if format == ScreenshotFormat::Png {
+            return Err(ApiError::InvalidParameters(
+                "quality is not supported for Png screenshots".to_string(),
+            ));
+        }
+        if quality > 100 {
+            return Err(ApiError::InvalidParameters(format!(
+                "quality must be between 0 and 100, got {}",
+                quality
+            )));
+        }
+    }
+    if let Some(clip) = options.clip
+        && (clip.width <= 0.0 || clip.height <= 0.0)
+    {
+        return Err(ApiError::InvalidParameters(format!(
+            "clip must have a positive size, got {}x{}",
+            clip.width, clip.height
+        )));
+    }
+    Ok(CaptureScreenshotParams {
+        format: format_to_cdp(format),
+        quality: options.quality,
+        clip: options.clip.map(|clip| Viewport {
+            x: clip.x,
+            y: clip.y,
+            width: clip.width,
+            height: clip.height,
+            scale: 1.0,
+        }),
+        from_surface: options.from_surface,
+        capture_beyond_viewport: options.capture_beyond_viewport,
+    })
+}
+
+/// Bounding rectangle of a `DOM.BoxModel` quad, offset by the viewport's scroll
+/// position so that it is relative to the document. Returns `None` for empty boxes.
+pub(crate) fn quad_to_clip(quad: &[f64], page_x: f64, page_y: f64) -> Option<Clip> {
+    if quad.len() != 8 {
+        return None;
+    }
+    let xs = quad.iter().step_by(2);
+    let ys = quad.iter().skip(1).step_by(2);
+    let (min_x, max_x) = xs.fold((f64::MAX, f64::MIN), |(min, max), &x| (min.min(x), max.max(x)));
+    let (min_y, max_y) = ys.fold((f64::MAX, f64::MIN), |(min, max), &y| (min.min(y), max.max(y)));
+    let (width, height) = (max_x - min_x, max_y - min_y);
+    if width <= 0.0 || height <= 0.0 {
+        return None;
+    }
+    Some(Clip {
+        x: min_x + page_x,
+        y: min_y + page_y,
+        width,
+        height,
+    })
+}
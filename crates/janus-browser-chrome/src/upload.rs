@@ -0,0 +1,30 @@
+//! File uploads: files for an `<input type=file>` or an intercepted file chooser, both
+//! set through `DOM.setFileInputFiles`.
+
+use janus_interfaces::ApiError;
+use std::path::PathBuf;
+
+// Whether the element is a file input accepting several files; null if it is none.
+pub(crate) const FILE_INPUT_MULTIPLE: &str = "function() {
+    if (this.nodeName !== 'INPUT' || this.type !== 'file') return null;
+    return this.multiple;
+}";
+
+/// Paths of `files`, passed through as given: Chrome opens them itself, possibly on
+/// another machine, so they can be neither checked nor made absolute here.
+pub(crate) fn input_file_paths(files: &[PathBuf], multiple: bool) -> Result<Vec<String>, ApiError> {
+    if files.len() > 1 && !multiple {
+        return Err(ApiError::InvalidParameters(format!(
+            "{} files given, but only one can be chosen",
+            files.len()
+        )));
+    }
+    files
+        .iter()
+        .map(|file| {
+            file.to_str().map(str::to_string).ok_or_else(|| {
+                ApiError::InvalidParameters(format!("File path is not valid UTF-8: {}", file.display()))
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,100 @@
+//! In-page waits of `Page::wait_for_function`.
+//!
+//! The predicate runs in the page until it returns a truthy value or a slice of
+//! [`WAIT_SLICE_MS`] ends; slices repeat until the timeout, which keeps every
+//! `Runtime.callFunctionOn` below the command timeout. Each slice reports the
+//! document's `performance.timeOrigin`, which changes when the page navigates.
+
+use janus_interfaces::{ApiError, Polling};
+use serde::Deserialize;
+use serde_json::Value;
+use std::time::Duration;
+
+/// Longest in-page wait of a single call.
+pub(crate) const WAIT_SLICE_MS: u64 = 5_000;
+
+/// How long a slice may overrun before the wait gives up on the page.
+pub(crate) const WAIT_SLICE_GRACE: Duration = Duration::from_secs(1);
+
+/// Delay before running a slice again after its document went away.
+pub(crate) const WAIT_RETRY_DELAY: Duration = Duration::from_millis(20);
+
+/// Result of one slice.
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WaitSlice {
+    pub done: bool,
+    #[serde(default)]
+    pub value: Value, // The truthy value, or the last falsy one
+    pub time_origin: f64,
+}
+
+/// Wraps `predicate` into a function of `(polling, interval, sliceMs, ...args)` that
+/// runs one slice.
+pub(crate) fn wait_script(predicate: &str) -> String {
+    format!(
+        "async function(polling, interval, sliceMs, ...args) {{
+    const predicate = ({predicate});
+    const deadline = Date.now() + sliceMs;
+    const timeOrigin = performance.timeOrigin;
+    while (true) {{
+        const value = await predicate(...args);
+        const remaining = deadline - Date.now();
+        if (value || remaining <= 0) return {{ done: !!value, value, timeOrigin }};
+        await new Promise(resolve => {{
+            let observer;
+            const next = () => {{
+                if (observer) observer.disconnect();
+                clearTimeout(timer);
+                resolve();
+            }};
+            // Animation frames do not fire in pages that are not rendering.
+            const delay = polling === 'interval' ? interval : polling === 'raf' ? 100 : remaining;
+            const timer = setTimeout(next, Math.min(delay, remaining));
+            if (polling === 'raf') requestAnimationFrame(next);
+            if (polling === 'mutation') {{
+                observer = new MutationObserver(next);
+                observer.observe(document, {{ childList: true, subtree: true, attributes: true, characterData: true }});
+            }}
+        }});
+    }}
+}}"
+    )
+}
+
+/// The `polling` and `interval` arguments of [`wait_script`].
+pub(crate) fn polling_arguments(polling: Polling) -> (&'static str, u64) {
+    match polling {
+        Polling::RequestAnimationFrame => ("raf", 0),
+        Polling::Interval(interval_ms) => ("interval", interval_ms),
+        Polling::Mutation => ("mutation", 0),
+    }
+}
+
+pub(crate) fn parse_wait_slice(value: Value) -> Result<WaitSlice, ApiError> {
+    serde_json::from_value(value)
+        .map_err(|e| ApiError::InternalError(format!("Failed to parse wait_for_function result: {}", e)))
+}
+
+/// The error a wait fails with after a slice failed with `error`, or `None` if the
+/// slice should run again. `ran` tells whether a slice already ran in a document.
+///
+/// A context that went away before any slice ran is the previous document's (e.g.
+/// right after `goto`), and one that went away after is a navigation. Whether the
+/// target "navigated or closed" is told by the next slice: a new `timeOrigin`, or the
+/// session being gone.
+pub(crate) fn slice_error(error: ApiError, ran: bool) -> Option<ApiError> {
+    let ApiError::ProtocolError(message) = &error else {
+        return Some(error);
+    };
+    let has = |messages: &[&str]| messages.iter().any(|expected| message.contains(expected));
+    if has(&["Session with given id not found", "No target with given id"]) {
+        Some(ApiError::TargetDetached)
+    } else if has(&["Inspected target navigated or closed"]) {
+        None
+    } else if has(&["Execution context was destroyed", "Cannot find context with specified id"]) {
+        ran.then(|| ApiError::WaitCancelled(format!("the page navigated ({})", message)))
+    } else {
+        Some(error)
+    }
+}
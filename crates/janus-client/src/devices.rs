@@ -0,0 +1,32 @@
+//! Device profiles from configuration.
+
+use janus_core::config::Config;
+use janus_interfaces::{DeviceDescriptor, DeviceRegistry};
+
+/// The built-in device profiles plus those of the `[devices."<name>"]` tables in
+/// `config`; a table named like a built-in profile replaces it.
+///
+/// ```toml
+/// [devices."Kiosk 1080p"]
+/// width = 1920
+/// height = 1080
+/// user_agent = "Mozilla/5.0 (X11; Linux x86_64) KioskBrowser/1.0"
+/// ```
+pub fn device_registry(config: &Config) -> DeviceRegistry {
+    let mut registry = DeviceRegistry::default();
+    // Sorted, so that names differing only in case resolve the same way on every run.
+    let mut devices: Vec<_> = config.devices.iter().collect();
+    devices.sort_by(|a, b| a.0.cmp(b.0));
+    for (name, device) in devices {
+        registry.insert(DeviceDescriptor {
+            name: name.clone(),
+            width: device.width,
+            height: device.height,
+            device_scale_factor: device.device_scale_factor,
+            is_mobile: device.is_mobile,
+            has_touch: device.has_touch,
+            user_agent: device.user_agent.clone(),
+        });
+    }
+    registry
+}
@@ -2,7 +2,7 @@
 
 use crate::error::ClientError;
 use crate::supervisor::{CoreActorsInfo, StartBrowserActor, StartCoreActors, SupervisorActor};
-use janus_browser_chrome::ChromeBrowser; // Import L2 implementation
+use janus_browser_chrome::{ChromeBrowser, ChromeProcess, DevToolsHttpClient}; // Import L2 implementation
 use janus_core::config::{self, BrowserLaunchConfig, Config};
 use janus_core::logging;
 use janus_interfaces::Browser; // Use L1 traits
@@ -54,9 +54,8 @@ pub async fn launch(
     info!("Janus Client starting...");
     debug!("Loaded configuration: {:?}", cfg); // Be careful logging sensitive config
 
-    // 3. Determine ConnectParams and Launch specific config
-    // TODO (Phase 3): Implement actual browser process launching in determine_connection
-    let (connect_params, _launch_config) = determine_connection_params(&mode, &cfg).await?; // launch_config needed later for process mgmt
+    // 3. Determine ConnectParams, launching the browser process if requested
+    let (connect_params, process) = determine_connection_params(&mode, &cfg).await?;
 
     // --- Phase 2: Actor System and Wiring ---
 
@@ -115,8 +114,11 @@ pub async fn launch(
     // TODO: Wait for BrowserActor to signal readiness?
 
     // 7. Create the L2 Browser implementation (e.g., ChromeBrowser)
-    // Give it the Addr of the BrowserActor
-    let browser_impl = ChromeBrowser::new(browser_actor_addr);
+    // Give it the Addr of the BrowserActor, and ownership of the process if we launched one
+    let browser_impl = match process {
+        Some(process) => ChromeBrowser::with_process(browser_actor_addr, process),
+        None => ChromeBrowser::new(browser_actor_addr),
+    };
 
     // 8. Return the L2 implementation boxed as `dyn Browser`
     info!("Janus client launch sequence complete.");
@@ -124,21 +126,22 @@ pub async fn launch(
 }
 
 /// Determines the connection parameters based on launch mode and config.
-/// Phase 2: Does *not* actually launch the browser process yet.
+/// For `LaunchMode::Launch` this spawns the browser process and returns it, so the
+/// caller can hand ownership to the `Browser` handle.
 async fn determine_connection_params(
     mode: &LaunchMode,
     cfg: &Config,
-) -> Result<(ConnectParams, BrowserLaunchConfig), ClientError> {
+) -> Result<(ConnectParams, Option<ChromeProcess>), ClientError> {
     match mode {
         LaunchMode::Connect { url } => {
             info!("Connecting to existing browser at: {}", url);
             let params = ConnectParams {
-                url: url.clone(),
+                url: resolve_browser_ws_url(url, cfg).await?,
                 connection_timeout: cfg.transport.connect_timeout,
                 // #[cfg(feature = "websocket")]
                 ws_options: cfg.transport.websocket.clone(),
             };
-            Ok((params, BrowserLaunchConfig::default())) // No launch config needed
+            Ok((params, None)) // Not our process to manage
         }
         LaunchMode::Launch {
             browser_id,
@@ -160,29 +163,20 @@ async fn determine_connection_params(
 
             debug!("Effective launch configuration: {:?}", launch_cfg);
 
-            // Phase 2: Determine connection URL *without* launching process
-            let url = if let Some(override_url) = &launch_cfg.connection_url_override {
+            let (url, process) = if let Some(override_url) = &launch_cfg.connection_url_override {
+                // The browser is managed externally; only connect to it.
                 info!("Using connection URL override: {}", override_url);
-                override_url.clone()
+                (resolve_browser_ws_url(override_url, cfg).await?, None)
             } else {
-                // Construct URL from config defaults (e.g., localhost:9222)
-                let port = launch_cfg.remote_debugging_port.unwrap_or(9222);
-                let addr = launch_cfg
-                    .remote_debugging_address
-                    .as_deref()
-                    .unwrap_or("127.0.0.1");
-
-                // In Phase 2, we'll use a simpler approach for connecting to Chrome
-                // Connect to the /json/version endpoint first to get browser websocket URL
+                let process = ChromeProcess::launch(&launch_cfg, cfg.transport.connect_timeout)
+                    .await
+                    .map_err(|e| ClientError::LaunchError(e.to_string()))?;
                 info!(
-                    "Discovering browser websocket endpoint at http://{}:{}",
-                    addr, port
+                    "Launched browser (pid: {:?}), profile: {}",
+                    process.pid(),
+                    process.user_data_dir().display()
                 );
-
-                // In a real implementation, we would fetch the WebSocket URL from:
-                // http://{}:{}/json/version
-                // For now, use a direct WebSocket URL that should work with standard Chrome
-                format!("ws://{}:{}/devtools/browser/", addr, port).into()
+                (process.ws_url().to_string(), Some(process))
             };
 
             let params = ConnectParams {
@@ -192,7 +186,43 @@ async fn determine_connection_params(
                 ws_options: cfg.transport.websocket.clone(), // TODO: Merge from launch_cfg if needed
             };
 
-            Ok((params, launch_cfg))
+            Ok((params, process))
         }
     }
 }
+
+/// Turns a user-supplied endpoint into a browser-level WebSocket URL.
+///
+/// Full URLs such as `ws://host:port/devtools/browser/<id>` are used as-is. For
+/// `http(s)://host:port` or a `ws://` URL without the browser ID, the real
+/// `webSocketDebuggerUrl` is looked up via `/json/version`.
+async fn resolve_browser_ws_url(url: &str, cfg: &Config) -> Result<String, ClientError> {
+    if has_target_path(url) {
+        return Ok(url.to_string());
+    }
+    info!("Discovering browser websocket endpoint for {}", url);
+    let client = DevToolsHttpClient::from_url(url)?.with_timeout(cfg.transport.connect_timeout);
+    let ws_url = client.browser_ws_url().await.map_err(|e| {
+        ClientError::LaunchError(format!(
+            "Failed to discover browser endpoint at http://{}:{}: {}",
+            client.host(),
+            client.port(),
+            e
+        ))
+    })?;
+    info!("Discovered browser websocket endpoint: {}", ws_url);
+    Ok(ws_url)
+}
+
+/// True for WebSocket URLs that already name a target, e.g. `/devtools/browser/<id>`.
+fn has_target_path(url: &str) -> bool {
+    let Some(rest) = url
+        .strip_prefix("ws://")
+        .or_else(|| url.strip_prefix("wss://"))
+    else {
+        return false;
+    };
+    let path = rest.find('/').map(|idx| &rest[idx..]).unwrap_or("");
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    matches!(segments.as_slice(), ["devtools", _kind, _id])
+}
@@ -9,17 +9,92 @@
 
 // Re-export the L1 API for user convenience
 pub use janus_interfaces::{
+    AbortReason,
     ApiError,
+    BoundingBox,
     Browser,
+    BrowserContext,
+    BrowserContextOptions,
     Clip,
+    ColorScheme,
     // Re-export other common types as needed
+    Condition,
     ConsoleLogLevel,
     ConsoleMessage,
+    ContinueOverrides,
+    Cookie,
+    CookieFilter,
+    DEFAULT_LOCATOR_TIMEOUT_MS,
+    DeviceDescriptor,
+    DeviceRegistry,
+    DialogInfo,
+    DialogPolicy,
+    DialogType,
+    Download,
+    DownloadBehavior,
+    DownloadProgress,
+    DownloadStarted,
+    DownloadState,
     ElementHandle,
+    ElementState,
+    EventHandler,
+    ExposedFunction,
+    ExposedFunctionFuture,
+    FileChooser,
+    Frame,
+    FulfillResponse,
+    Geolocation,
+    Har,
+    HarContent,
+    HarEntry,
+    HarHeader,
+    HarLog,
+    HarNotFound,
+    HarPage,
+    HarRecordOptions,
+    HarReplayOptions,
+    HarRequest,
+    HarResponse,
+    HarTimings,
+    HarUrlMatch,
+    InitScriptId,
+    InterceptedRequest,
+    JsArg,
+    JsHandle,
+    JsValue,
+    KeyModifiers,
+    LifecycleEvent,
+    Locator,
+    LocatorFilter,
+    MediaEmulation,
+    MediaType,
+    MouseButton,
+    MouseOptions,
+    NavigationResult,
+    NetworkRequest,
+    NetworkResponse,
     Page,
+    PageCloseOptions,
+    PageExt,
+    PageError,
+    PdfOptions,
+    Permission,
+    Polling,
+    ReducedMotion,
+    RequestFailed,
+    RequestFinished,
+    ResourceTiming,
+    RouteAction,
+    RouteHandler,
+    SameSite,
     ScreenshotFormat,
     ScreenshotOptions,
     SubscriptionId,
+    TargetCreatedEvent,
+    TargetInfo,
+    TargetType,
+    WaitUntil,
+    wait_until,
 };
 
 // Export value for JSON parsing/serialization
@@ -31,11 +106,16 @@ pub use janus_core::Config; // Make Config accessible
 // Re-export specific Transport types if needed for advanced config/launch
 pub use janus_transport::{ConnectParams, WebSocketConnectOptions};
 
+// DevTools HTTP discovery (`/json/version`, `/json/list`, ...) for use without a WebSocket
+pub use janus_browser_chrome::discovery;
+
 // Modules internal to this crate
+mod devices; // Device profiles from `janus.toml`
 mod error;
-mod launch; // Placeholder for launch functions
+pub mod launch; // Launch/connect entry points (`launch`, `LaunchMode`)
 mod supervisor; // Placeholder for the main supervisor
 
+pub use devices::device_registry;
 pub use error::ClientError;
 pub use launch::launch; // Example basic launch function
 
@@ -51,8 +131,9 @@ mod tests {
     // Test re-exports (compile-time check)
     #[allow(dead_code)]
     fn check_reexports() {
-        let _e: ApiError = ApiError::Timeout;
+        let _e: ApiError = ApiError::Timeout { last_observed: None };
         let _cfg: Config = Config::default();
+        let _devices: DeviceRegistry = device_registry(&_cfg);
         let _params: ConnectParams = ConnectParams {
             url: String::new(),
             connection_timeout: std::time::Duration::from_secs(1),
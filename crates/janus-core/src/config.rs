@@ -34,6 +34,7 @@ pub struct Config {
     pub browser_defaults: BrowserLaunchConfig, // Default launch settings
     // Use BTreeMap for consistent ordering if serialized/logged
     pub browsers: HashMap<String, BrowserLaunchConfig>, // Browser-specific overrides
+    pub devices: HashMap<String, DeviceConfig>, // Extra device profiles, keyed by name
 }
 
 // Global settings
@@ -145,6 +146,24 @@ pub struct BidiSettings {
     pub capabilities: Option<serde_json::Value>,
 }
 
+// Device profile for emulation, from a `[devices."<name>"]` table
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct DeviceConfig {
+    pub width: u32, // Viewport size in CSS pixels
+    pub height: u32,
+    #[serde(default = "default_device_scale_factor")]
+    pub device_scale_factor: f64,
+    #[serde(default)]
+    pub is_mobile: bool,
+    #[serde(default)]
+    pub has_touch: bool,
+    pub user_agent: String,
+}
+
+fn default_device_scale_factor() -> f64 {
+    1.0
+}
+
 // Merging logic remains the same as in the design doc
 impl BrowserLaunchConfig {
     pub fn merged_with(&self, defaults: &BrowserLaunchConfig) -> Self {
@@ -25,6 +25,20 @@ pub enum InternalError {
     #[error("Internal operation timed out")]
     Timeout,
 
+    /// A page navigation failed or did not finish in time.
+    #[error("Navigation to {url} failed: {reason}")]
+    Navigation { url: String, reason: String },
+
+    /// A script threw, or returned a rejected promise.
+    #[error("Script evaluation failed: {message}")]
+    Evaluation {
+        message: String,
+        url: Option<String>,
+        line_number: Option<u32>,
+        column_number: Option<u32>,
+        stack: Option<String>, // Formatted JavaScript stack trace
+    },
+
     /// Could not determine the state or details of the browser process (likely crashed).
     #[error("Browser process died or is unresponsive")]
     BrowserProcessDied,
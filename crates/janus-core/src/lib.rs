@@ -55,4 +55,34 @@ mod tests {
             Duration::from_millis(10000)
         );
     }
+
+    #[test]
+    fn test_device_config() {
+        let toml = r#"
+            [devices."Kiosk 1080p"]
+            width = 1920
+            height = 1080
+            user_agent = "KioskBrowser/1.0"
+
+            [devices."Galaxy A54"]
+            width = 412
+            height = 915
+            device_scale_factor = 2.625
+            is_mobile = true
+            has_touch = true
+            user_agent = "Mozilla/5.0 (Linux; Android 13; SM-A546B)"
+        "#;
+        let cfg: Config = ::config::Config::builder()
+            .add_source(::config::File::from_str(toml, ::config::FileFormat::Toml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        let kiosk = &cfg.devices["Kiosk 1080p"];
+        assert_eq!(kiosk.device_scale_factor, 1.0);
+        assert!(!kiosk.is_mobile && !kiosk.has_touch);
+        let phone = &cfg.devices["Galaxy A54"];
+        assert_eq!(phone.device_scale_factor, 2.625);
+        assert!(phone.is_mobile && phone.has_touch);
+    }
 }
@@ -1,7 +1,20 @@
+use crate::common::{
+    BrowserContextOptions, Cookie, CookieFilter, Download, DownloadBehavior, DownloadProgress, DownloadStarted,
+    EventHandler, SubscriptionId, TargetInfo,
+};
+use crate::context::BrowserContext;
 use crate::error::ApiError;
 use crate::page::Page;
 use std::fmt::Debug;
 
+/// Payload passed to [`Browser::on_target_created`] handlers.
+#[derive(Debug)]
+pub struct TargetCreatedEvent {
+    pub target: TargetInfo,
+    /// Ready-to-use handle for page targets (tabs and popups); `None` for other target types.
+    pub page: Option<Box<dyn Page>>,
+}
+
 /// Represents a connection to and control over a web browser instance.
 ///
 /// This trait provides a high-level, protocol-agnostic API for interacting
@@ -37,6 +50,17 @@ pub trait Browser: Send + Sync + Debug {
     /// - `Err(ApiError)` if creating the page fails.
     async fn new_page(&self) -> Result<Box<dyn Page>, ApiError>;
 
+    /// Creates an isolated browser context, like an incognito window: its pages share
+    /// cookies, storage and cache with each other but not with the rest of the browser.
+    ///
+    /// # Returns
+    /// - `Ok(Box<dyn BrowserContext>)` containing a handle to the new context.
+    /// - `Err(ApiError)` if creating the context fails.
+    async fn new_context(
+        &self,
+        options: BrowserContextOptions,
+    ) -> Result<Box<dyn BrowserContext>, ApiError>;
+
     /// Retrieves handles to all currently open pages/tabs (targets) within the browser.
     ///
     /// # Returns
@@ -51,11 +75,114 @@ pub trait Browser: Send + Sync + Debug {
     /// - `Err(ApiError)` if fetching version information fails.
     async fn version(&self) -> Result<String, ApiError>;
 
-    // --- Event Subscription (Placeholder - Requires careful design) ---
-    // Event subscription APIs might return stream handles or require callbacks.
-    // This is a complex area deferred beyond Phase 1.
+    // --- Cookies ---
+    // These act on the default context; see `BrowserContext` for the others.
+
+    /// Returns the cookies stored in the browser.
+    ///
+    /// # Arguments
+    /// * `urls` - Only return cookies that would be sent to one of these URLs.
+    ///   An empty slice returns all cookies.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Cookie>)` with the matching cookies.
+    /// - `Err(ApiError)` if reading the cookies fails.
+    async fn cookies(&self, urls: &[String]) -> Result<Vec<Cookie>, ApiError>;
+
+    /// Sets cookies, replacing existing ones with the same name, domain and path.
+    ///
+    /// # Returns
+    /// - `Ok(())` once all cookies are set.
+    /// - `Err(ApiError)` if a cookie is rejected (e.g., neither `url` nor `domain` is set).
+    async fn set_cookies(&self, cookies: Vec<Cookie>) -> Result<(), ApiError>;
+
+    /// Deletes the cookies selected by `filter`.
+    async fn delete_cookies(&self, filter: CookieFilter) -> Result<(), ApiError>;
+
+    /// Deletes all cookies stored in the browser.
+    async fn clear_cookies(&self) -> Result<(), ApiError>;
+
+    // --- Downloads ---
 
-    // Example concept (details TBD):
-    // async fn on_target_created(&self, handler: Box<dyn Fn(Box<dyn Page>) + Send + Sync + 'static>) -> Result<SubscriptionId, ApiError>;
-    // async fn unsubscribe(&self, id: SubscriptionId) -> Result<(), ApiError>;
+    /// Sets what happens to downloads of the default context; see
+    /// `BrowserContext::set_download_behavior` for the others.
+    ///
+    /// # Returns
+    /// - `Ok(())` once the behavior is in effect.
+    /// - `Err(ApiError)` if the browser rejects it (e.g., the path is not a directory).
+    async fn set_download_behavior(&self, behavior: DownloadBehavior) -> Result<(), ApiError>;
+
+    /// Waits for the next download to start and complete. Downloads are not told apart
+    /// by context: the first one to start in any context is the one waited for, so
+    /// with several contexts downloading at once, use `on_download_started` and
+    /// `on_download_progress` instead.
+    ///
+    /// # Returns
+    /// - `Ok(Download)` with the path of the saved file.
+    /// - `Err(ApiError::DownloadFailed)` if it was canceled or not saved (no
+    ///   `DownloadBehavior::Allow` in effect).
+    /// - `Err(ApiError::Timeout)` if it did not complete within `timeout_ms`.
+    async fn wait_for_download(&self, timeout_ms: u64) -> Result<Download, ApiError>;
+
+    // --- Event Subscription ---
+
+    /// Registers a handler for newly created targets (tabs, popups, workers, iframes).
+    /// Page targets are reported once they are attached, together with a `Page` handle.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Browser::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_target_created(
+        &self,
+        handler: EventHandler<TargetCreatedEvent>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for target info changes (e.g., a page navigated or changed its title).
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Browser::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_target_changed(
+        &self,
+        handler: EventHandler<TargetInfo>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for destroyed targets. The handler receives the last
+    /// known info of the target.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Browser::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_target_destroyed(
+        &self,
+        handler: EventHandler<TargetInfo>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for downloads that are about to start, in any context.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Browser::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_download_started(
+        &self,
+        handler: EventHandler<DownloadStarted>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for the progress of downloads, up to their completion or
+    /// cancellation. Only reported while a download behavior is set.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Browser::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_download_progress(
+        &self,
+        handler: EventHandler<DownloadProgress>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Removes a handler registered with one of the `on_*` methods.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the subscription was removed.
+    /// - `Err(ApiError::InvalidParameters)` if the ID is unknown.
+    async fn unsubscribe(&self, id: SubscriptionId) -> Result<(), ApiError>;
 }
@@ -1,25 +1,29 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::pin::Pin;
 
 /// Represents a unique identifier for event subscriptions.
 pub type SubscriptionId = u64;
 
-/// Represents a handle to an element in the DOM.
-/// Specific implementations will hold protocol-specific details internally.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct ElementHandle {
-    // For now, just a placeholder. Might contain an internal ID or description.
-    // The exact structure might evolve based on implementation needs.
-    pub description: String,
-    // Potentially add remote object ID if common across protocols?
-    // pub internal_id: String,
-}
+/// Callback invoked for each occurrence of a subscribed event.
+///
+/// Handlers run on the actor that dispatches the event, so they should return
+/// quickly (e.g., push into a channel or a shared `Vec`) rather than block.
+pub type EventHandler<T> = Box<dyn Fn(T) + Send + Sync + 'static>;
 
 /// Represents a message logged to the browser's console.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ConsoleMessage {
     pub level: ConsoleLogLevel,
     pub text: String,
-    // Potentially add source (JS, network, etc.), line number, args etc.
+    /// Script URL of the call site, if known.
+    pub url: Option<String>,
+    /// 0-based line number of the call site, if known.
+    pub line_number: Option<u32>,
+    /// 0-based column number of the call site, if known.
+    pub column_number: Option<u32>,
 }
 
 /// Severity level of a console message.
@@ -33,29 +37,590 @@ pub enum ConsoleLogLevel {
     // Other levels like trace, table, etc. might be added
 }
 
+/// A page lifecycle milestone, e.g. `"DOMContentLoaded"`, `"load"` or `"networkIdle"`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LifecycleEvent {
+    /// Name of the lifecycle event as reported by the browser.
+    pub name: String,
+    /// Frame the event belongs to.
+    pub frame_id: String,
+    /// Monotonic timestamp in seconds.
+    pub timestamp: f64,
+}
+
+/// When a navigation is considered finished.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum WaitUntil {
+    /// The new document has been committed; its content may not be parsed yet.
+    Commit,
+    /// The `DOMContentLoaded` event has fired.
+    DomContentLoaded,
+    /// The `load` event has fired.
+    #[default]
+    Load,
+    /// There have been no network connections for at least 500 ms.
+    NetworkIdle,
+}
+
+/// Outcome of a completed navigation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NavigationResult {
+    /// ID of the loader that fetched the new document; `None` for same-document
+    /// navigations (e.g. fragment changes).
+    pub loader_id: Option<String>,
+    /// URL of the document after redirects.
+    pub url: String,
+    /// HTTP status of the main document response, if it was loaded over HTTP.
+    pub status: Option<u16>,
+}
+
+/// An uncaught exception (or unhandled promise rejection) thrown by page script.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PageError {
+    /// Exception message, e.g. `"Uncaught TypeError: x is not a function"`.
+    pub message: String,
+    pub url: Option<String>,
+    pub line_number: Option<u32>,
+    pub column_number: Option<u32>,
+    /// Formatted JavaScript stack trace, if available.
+    pub stack: Option<String>,
+}
+
+/// Kind of JavaScript dialog opened by the page.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DialogType {
+    Alert,
+    Confirm,
+    Prompt,
+    BeforeUnload,
+}
+
+/// A JavaScript dialog (`alert`, `confirm`, `prompt`, `beforeunload`) opened by the page.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DialogInfo {
+    pub dialog_type: DialogType,
+    pub message: String,
+    /// Default value of a `prompt()` dialog.
+    pub default_prompt: Option<String>,
+    /// URL of the frame that opened the dialog.
+    pub url: String,
+}
+
+/// What happens to a dialog while no `Page::on_dialog` handler is registered.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum DialogPolicy {
+    /// Accept it; `prompt()` returns its default value.
+    Accept,
+    /// Dismiss it: `confirm()` returns false, `prompt()` null, and `beforeunload`
+    /// keeps the page open.
+    #[default]
+    Dismiss,
+    /// Dismiss it and report it to the `Page::on_page_error` handlers, for pages that
+    /// must not open dialogs at all.
+    Error,
+}
+
+/// A file chooser opened by the page (e.g., by clicking an `<input type=file>`), held
+/// open until answered with `Page::set_file_chooser_files`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileChooser {
+    /// Identifies the chooser in `Page::set_file_chooser_files`.
+    pub id: u64,
+    /// Whether several files may be chosen (`<input multiple>`).
+    pub multiple: bool,
+    /// ID of the frame that opened the chooser.
+    pub frame_id: String,
+}
+
+/// Options for `Page::close_with_options`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct PageCloseOptions {
+    /// Run the page's `beforeunload` handlers first. A `beforeunload` dialog is then
+    /// handled like any other; dismissing it keeps the page open.
+    pub run_before_unload: bool,
+}
+
+/// Kind of browser target.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetType {
+    Page,
+    BackgroundPage,
+    Iframe,
+    Worker,
+    SharedWorker,
+    ServiceWorker,
+    Browser,
+    #[serde(other)]
+    Other,
+}
+
+/// Describes a browser target (tab, popup, out-of-process iframe, worker, ...).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TargetInfo {
+    pub target_id: String,
+    pub target_type: TargetType,
+    pub title: String,
+    pub url: String,
+    /// Whether a debugging client is attached to the target.
+    pub attached: bool,
+    /// ID of the target that opened this one (e.g., the page that opened a popup).
+    pub opener_id: Option<String>,
+    pub browser_context_id: Option<String>,
+}
+
+/// Options for `Browser::new_context`.
+///
+/// `timezone_id`, `locale`, `geolocation` and `media` are applied to every page of the
+/// context, including popups, as by `Page::set_timezone`, `Page::set_locale`,
+/// `Page::set_geolocation` and `Page::emulate_media`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct BrowserContextOptions {
+    /// Proxy for all requests of the context, e.g. `"http://proxy.example.com:8080"`.
+    pub proxy_server: Option<String>,
+    /// Comma-separated hosts that bypass the proxy.
+    pub proxy_bypass_list: Option<String>,
+    /// Permissions granted to all origins when the context is created.
+    pub permissions: Vec<Permission>,
+    pub timezone_id: Option<String>,
+    pub locale: Option<String>,
+    pub geolocation: Option<Geolocation>,
+    pub media: MediaEmulation,
+}
+
+/// What the browser does with downloads; see `Browser::set_download_behavior`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum DownloadBehavior {
+    /// The browser's own behavior. Headless browsers drop downloads.
+    #[default]
+    Default,
+    /// Save downloads in `download_path`, each under its GUID as file name (see
+    /// `DownloadStarted::guid`), so that concurrent downloads never collide.
+    Allow { download_path: PathBuf },
+    /// Cancel all downloads.
+    Deny,
+}
+
+/// A download that is about to start.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DownloadStarted {
+    /// Identifies the download in later `DownloadProgress` events.
+    pub guid: String,
+    pub url: String,
+    /// File name proposed by the server or the `download` attribute.
+    pub suggested_filename: String,
+    /// ID of the frame that started the download.
+    pub frame_id: String,
+}
+
+/// State of a download.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DownloadState {
+    InProgress,
+    Completed,
+    Canceled,
+}
+
+/// Progress of a download.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DownloadProgress {
+    pub guid: String,
+    pub state: DownloadState,
+    pub received_bytes: u64,
+    /// Size of the download, if known.
+    pub total_bytes: Option<u64>,
+    /// Where the file was saved; set once completed, if downloads are allowed.
+    pub path: Option<PathBuf>,
+}
+
+/// A completed download, as returned by `Browser::wait_for_download`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Download {
+    pub guid: String,
+    pub url: String,
+    pub suggested_filename: String,
+    pub path: PathBuf,
+}
+
+/// A permission that can be granted to the origins of a browser context.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Permission {
+    Geolocation,
+    Notifications,
+    Camera,
+    Microphone,
+    ClipboardRead,
+    ClipboardWrite,
+    Midi,
+    MidiSysex,
+    BackgroundSync,
+    Sensors,
+    PaymentHandler,
+    StorageAccess,
+    IdleDetection,
+    LocalFonts,
+}
+
+/// A position reported by the Geolocation API.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Geolocation {
+    /// Degrees, -90 to 90.
+    pub latitude: f64,
+    /// Degrees, -180 to 180.
+    pub longitude: f64,
+    /// Accuracy radius in meters. Defaults to 0.
+    pub accuracy: Option<f64>,
+}
+
+/// CSS media type, as matched by `@media screen` / `@media print`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MediaType {
+    Screen,
+    Print,
+}
+
+/// Value of the `prefers-color-scheme` media feature.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+    NoPreference,
+}
+
+/// Value of the `prefers-reduced-motion` media feature.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ReducedMotion {
+    Reduce,
+    NoPreference,
+}
+
+/// Media emulated by `Page::emulate_media`. `None` fields follow the browser's own
+/// settings.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct MediaEmulation {
+    pub media_type: Option<MediaType>,
+    pub color_scheme: Option<ColorScheme>,
+    pub reduced_motion: Option<ReducedMotion>,
+}
+
+/// Value of a cookie's `SameSite` attribute.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+/// An HTTP cookie.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    /// Cookie domain; a leading dot also matches subdomains. When setting a cookie,
+    /// either `domain` or `url` must be given (pages fall back to their own URL).
+    pub domain: Option<String>,
+    pub path: Option<String>,
+    /// Only used when setting a cookie: the URL it is set for. Domain, path and
+    /// `secure` default from it.
+    pub url: Option<String>,
+    /// Expiry as Unix time in seconds; `None` for session cookies.
+    pub expires: Option<f64>,
+    pub http_only: bool,
+    pub secure: bool,
+    pub same_site: Option<SameSite>,
+}
+
+/// Selects the cookies removed by `delete_cookies`: all cookies named `name` that
+/// also match every other field that is set.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CookieFilter {
+    pub name: String,
+    /// Matches cookies that would be sent to this URL.
+    pub url: Option<String>,
+    /// Matches the cookie domain exactly.
+    pub domain: Option<String>,
+    /// Matches the cookie path exactly.
+    pub path: Option<String>,
+}
+
+/// A request about to be sent by the page.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NetworkRequest {
+    /// Identifier shared by all events of this request; also used by `response_body`.
+    pub request_id: String,
+    pub url: String,
+    pub method: String,
+    pub headers: HashMap<String, String>,
+    pub post_data: Option<String>,
+    /// Kind of resource, e.g. `"Document"`, `"Script"`, `"XHR"` or `"Fetch"`.
+    pub resource_type: Option<String>,
+    pub frame_id: Option<String>,
+    /// URL of the document that issued the request.
+    pub document_url: String,
+    /// Monotonic timestamp in seconds.
+    pub timestamp: f64,
+    /// Unix time in seconds.
+    pub wall_time: f64,
+    /// Response that redirected to this request, if any. Redirects reuse the request ID.
+    pub redirect_response: Option<NetworkResponse>,
+}
+
+/// Response headers of a request were received.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NetworkResponse {
+    pub request_id: String,
+    pub url: String,
+    pub status: u16,
+    pub status_text: String,
+    pub headers: HashMap<String, String>,
+    pub mime_type: String,
+    pub resource_type: Option<String>,
+    pub remote_ip_address: Option<String>,
+    pub remote_port: Option<u16>,
+    /// Network protocol, e.g. `"http/1.1"` or `"h2"`.
+    pub protocol: Option<String>,
+    pub from_disk_cache: bool,
+    pub from_service_worker: bool,
+    pub timing: Option<ResourceTiming>,
+    /// Monotonic timestamp in seconds.
+    pub timestamp: f64,
+}
+
+/// Timing of a request. `request_time` is a monotonic timestamp in seconds; all other
+/// values are milliseconds relative to it, or -1 if the phase did not happen.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ResourceTiming {
+    pub request_time: f64,
+    pub dns_start: f64,
+    pub dns_end: f64,
+    pub connect_start: f64,
+    pub connect_end: f64,
+    pub ssl_start: f64,
+    pub ssl_end: f64,
+    pub send_start: f64,
+    pub send_end: f64,
+    pub receive_headers_end: f64,
+}
+
+/// A request finished loading its body.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RequestFinished {
+    pub request_id: String,
+    /// Monotonic timestamp in seconds.
+    pub timestamp: f64,
+    /// Bytes received over the network, including headers.
+    pub encoded_data_length: f64,
+}
+
+/// A request failed or was canceled.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RequestFailed {
+    pub request_id: String,
+    /// Monotonic timestamp in seconds.
+    pub timestamp: f64,
+    /// Network error, e.g. `"net::ERR_CONNECTION_REFUSED"`.
+    pub error_text: String,
+    pub canceled: bool,
+    /// Why the request was blocked, e.g. `"inspector"` for aborted routes.
+    pub blocked_reason: Option<String>,
+    pub resource_type: Option<String>,
+}
+
+/// A network request paused by a route, before it is sent.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InterceptedRequest {
+    pub url: String,
+    pub method: String,
+    pub headers: HashMap<String, String>,
+    /// Request body, if any (e.g., for `POST` requests).
+    pub post_data: Option<String>,
+    /// Kind of resource, e.g. `"Document"`, `"Script"`, `"XHR"` or `"Fetch"`.
+    pub resource_type: String,
+    pub frame_id: Option<String>,
+}
+
+/// Decision of a route handler for an intercepted request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RouteAction {
+    /// Send the request, optionally modified.
+    Continue(ContinueOverrides),
+    /// Answer the request with a synthetic response without hitting the network.
+    Fulfill(FulfillResponse),
+    /// Fail the request as if a network error occurred.
+    Abort(AbortReason),
+}
+
+/// Changes applied to a continued request. Unset fields keep their original value.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContinueOverrides {
+    /// New URL; must have the same scheme and is not visible to the page.
+    pub url: Option<String>,
+    pub method: Option<String>,
+    /// Replaces all request headers.
+    pub headers: Option<Vec<(String, String)>>,
+    pub post_data: Option<Vec<u8>>,
+}
+
+/// A synthetic response for an intercepted request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FulfillResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Default for FulfillResponse {
+    fn default() -> Self {
+        Self {
+            status: 200,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+}
+
+/// Network error reported for an aborted request.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum AbortReason {
+    #[default]
+    Failed,
+    Aborted,
+    TimedOut,
+    AccessDenied,
+    ConnectionClosed,
+    ConnectionReset,
+    ConnectionRefused,
+    ConnectionAborted,
+    ConnectionFailed,
+    NameNotResolved,
+    InternetDisconnected,
+    AddressUnreachable,
+    BlockedByClient,
+    BlockedByResponse,
+}
+
+/// Callback deciding how an intercepted request proceeds. Like event handlers, it
+/// runs on the page's actor and should return quickly; the request stays paused
+/// until it does.
+pub type RouteHandler = Box<dyn Fn(InterceptedRequest) -> RouteAction + Send + Sync + 'static>;
+
+/// Identifies a script added with `Page::add_init_script`.
+pub type InitScriptId = String;
+
+/// Future returned by an [`ExposedFunction`]: the value to resolve the page's promise
+/// with, or a message to reject it with.
+pub type ExposedFunctionFuture = Pin<Box<dyn Future<Output = Result<Value, String>> + Send + 'static>>;
+
+/// Rust function callable from page scripts (see `Page::expose_function`). It gets the
+/// JSON-serialized arguments of each call. Unlike event handlers, its future does not
+/// hold up the page's actor, so it may wait on other page operations.
+pub type ExposedFunction = Box<dyn Fn(Vec<Value>) -> ExposedFunctionFuture + Send + Sync + 'static>;
+
+/// Mouse button used for mouse input.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum MouseButton {
+    #[default]
+    Left,
+    Middle,
+    Right,
+    Back,
+    Forward,
+}
+
+/// Modifier keys held during an input event, in addition to those currently
+/// held down via the keyboard methods.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct KeyModifiers {
+    pub alt: bool,
+    pub ctrl: bool,
+    pub meta: bool,
+    pub shift: bool,
+}
+
+/// Options for mouse button input (`mouse_down`, `mouse_up`, `mouse_click`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MouseOptions {
+    /// Button to press. Defaults to `Left`.
+    pub button: MouseButton,
+    /// Number of clicks, e.g. 2 for a double click. Defaults to 1.
+    pub click_count: u32,
+    pub modifiers: KeyModifiers,
+}
+
+impl Default for MouseOptions {
+    fn default() -> Self {
+        Self {
+            button: MouseButton::Left,
+            click_count: 1,
+            modifiers: KeyModifiers::default(),
+        }
+    }
+}
+
 /// Available formats for taking screenshots.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ScreenshotFormat {
     Png,
     Jpeg,
-    // WebP might be added later
+    Webp,
 }
 
 /// Options for taking a screenshot.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct ScreenshotOptions {
-    /// Capture the screenshot from the surface, rather than the view. Defaults to true.
+    /// Capture content outside the viewport (needed for `clip`s reaching beyond it).
+    /// Defaults to false, or true for full-page and element screenshots.
     pub capture_beyond_viewport: Option<bool>,
-    /// Specify a specific area to capture.
+    /// Specify a specific area to capture, in CSS pixels relative to the document.
     pub clip: Option<Clip>,
-    /// Format of the resulting image. Defaults to Png.
+    /// Format of the resulting image. Overrides the `format` argument when set.
     pub format: Option<ScreenshotFormat>,
-    /// Quality of the image (0-100). Only applicable to Jpeg.
+    /// Quality of the image (0-100). Only applicable to Jpeg and Webp.
     pub quality: Option<u8>,
-    /// When true, encodes the screenshot in base64. Defaults to false (returns raw bytes).
-    /// Note: The trait returns `Vec<u8>`, so users might need to encode/decode if using this.
+    /// Capture the screenshot from the surface, rather than the view. Defaults to true.
     pub from_surface: Option<bool>,
-    // Optimize for speed? (CDP specific?)
+    /// Capture the whole scrollable page instead of the viewport. Ignored when `clip` is set.
+    pub full_page: Option<bool>,
+}
+
+/// Options for printing a page to PDF. Lengths are in inches.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct PdfOptions {
+    /// Paper orientation. Defaults to false (portrait).
+    pub landscape: Option<bool>,
+    /// Print the header and footer templates. Defaults to false.
+    pub display_header_footer: Option<bool>,
+    /// Print background graphics. Defaults to false.
+    pub print_background: Option<bool>,
+    /// Scale of the webpage rendering (0.1 - 2.0). Defaults to 1.
+    pub scale: Option<f64>,
+    /// Paper width. Defaults to 8.5 (US Letter).
+    pub paper_width: Option<f64>,
+    /// Paper height. Defaults to 11 (US Letter).
+    pub paper_height: Option<f64>,
+    /// Margins. Each defaults to ~0.4 (1 cm).
+    pub margin_top: Option<f64>,
+    pub margin_bottom: Option<f64>,
+    pub margin_left: Option<f64>,
+    pub margin_right: Option<f64>,
+    /// Pages to print, e.g. `"1-5, 8, 11-13"`. Defaults to all pages.
+    pub page_ranges: Option<String>,
+    /// HTML template for the print header. Elements with the classes `date`, `title`,
+    /// `url`, `pageNumber` and `totalPages` are filled in by the browser.
+    pub header_template: Option<String>,
+    /// HTML template for the print footer, with the same placeholders as the header.
+    pub footer_template: Option<String>,
+    /// Prefer a page size defined by CSS `@page` over `paper_width` / `paper_height`.
+    pub prefer_css_page_size: Option<bool>,
+}
+
+/// Position and size of an element, in CSS pixels relative to the viewport.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct BoundingBox {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
 }
 
 /// Specifies a rectangular area.
@@ -0,0 +1,85 @@
+use crate::common::{Cookie, CookieFilter, DownloadBehavior, Permission};
+use crate::error::ApiError;
+use crate::page::Page;
+use std::fmt::Debug;
+
+/// An isolated browser session created with `Browser::new_context`. Its pages share
+/// cookies, storage and cache only with each other.
+#[async_trait::async_trait]
+pub trait BrowserContext: Send + Sync + Debug {
+    /// Returns the unique identifier for this context.
+    fn id(&self) -> String;
+
+    /// Opens a new page (tab) in this context.
+    ///
+    /// # Returns
+    /// - `Ok(Box<dyn Page>)` containing a handle to the newly created page.
+    /// - `Err(ApiError)` if creating the page fails (e.g., the context was closed).
+    async fn new_page(&self) -> Result<Box<dyn Page>, ApiError>;
+
+    /// Retrieves handles to the open pages of this context.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Box<dyn Page>>)` containing handles to the pages.
+    /// - `Err(ApiError)` if retrieving the page list fails.
+    async fn pages(&self) -> Result<Vec<Box<dyn Page>>, ApiError>;
+
+    // --- Cookies ---
+
+    /// Returns the cookies stored in this context.
+    ///
+    /// # Arguments
+    /// * `urls` - Only return cookies that would be sent to one of these URLs.
+    ///   An empty slice returns all cookies.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Cookie>)` with the matching cookies.
+    /// - `Err(ApiError)` if reading the cookies fails.
+    async fn cookies(&self, urls: &[String]) -> Result<Vec<Cookie>, ApiError>;
+
+    /// Sets cookies, replacing existing ones with the same name, domain and path.
+    ///
+    /// # Returns
+    /// - `Ok(())` once all cookies are set.
+    /// - `Err(ApiError)` if a cookie is rejected (e.g., neither `url` nor `domain` is set).
+    async fn set_cookies(&self, cookies: Vec<Cookie>) -> Result<(), ApiError>;
+
+    /// Deletes the cookies selected by `filter`.
+    async fn delete_cookies(&self, filter: CookieFilter) -> Result<(), ApiError>;
+
+    /// Deletes all cookies stored in this context.
+    async fn clear_cookies(&self) -> Result<(), ApiError>;
+
+    // --- Permissions ---
+
+    /// Grants `permissions` without prompting, replacing earlier grants for the origin.
+    ///
+    /// # Arguments
+    /// * `origin` - The origin to grant them to (e.g., `"https://example.com"`), or
+    ///   `None` for all origins.
+    ///
+    /// # Returns
+    /// - `Ok(())` once the permissions are granted.
+    /// - `Err(ApiError)` if the browser rejects a permission.
+    async fn grant_permissions(
+        &self,
+        permissions: &[Permission],
+        origin: Option<&str>,
+    ) -> Result<(), ApiError>;
+
+    /// Revokes all permissions granted with [`BrowserContext::grant_permissions`].
+    async fn clear_permissions(&self) -> Result<(), ApiError>;
+
+    // --- Downloads ---
+
+    /// Sets what happens to downloads of this context. Its downloads are reported
+    /// through the `Browser` download events.
+    async fn set_download_behavior(&self, behavior: DownloadBehavior) -> Result<(), ApiError>;
+
+    /// Closes all pages of the context and discards its data.
+    ///
+    /// # Returns
+    /// - `Ok(())` once the context is gone.
+    /// - `Err(ApiError)` if closing fails (e.g., the context was already closed).
+    async fn close(&self) -> Result<(), ApiError>;
+}
@@ -0,0 +1,131 @@
+//! Device profiles for `Page::emulate`, and a registry of common phones and tablets.
+
+use serde::{Deserialize, Serialize};
+
+/// Screen and browser characteristics of a device, applied by `Page::emulate`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeviceDescriptor {
+    pub name: String,
+    /// Viewport width in CSS pixels.
+    pub width: u32,
+    /// Viewport height in CSS pixels.
+    pub height: u32,
+    /// Device pixels per CSS pixel (`window.devicePixelRatio`).
+    pub device_scale_factor: f64,
+    /// Behave like a mobile browser: honour `<meta name="viewport">`, use overlay scrollbars.
+    pub is_mobile: bool,
+    /// Report touch support (`navigator.maxTouchPoints`, touch events).
+    pub has_touch: bool,
+    pub user_agent: String,
+}
+
+impl DeviceDescriptor {
+    /// The same device turned sideways.
+    pub fn landscape(&self) -> Self {
+        Self {
+            name: format!("{} landscape", self.name),
+            width: self.height,
+            height: self.width,
+            ..self.clone()
+        }
+    }
+}
+
+const IOS_15_UA: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 15_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.0 Mobile/15E148 Safari/604.1";
+const IOS_16_UA: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 16_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.0 Mobile/15E148 Safari/604.1";
+const IPADOS_15_UA: &str = "Mozilla/5.0 (iPad; CPU OS 15_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/15.0 Mobile/15E148 Safari/604.1";
+
+// Name, width, height, device scale factor, mobile, user agent. All have touch.
+const BUILTIN_DEVICES: &[(&str, u32, u32, f64, bool, &str)] = &[
+    ("iPhone SE", 375, 667, 2.0, true, IOS_15_UA),
+    ("iPhone 13", 390, 844, 3.0, true, IOS_15_UA),
+    ("iPhone 13 Mini", 375, 812, 3.0, true, IOS_15_UA),
+    ("iPhone 14 Pro Max", 430, 932, 3.0, true, IOS_16_UA),
+    (
+        "Pixel 5",
+        393,
+        851,
+        2.75,
+        true,
+        "Mozilla/5.0 (Linux; Android 11; Pixel 5) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36",
+    ),
+    (
+        "Pixel 7",
+        412,
+        915,
+        2.625,
+        true,
+        "Mozilla/5.0 (Linux; Android 14; Pixel 7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36",
+    ),
+    (
+        "Galaxy S9+",
+        320,
+        658,
+        4.5,
+        true,
+        "Mozilla/5.0 (Linux; Android 8.0.0; SM-G965U) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36",
+    ),
+    ("iPad Mini", 768, 1024, 2.0, true, IPADOS_15_UA),
+    ("iPad (gen 9)", 810, 1080, 2.0, true, IPADOS_15_UA),
+    ("iPad Pro 11", 834, 1194, 2.0, true, IPADOS_15_UA),
+    (
+        "Galaxy Tab S4",
+        712,
+        1138,
+        2.25,
+        true,
+        "Mozilla/5.0 (Linux; Android 8.1.0; SM-T837A) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+    ),
+];
+
+/// Device profiles looked up by name (case-insensitively).
+///
+/// `DeviceRegistry::default()` holds the built-in phone and tablet profiles; more can
+/// be added, or built-in ones replaced, with `insert` (e.g. from the `[devices]` tables
+/// of `janus.toml`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceRegistry {
+    devices: Vec<DeviceDescriptor>,
+}
+
+impl DeviceRegistry {
+    /// A registry without any profiles.
+    pub fn empty() -> Self {
+        Self { devices: Vec::new() }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&DeviceDescriptor> {
+        self.devices.iter().find(|device| device.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Adds a profile, replacing any with the same name.
+    pub fn insert(&mut self, device: DeviceDescriptor) {
+        match self.devices.iter_mut().find(|existing| existing.name.eq_ignore_ascii_case(&device.name)) {
+            Some(existing) => *existing = device,
+            None => self.devices.push(device),
+        }
+    }
+
+    /// Names of all profiles, in insertion order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.devices.iter().map(|device| device.name.as_str())
+    }
+}
+
+impl Default for DeviceRegistry {
+    fn default() -> Self {
+        let devices = BUILTIN_DEVICES
+            .iter()
+            .map(|&(name, width, height, device_scale_factor, is_mobile, user_agent)| DeviceDescriptor {
+                name: name.to_string(),
+                width,
+                height,
+                device_scale_factor,
+                is_mobile,
+                has_touch: true,
+                user_agent: user_agent.to_string(),
+            })
+            .collect();
+        Self { devices }
+    }
+}
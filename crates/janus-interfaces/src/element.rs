@@ -0,0 +1,94 @@
+use crate::common::{BoundingBox, ScreenshotFormat, ScreenshotOptions};
+use crate::error::ApiError;
+use std::fmt::Debug;
+use std::path::PathBuf;
+
+/// A handle to a DOM element living in a page.
+///
+/// The handle keeps the underlying remote object alive until [`ElementHandle::dispose`]
+/// is called or the handle is dropped. It becomes invalid when the element's document
+/// goes away (e.g., after navigation); operations then fail with an `ApiError`.
+#[async_trait::async_trait]
+pub trait ElementHandle: Send + Sync + Debug {
+    /// Short human-readable description of the element, e.g. `"button#submit.primary"`.
+    fn description(&self) -> String;
+
+    /// ID of the remote object backing the handle, or `None` once disposed. Used by
+    /// backends to pass the element into page functions (see [`JsArg`](crate::JsArg)).
+    fn remote_object_id(&self) -> Option<String>;
+
+    /// Scrolls the element into view and clicks its center with the left mouse button.
+    ///
+    /// # Returns
+    /// - `Ok(())` once the click has been dispatched.
+    /// - `Err(ApiError)` if the element is detached or has no visible box.
+    async fn click(&self) -> Result<(), ApiError>;
+
+    /// Focuses the element and types `text` into it as keyboard input.
+    async fn type_text(&self, text: &str) -> Result<(), ApiError>;
+
+    /// Focuses the element.
+    async fn focus(&self) -> Result<(), ApiError>;
+
+    /// Scrolls the element into view and moves the mouse over its center.
+    async fn hover(&self) -> Result<(), ApiError>;
+
+    /// Gets the element's `textContent`.
+    ///
+    /// # Returns
+    /// - `Ok(None)` for nodes without text content (e.g., documents).
+    async fn text_content(&self) -> Result<Option<String>, ApiError>;
+
+    /// Gets the element's `innerHTML`.
+    async fn inner_html(&self) -> Result<String, ApiError>;
+
+    /// Gets the value of the attribute `name`.
+    ///
+    /// # Returns
+    /// - `Ok(None)` if the element has no such attribute.
+    async fn get_attribute(&self, name: &str) -> Result<Option<String>, ApiError>;
+
+    /// Gets the element's bounding box relative to the viewport.
+    ///
+    /// # Returns
+    /// - `Ok(None)` if the element is detached or not rendered.
+    async fn bounding_box(&self) -> Result<Option<BoundingBox>, ApiError>;
+
+    /// Whether the element is attached, rendered with a non-empty box and not hidden
+    /// via `visibility`.
+    async fn is_visible(&self) -> Result<bool, ApiError>;
+
+    /// Scrolls the element into view and captures the area of its border box.
+    /// `clip` and `full_page` in `options` are ignored.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)` containing the raw image bytes.
+    /// - `Err(ApiError::InvalidParameters)` if the element is detached or not rendered.
+    async fn screenshot(
+        &self,
+        format: ScreenshotFormat,
+        options: ScreenshotOptions,
+    ) -> Result<Vec<u8>, ApiError>;
+
+    /// Sets the files of an `<input type=file>`, as if chosen by the user, and fires
+    /// its `input` and `change` events. An empty slice clears the selection.
+    ///
+    /// The paths are read by the browser, so they must exist on its machine; relative
+    /// paths resolve against the browser's working directory.
+    ///
+    /// # Returns
+    /// - `Err(ApiError::InvalidParameters)` if the element is not a file input, or several
+    ///   files are given to an input without `multiple`.
+    async fn set_input_files(&self, files: &[PathBuf]) -> Result<(), ApiError>;
+
+    /// Finds the first descendant matching the given CSS selector.
+    ///
+    /// # Returns
+    /// - `Ok(Some(handle))` if a descendant matches.
+    /// - `Ok(None)` if no descendant matches.
+    async fn query_selector(&self, selector: &str) -> Result<Option<Box<dyn ElementHandle>>, ApiError>;
+
+    /// Releases the remote object backing this handle. Further operations on the
+    /// handle fail. Calling it more than once is a no-op.
+    async fn dispose(&self) -> Result<(), ApiError>;
+}
@@ -9,8 +9,9 @@ pub enum ApiError {
     ConnectionFailed(String),
 
     /// An operation did not complete within the specified or default timeout period.
-    #[error("Operation timed out")]
-    Timeout,
+    /// Waits for a condition describe the last value they observed in `last_observed`.
+    #[error("Operation timed out{}", last_observed_suffix(.last_observed))]
+    Timeout { last_observed: Option<String> },
 
     /// An error occurred related to the debugging protocol itself (e.g., malformed message,
     /// unexpected response, command rejected by the browser). Contains details from the
@@ -35,10 +36,37 @@ pub enum ApiError {
     #[error("Target detached or closed")]
     TargetDetached,
 
+    /// A wait was abandoned before its condition was met, e.g. because the page
+    /// navigated to another document.
+    #[error("Wait cancelled: {0}")]
+    WaitCancelled(String),
+
     /// An internal error occurred within the Janus client library. This may indicate a bug.
     #[error("Internal client error: {0}")]
     InternalError(String),
 
+    /// A navigation failed, e.g. with a `net::ERR_*` error, or did not reach the
+    /// requested lifecycle state in time.
+    #[error("Navigation to {url} failed: {reason}")]
+    NavigationFailed { url: String, reason: String },
+
+    /// A script threw an exception, or returned a promise that was rejected.
+    /// `message` is the exception text, e.g. `"Uncaught TypeError: x is not a function"`;
+    /// line and column numbers are 0-based.
+    #[error("Evaluation failed: {message}")]
+    Evaluation {
+        message: String,
+        url: Option<String>,
+        line_number: Option<u32>,
+        column_number: Option<u32>,
+        /// Formatted JavaScript stack trace, if available.
+        stack: Option<String>,
+    },
+
+    /// A download was canceled, or completed without being saved.
+    #[error("Download of {url} failed: {reason}")]
+    DownloadFailed { url: String, reason: String },
+
     /// Error related to launching the browser process.
     #[error("Failed to launch browser: {0}")]
     LaunchError(String),
@@ -46,7 +74,14 @@ pub enum ApiError {
     /// Generic I/O error occurred.
     #[error("I/O error: {0}")]
     IoError(String),
-    // Consider adding more specific common errors as needed, e.g., NavigationFailed
+    // Consider adding more specific common errors as needed
+}
+
+fn last_observed_suffix(last_observed: &Option<String>) -> String {
+    match last_observed {
+        Some(observed) => format!("; last observed: {}", observed),
+        None => String::new(),
+    }
 }
 
 // Allow easy conversion from IO errors if needed at the API boundary
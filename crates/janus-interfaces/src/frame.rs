@@ -0,0 +1,49 @@
+use crate::element::ElementHandle;
+use crate::error::ApiError;
+use serde_json::Value;
+use std::fmt::Debug;
+
+/// A frame of a page: the main frame, or the document of an `<iframe>` (including
+/// cross-origin frames that run in a separate renderer process).
+///
+/// Handles are obtained from [`Page::frames`](crate::Page::frames) and
+/// [`Page::main_frame`](crate::Page::main_frame). A frame keeps its ID across
+/// navigations; once it is removed from the page, operations fail with an `ApiError`.
+///
+/// Element handles of out-of-process frames support DOM operations, but not pointer
+/// input or screenshots; their bounding boxes are relative to the frame.
+#[async_trait::async_trait]
+pub trait Frame: Send + Sync + Debug {
+    /// Returns the frame's ID (the `FrameId` in CDP). The main frame has the page's ID.
+    fn id(&self) -> String;
+
+    /// Returns the ID of the parent frame, or `None` for the main frame.
+    fn parent_id(&self) -> Option<String>;
+
+    /// Gets the frame's `name` attribute, as of its last navigation.
+    async fn name(&self) -> Result<String, ApiError>;
+
+    /// Gets the URL of the frame's document.
+    async fn url(&self) -> Result<String, ApiError>;
+
+    /// Gets the full HTML content of the frame's document.
+    async fn content(&self) -> Result<String, ApiError>;
+
+    /// Evaluates a JavaScript expression in the frame's document.
+    ///
+    /// # Returns
+    /// - `Ok(serde_json::Value)` representing the result of the expression.
+    /// - `Err(ApiError)` if evaluation fails, or the frame has no document yet.
+    async fn evaluate_script(&self, script: &str) -> Result<Value, ApiError>;
+
+    /// Finds the first element of the frame's document matching the CSS selector.
+    ///
+    /// # Returns
+    /// - `Ok(Some(handle))` if an element is found.
+    /// - `Ok(None)` if no element matches the selector.
+    /// - `Err(ApiError)` if the query fails.
+    async fn query_selector(&self, selector: &str) -> Result<Option<Box<dyn ElementHandle>>, ApiError>;
+
+    /// Whether the frame has been removed from the page.
+    async fn is_detached(&self) -> Result<bool, ApiError>;
+}
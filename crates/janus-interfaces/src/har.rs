@@ -0,0 +1,265 @@
+//! HTTP Archive (HAR 1.2) documents, as written by `Page::stop_har_recording` and
+//! served by `Page::route_from_har`. Field names follow the HAR specification, so
+//! the types (de)serialize directly to and from `.har` files with `serde_json`.
+
+use serde::{Deserialize, Serialize};
+
+fn not_available() -> f64 {
+    -1.0
+}
+
+/// Root object of a `.har` file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Har {
+    pub log: HarLog,
+}
+
+/// The recorded pages and requests.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HarLog {
+    pub version: String, // "1.2"
+    pub creator: HarCreator,
+    #[serde(default)]
+    pub pages: Vec<HarPage>,
+    pub entries: Vec<HarEntry>,
+}
+
+/// Application that created the log.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HarCreator {
+    pub name: String,
+    pub version: String,
+}
+
+/// A document loaded in the main frame; entries refer to it through `pageref`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HarPage {
+    pub started_date_time: String, // ISO 8601
+    pub id: String,
+    pub title: String,
+    pub page_timings: HarPageTimings,
+}
+
+/// Milliseconds from the start of the page load, or -1 if not reached.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HarPageTimings {
+    #[serde(default = "not_available")]
+    pub on_content_load: f64,
+    #[serde(default = "not_available")]
+    pub on_load: f64,
+}
+
+impl Default for HarPageTimings {
+    fn default() -> Self {
+        Self {
+            on_content_load: -1.0,
+            on_load: -1.0,
+        }
+    }
+}
+
+/// One request and its response.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HarEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pageref: Option<String>,
+    pub started_date_time: String, // ISO 8601
+    /// Total time in milliseconds: the sum of the known `timings`.
+    pub time: f64,
+    pub request: HarRequest,
+    pub response: HarResponse,
+    #[serde(default)]
+    pub cache: HarCache,
+    pub timings: HarTimings,
+    #[serde(rename = "serverIPAddress", default, skip_serializing_if = "Option::is_none")]
+    pub server_ip_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection: Option<String>, // Remote port
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HarRequest {
+    pub method: String,
+    pub url: String,
+    pub http_version: String,
+    #[serde(default)]
+    pub cookies: Vec<HarCookie>,
+    #[serde(default)]
+    pub headers: Vec<HarHeader>,
+    #[serde(default)]
+    pub query_string: Vec<HarQueryParam>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_data: Option<HarPostData>,
+    pub headers_size: i64, // -1 if unknown
+    pub body_size: i64,
+}
+
+/// A response. Requests that failed without one have status 0 and the network
+/// error in `error`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HarResponse {
+    pub status: u16,
+    pub status_text: String,
+    pub http_version: String,
+    #[serde(default)]
+    pub cookies: Vec<HarCookie>,
+    #[serde(default)]
+    pub headers: Vec<HarHeader>,
+    pub content: HarContent,
+    #[serde(rename = "redirectURL", default)]
+    pub redirect_url: String,
+    pub headers_size: i64, // -1 if unknown
+    pub body_size: i64,    // -1 if unknown
+    #[serde(rename = "_error", default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HarHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HarQueryParam {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HarCookie {
+    pub name: String,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_only: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secure: Option<bool>,
+}
+
+/// Body of a request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HarPostData {
+    pub mime_type: String,
+    #[serde(default)]
+    pub text: String,
+}
+
+/// Body of a response. `text` is only present if the body was recorded; binary
+/// bodies are base64 encoded, with `encoding` set to `"base64"`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HarContent {
+    pub size: i64,
+    pub mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+}
+
+/// Cache state of an entry. Not recorded; present because HAR requires it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct HarCache {}
+
+/// Phases of a request in milliseconds. `blocked`, `dns`, `connect` and `ssl` are
+/// -1 when they did not happen (e.g., a reused connection); `connect` includes `ssl`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct HarTimings {
+    #[serde(default = "not_available")]
+    pub blocked: f64,
+    #[serde(default = "not_available")]
+    pub dns: f64,
+    #[serde(default = "not_available")]
+    pub connect: f64,
+    pub send: f64,
+    pub wait: f64,
+    pub receive: f64,
+    #[serde(default = "not_available")]
+    pub ssl: f64,
+}
+
+impl Default for HarTimings {
+    fn default() -> Self {
+        Self {
+            blocked: -1.0,
+            dns: -1.0,
+            connect: -1.0,
+            send: 0.0,
+            wait: 0.0,
+            receive: 0.0,
+            ssl: -1.0,
+        }
+    }
+}
+
+/// Options for `Page::start_har_recording`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct HarRecordOptions {
+    /// Store response bodies in `content.text`. Off by default.
+    pub content: bool,
+    /// Only record requests whose URL matches this pattern (`*` and `?` wildcards, as
+    /// in `Page::route`). All requests are recorded by default.
+    pub url_pattern: Option<String>,
+}
+
+/// How the URL of a request is compared with the URLs in a HAR. Fragments are
+/// always ignored.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum HarUrlMatch {
+    /// The URLs must be equal.
+    #[default]
+    Exact,
+    /// The query parameters must be equal, in any order.
+    IgnoreQueryOrder,
+    /// Only everything before the query string must be equal.
+    IgnoreQuery,
+}
+
+/// What happens to a replayed request without a matching HAR entry.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum HarNotFound {
+    /// Fail the request, keeping the page offline.
+    #[default]
+    Abort,
+    /// Send the request to the network.
+    Fallback,
+}
+
+/// Options for `Page::route_from_har`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HarReplayOptions {
+    /// Requests served from the HAR (same syntax as `Page::route`); all by default.
+    pub url_pattern: Option<String>,
+    pub url_match: HarUrlMatch,
+    /// Require the HTTP method to match. On by default.
+    pub match_method: bool,
+    /// Require the request body to match the recorded `postData`. On by default.
+    pub match_body: bool,
+    pub not_found: HarNotFound,
+}
+
+impl Default for HarReplayOptions {
+    fn default() -> Self {
+        Self {
+            url_pattern: None,
+            url_match: HarUrlMatch::default(),
+            match_method: true,
+            match_body: true,
+            not_found: HarNotFound::default(),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::element::ElementHandle;
+use crate::error::ApiError;
+use serde::de::value::{Error as ValueError, F64Deserializer, StringDeserializer};
+use serde::de::{DeserializeOwned, IntoDeserializer};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+/// A handle to a JavaScript value living in a page, as returned by
+/// [`Page::call_function_handle`](crate::Page::call_function_handle).
+///
+/// Objects are kept alive until [`JsHandle::dispose`] is called or the handle is
+/// dropped; primitive values are held by the handle itself.
+#[async_trait::async_trait]
+pub trait JsHandle: Send + Sync + Debug {
+    /// Short human-readable description of the value, e.g. `"Array(3)"` or `"42"`.
+    fn description(&self) -> String;
+
+    /// ID of the remote object backing the handle, or `None` for primitive values and
+    /// disposed handles. Used by backends to pass the handle back into the page.
+    fn remote_object_id(&self) -> Option<String>;
+
+    /// Gets the property `name` of the value.
+    ///
+    /// # Returns
+    /// - `Ok(handle)` to the property's value (`undefined` if it does not exist).
+    /// - `Err(ApiError::InvalidParameters)` if the value is not an object.
+    async fn get_property(&self, name: &str) -> Result<Box<dyn JsHandle>, ApiError>;
+
+    /// Gets the own enumerable properties of the value, by name.
+    async fn get_properties(&self) -> Result<HashMap<String, Box<dyn JsHandle>>, ApiError>;
+
+    /// Serializes the value to JSON in the page.
+    ///
+    /// # Returns
+    /// - `Err(ApiError)` if the value cannot be serialized (e.g., it is cyclic).
+    async fn json_value(&self) -> Result<Value, ApiError>;
+
+    /// Releases the remote object backing this handle. Further operations on the
+    /// handle fail. Calling it more than once is a no-op.
+    async fn dispose(&self) -> Result<(), ApiError>;
+}
+
+/// An argument to [`Page::call_function`](crate::Page::call_function): a JSON value,
+/// or a handle whose live object is passed as is.
+///
+/// Handles must belong to the page's main frame.
+#[derive(Debug, Clone)]
+pub enum JsArg<'a> {
+    Value(Value),
+    Element(&'a dyn ElementHandle),
+    Handle(&'a dyn JsHandle),
+}
+
+impl From<Value> for JsArg<'_> {
+    fn from(value: Value) -> Self {
+        JsArg::Value(value)
+    }
+}
+
+impl<'a> From<&'a dyn ElementHandle> for JsArg<'a> {
+    fn from(element: &'a dyn ElementHandle) -> Self {
+        JsArg::Element(element)
+    }
+}
+
+impl<'a> From<&'a Box<dyn ElementHandle>> for JsArg<'a> {
+    fn from(element: &'a Box<dyn ElementHandle>) -> Self {
+        JsArg::Element(element.as_ref())
+    }
+}
+
+impl<'a> From<&'a dyn JsHandle> for JsArg<'a> {
+    fn from(handle: &'a dyn JsHandle) -> Self {
+        JsArg::Handle(handle)
+    }
+}
+
+impl<'a> From<&'a Box<dyn JsHandle>> for JsArg<'a> {
+    fn from(handle: &'a Box<dyn JsHandle>) -> Self {
+        JsArg::Handle(handle.as_ref())
+    }
+}
+
+/// A value returned by a script. Values JSON cannot represent are kept apart, so that
+/// they survive [`JsValue::deserialize`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsValue {
+    /// `undefined`.
+    Undefined,
+    /// `NaN`, `Infinity`, `-Infinity` or `-0`.
+    Number(f64),
+    /// A `bigint`, as decimal digits.
+    BigInt(String),
+    /// Any other value. `Date`s are ISO 8601 strings (`null` if invalid); values nested
+    /// in objects and arrays follow `JSON.stringify`.
+    Json(Value),
+}
+
+impl JsValue {
+    /// Deserializes the value into `T`. `undefined` deserializes like `null` (e.g., into
+    /// `Option<T>` or `()`); a `bigint` into integer types it fits in, or into `String`.
+    pub fn deserialize<T: DeserializeOwned>(self) -> Result<T, ApiError> {
+        let result = match self {
+            JsValue::Undefined => serde_json::from_value(Value::Null).map_err(|e| e.to_string()),
+            JsValue::Json(value) => serde_json::from_value(value).map_err(|e| e.to_string()),
+            JsValue::Number(number) => {
+                let deserializer: F64Deserializer<ValueError> = number.into_deserializer();
+                T::deserialize(deserializer).map_err(|e| e.to_string())
+            }
+            JsValue::BigInt(digits) => {
+                // Serde's integer visitors only take 128-bit values for 128-bit types.
+                let as_integer = if let Ok(integer) = digits.parse::<i64>() {
+                    T::deserialize(IntoDeserializer::<ValueError>::into_deserializer(integer)).ok()
+                } else if let Ok(integer) = digits.parse::<u64>() {
+                    T::deserialize(IntoDeserializer::<ValueError>::into_deserializer(integer)).ok()
+                } else if let Ok(integer) = digits.parse::<i128>() {
+                    T::deserialize(IntoDeserializer::<ValueError>::into_deserializer(integer)).ok()
+                } else {
+                    None
+                };
+                match as_integer {
+                    Some(value) => Ok(value),
+                    None => {
+                        let deserializer: StringDeserializer<ValueError> = digits.into_deserializer();
+                        T::deserialize(deserializer).map_err(|e| e.to_string())
+                    }
+                }
+            }
+        };
+        result.map_err(|e| ApiError::InvalidParameters(format!("Cannot deserialize evaluation result: {}", e)))
+    }
+}
@@ -7,18 +7,90 @@
 
 mod browser;
 mod common;
+mod context;
+mod device;
+mod element;
 mod error;
+mod frame;
+mod har;
+mod js_handle;
+mod locator;
 mod page;
+mod wait;
 
 pub use browser::*;
 pub use common::*;
+pub use context::*;
+pub use device::*;
+pub use element::*;
 pub use error::*;
+pub use frame::*;
+pub use har::*;
+pub use js_handle::*;
+pub use locator::*;
 pub use page::*;
+pub use wait::*;
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn test_device_registry() {
+        let mut devices = DeviceRegistry::default();
+        let iphone = devices.get("iphone 13").cloned().expect("built-in profile");
+        assert_eq!((iphone.width, iphone.height, iphone.device_scale_factor), (390, 844, 3.0));
+        assert!(iphone.is_mobile && iphone.has_touch);
+        assert_eq!(iphone.landscape().width, 844);
+
+        let count = devices.names().count();
+        devices.insert(DeviceDescriptor { width: 400, ..iphone.clone() });
+        devices.insert(DeviceDescriptor { name: "Kiosk".into(), is_mobile: false, ..iphone.clone() });
+        assert_eq!(devices.names().count(), count + 1);
+        assert_eq!(devices.get("iPhone 13").map(|device| device.width), Some(400));
+        assert!(devices.get("Kiosk").is_some());
+        assert!(DeviceRegistry::empty().get("iPhone 13").is_none());
+    }
+
+    #[test]
+    fn test_js_value_deserialize() {
+        assert_eq!(JsValue::Undefined.deserialize::<Option<u32>>().unwrap(), None);
+        assert!(JsValue::Number(f64::NAN).deserialize::<f64>().unwrap().is_nan());
+        assert!(JsValue::Number(-0.0).deserialize::<f64>().unwrap().is_sign_negative());
+        assert_eq!(JsValue::BigInt("-12".into()).deserialize::<i64>().unwrap(), -12);
+        assert_eq!(JsValue::BigInt("1".repeat(30)).deserialize::<i128>().unwrap(), "1".repeat(30).parse().unwrap());
+        let huge = "1".repeat(40);
+        assert_eq!(JsValue::BigInt(huge.clone()).deserialize::<String>().unwrap(), huge);
+        assert!(JsValue::BigInt(huge).deserialize::<u64>().is_err());
+        let point: (i32, i32) = JsValue::Json(serde_json::json!([1, 2])).deserialize().unwrap();
+        assert_eq!(point, (1, 2));
+        assert!(matches!(
+            JsValue::Json(serde_json::json!("x")).deserialize::<bool>(),
+            Err(ApiError::InvalidParameters(_))
+        ));
+    }
+
+    #[test]
+    fn test_wait_until() {
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+        let mut checks = 0;
+        let value = runtime.block_on(wait_until(1_000, || {
+            checks += 1;
+            let count = checks;
+            async move { Ok(if count == 3 { Condition::Met(count * 10) } else { Condition::unmet(count) }) }
+        }));
+        assert_eq!(value.unwrap(), 30);
+
+        let timed_out = runtime.block_on(wait_until(100, || async { Ok(Condition::<()>::unmet("pending")) }));
+        let Err(error) = timed_out else { panic!("expected a timeout") };
+        assert_eq!(error.to_string(), r#"Operation timed out; last observed: "pending""#);
+
+        let failed = runtime.block_on(wait_until(100, || async { Err::<Condition<()>, _>(ApiError::TargetDetached) }));
+        assert!(matches!(failed, Err(ApiError::TargetDetached)));
+    }
 }
@@ -0,0 +1,124 @@
+use crate::element::ElementHandle;
+use crate::error::ApiError;
+use std::fmt::Debug;
+
+/// Timeout of locator actions, unless set with [`Locator::with_timeout`].
+pub const DEFAULT_LOCATOR_TIMEOUT_MS: u64 = 30_000;
+
+/// Narrows the elements of a locator, see [`Locator::filter`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocatorFilter {
+    /// Keeps elements whose text contains this, ignoring case and runs of whitespace.
+    pub has_text: Option<String>,
+    /// Keeps elements that have a descendant matching this selector.
+    pub has: Option<String>,
+}
+
+/// State to wait for with [`Locator::wait_for`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ElementState {
+    /// The element is in the DOM.
+    Attached,
+    /// No element matches.
+    Detached,
+    /// The element is in the DOM and visible.
+    #[default]
+    Visible,
+    /// No element matches, or it is not visible.
+    Hidden,
+}
+
+/// A lazy reference to the elements matching a selector in the page's main frame.
+///
+/// Nothing is looked up until an action runs; each action resolves the selector again,
+/// so a locator keeps working when the page re-renders its elements. Actions wait up
+/// to the locator's timeout for exactly one element to match and to be actionable:
+///
+/// | Action | Waits until the element is |
+/// |---|---|
+/// | `click` | attached, visible, stable (not animating), enabled and receiving pointer events |
+/// | `hover` | attached, visible, stable and receiving pointer events |
+/// | `fill` | attached, visible, enabled and editable |
+/// | others | attached |
+///
+/// Actions fail right away with `ApiError::InvalidParameters` if several elements
+/// match; narrow the locator with [`Locator::nth`] or [`Locator::filter`].
+///
+/// # Selectors
+///
+/// - `css=form .submit`: a CSS selector. Selectors without an engine prefix are CSS,
+///   unless they start with `//` (XPath) or a quote (text).
+/// - `xpath=//button[@type="submit"]`: an XPath expression.
+/// - `text=Sign in`: the smallest elements whose text contains `Sign in`, ignoring case
+///   and runs of whitespace; `text="Sign in"` matches the full text exactly.
+/// - `role=button[name="Sign in"]`: elements with an explicit or implicit ARIA role,
+///   optionally filtered by their accessible name (contained, ignoring case).
+/// - `form#login >> text=Sign in`: chained selectors; each one searches within the
+///   elements matched by the previous one.
+#[async_trait::async_trait]
+pub trait Locator: Send + Sync + Debug {
+    /// The locator's selector, including `nth` and `filter` steps, for messages.
+    fn description(&self) -> String;
+
+    /// Elements matching `selector` within the elements of this locator.
+    fn locator(&self, selector: &str) -> Box<dyn Locator>;
+
+    /// The element at `index`; negative indices count from the end (`-1` is the last).
+    fn nth(&self, index: i32) -> Box<dyn Locator>;
+
+    /// The first matching element.
+    fn first(&self) -> Box<dyn Locator>;
+
+    /// The last matching element.
+    fn last(&self) -> Box<dyn Locator>;
+
+    /// The matching elements that pass `filter`.
+    fn filter(&self, filter: LocatorFilter) -> Box<dyn Locator>;
+
+    /// The same locator, with actions waiting at most `timeout_ms`.
+    fn with_timeout(&self, timeout_ms: u64) -> Box<dyn Locator>;
+
+    /// Counts the matching elements, without waiting.
+    async fn count(&self) -> Result<usize, ApiError>;
+
+    /// Clicks the center of the element with the left mouse button.
+    ///
+    /// # Returns
+    /// - `Err(ApiError::Timeout { last_observed })` if the element is not actionable in
+    ///   time; `last_observed` tells which check failed last, e.g. `"not visible"`.
+    async fn click(&self) -> Result<(), ApiError>;
+
+    /// Moves the mouse over the center of the element.
+    async fn hover(&self) -> Result<(), ApiError>;
+
+    /// Replaces the value of an `<input>`, `<textarea>` or `contenteditable` element
+    /// with `text`, as a single text input.
+    async fn fill(&self, text: &str) -> Result<(), ApiError>;
+
+    /// Focuses the element and types `text` into it, one key press per character.
+    async fn type_text(&self, text: &str) -> Result<(), ApiError>;
+
+    /// Focuses the element.
+    async fn focus(&self) -> Result<(), ApiError>;
+
+    /// Gets the element's `textContent`.
+    async fn text_content(&self) -> Result<Option<String>, ApiError>;
+
+    /// Gets the element's `innerHTML`.
+    async fn inner_html(&self) -> Result<String, ApiError>;
+
+    /// Gets the value of the attribute `name`, or `None` if the element has none.
+    async fn get_attribute(&self, name: &str) -> Result<Option<String>, ApiError>;
+
+    /// Whether an element matches and is visible, without waiting.
+    async fn is_visible(&self) -> Result<bool, ApiError>;
+
+    /// Whether the element is enabled (not `disabled`, nor `aria-disabled`).
+    async fn is_enabled(&self) -> Result<bool, ApiError>;
+
+    /// Waits for the element to be attached and returns a handle to it.
+    async fn element_handle(&self) -> Result<Box<dyn ElementHandle>, ApiError>;
+
+    /// Waits until the locator reaches `state`.
+    async fn wait_for(&self, state: ElementState) -> Result<(), ApiError>;
+}
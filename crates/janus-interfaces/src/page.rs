@@ -1,8 +1,18 @@
 use crate::common::*;
+use crate::device::DeviceDescriptor;
+use crate::element::ElementHandle;
+use crate::frame::Frame;
 use crate::error::ApiError;
+use crate::har::{Har, HarRecordOptions, HarReplayOptions};
+use crate::js_handle::{JsArg, JsHandle, JsValue};
+use crate::locator::Locator;
+use crate::wait::Polling;
 use async_trait::async_trait;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::fmt::Debug;
+use std::path::PathBuf;
+use tokio::io::AsyncWrite;
 
 /// Represents a single browser page, tab, or other target (like a WebWorker).
 ///
@@ -20,6 +30,25 @@ pub trait Page: Send + Sync + Debug {
     /// - `Err(ApiError)` if navigation fails (e.g., invalid URL, network error).
     async fn navigate(&self, url: &str) -> Result<(), ApiError>;
 
+    /// Navigates the page to the specified URL and waits for the navigation to finish.
+    ///
+    /// # Arguments
+    /// * `url` - The URL to navigate to.
+    /// * `wait_until` - Lifecycle state at which the navigation counts as finished.
+    /// * `timeout_ms` - Maximum time in milliseconds for the whole navigation.
+    ///
+    /// # Returns
+    /// - `Ok(result)` with the loader ID, final URL and HTTP status once `wait_until` is reached.
+    /// - `Err(ApiError::NavigationFailed)` on a network error (`net::ERR_*`) or when the
+    ///   timeout is reached first.
+    /// - `Err(ApiError)` for other failures.
+    async fn navigate_and_wait(
+        &self,
+        url: &str,
+        wait_until: WaitUntil,
+        timeout_ms: u64,
+    ) -> Result<NavigationResult, ApiError>;
+
     /// Reloads the current page.
     ///
     /// # Returns
@@ -48,6 +77,11 @@ pub trait Page: Send + Sync + Debug {
     /// - `Err(ApiError)` if closing fails.
     async fn close(&self) -> Result<(), ApiError>;
 
+    /// Closes the page like [`Page::close`], optionally running its `beforeunload`
+    /// handlers first. In that case the call returns once closing was requested; the
+    /// page may stay open if the `beforeunload` dialog is dismissed.
+    async fn close_with_options(&self, options: PageCloseOptions) -> Result<(), ApiError>;
+
     /// Returns the unique identifier for this page/target within the browser session.
     /// The format might be protocol-specific (e.g., TargetID in CDP).
     fn id(&self) -> String;
@@ -69,47 +103,92 @@ pub trait Page: Send + Sync + Debug {
     /// - `Err(ApiError)` if evaluation fails (e.g., script error, serialization issue).
     async fn evaluate_script(&self, script: &str) -> Result<Value, ApiError>;
 
+    /// Evaluates a JavaScript expression, keeping values JSON cannot represent
+    /// (`undefined`, `NaN`, `bigint`, `Date`, ...). See [`PageExt::evaluate`] for a typed
+    /// result.
+    ///
+    /// # Returns
+    /// - `Err(ApiError::Evaluation)` if the script throws or its promise is rejected.
+    async fn evaluate_value(&self, script: &str) -> Result<JsValue, ApiError>;
+
     /// Calls a JavaScript function defined in the page context.
     ///
     /// # Arguments
     /// * `function_declaration` - A string containing the function declaration (e.g., `function(a, b) { return a + b; }`).
-    /// * `args` - A vector of JSON values to pass as arguments to the function.
+    /// * `args` - Arguments to pass to the function: JSON values, or element and JS handles,
+    ///   which are passed as the live objects they refer to.
     ///
     /// # Returns
-    /// - `Ok(serde_json::Value)` representing the function's return value.
-    /// - `Err(ApiError)` if calling the function fails.
+    /// - `Ok(serde_json::Value)` representing the function's return value (awaited if it is a promise).
+    /// - `Err(ApiError)` if calling the function fails, or a handle is disposed.
     async fn call_function(
         &self,
         function_declaration: &str,
-        args: Vec<Value>,
+        args: Vec<JsArg<'_>>,
     ) -> Result<Value, ApiError>;
 
+    /// Like [`Page::call_function`], but returns a handle to the function's return value
+    /// instead of serializing it, e.g., to keep working with a DOM node or a class instance.
+    async fn call_function_handle(
+        &self,
+        function_declaration: &str,
+        args: Vec<JsArg<'_>>,
+    ) -> Result<Box<dyn JsHandle>, ApiError>;
+
     /// Finds the first element matching the given CSS selector.
     ///
     /// # Arguments
     /// * `selector` - The CSS selector to query for.
     ///
     /// # Returns
-    /// - `Ok(Some(ElementHandle))` if an element is found.
+    /// - `Ok(Some(handle))` if an element is found.
     /// - `Ok(None)` if no element matches the selector.
     /// - `Err(ApiError)` if the query fails.
-    async fn query_selector(&self, selector: &str) -> Result<Option<ElementHandle>, ApiError>;
+    async fn query_selector(&self, selector: &str) -> Result<Option<Box<dyn ElementHandle>>, ApiError>;
+
+    /// Creates a lazy, auto-waiting [`Locator`] for `selector`, which may use the `css=`,
+    /// `xpath=`, `text=` and `role=` engines and chaining with `>>`.
+    fn locator(&self, selector: &str) -> Box<dyn Locator>;
 
     /// Waits for an element matching the selector to appear in the DOM.
     ///
     /// # Arguments
-    /// * `selector` - The CSS selector to wait for.
+    /// * `selector` - The selector to wait for, in the syntax of [`Page::locator`]. The
+    ///   first matching element is returned.
     /// * `timeout_ms` - Maximum time in milliseconds to wait.
     ///
     /// # Returns
-    /// - `Ok(ElementHandle)` when the element is found within the timeout.
+    /// - `Ok(handle)` when the element is found within the timeout.
     /// - `Err(ApiError::Timeout)` if the timeout is reached before the element is found.
     /// - `Err(ApiError)` for other failures.
     async fn wait_for_selector(
         &self,
         selector: &str,
         timeout_ms: u64,
-    ) -> Result<ElementHandle, ApiError>;
+    ) -> Result<Box<dyn ElementHandle>, ApiError>;
+
+    /// Waits for a JavaScript predicate to return a truthy value in the page.
+    ///
+    /// # Arguments
+    /// * `function_declaration` - The predicate, e.g. `"(min) => window.items.length >= min"`.
+    ///   It may be async; it is called with `args` as in [`Page::call_function`].
+    /// * `polling` - When the predicate runs again.
+    /// * `timeout_ms` - Maximum time in milliseconds to wait.
+    ///
+    /// # Returns
+    /// - `Ok(value)` with the truthy value, serialized to JSON.
+    /// - `Err(ApiError::Timeout { last_observed })` if the predicate stays falsy; the last
+    ///   value it returned is in `last_observed`.
+    /// - `Err(ApiError::WaitCancelled(_))` if the page navigates to another document, and
+    ///   `Err(ApiError::TargetDetached)` if it closes, before the predicate holds.
+    /// - `Err(ApiError::Evaluation { .. })` if the predicate throws.
+    async fn wait_for_function(
+        &self,
+        function_declaration: &str,
+        args: Vec<JsArg<'_>>,
+        polling: Polling,
+        timeout_ms: u64,
+    ) -> Result<Value, ApiError>;
 
     /// Gets the current URL of the page.
     ///
@@ -125,11 +204,11 @@ pub trait Page: Send + Sync + Debug {
     /// - `Err(ApiError)` if retrieving the title fails.
     async fn title(&self) -> Result<String, ApiError>;
 
-    /// Takes a screenshot of the current page viewport or a specified area.
+    /// Takes a screenshot of the current page viewport, the full page or a specified area.
     ///
     /// # Arguments
-    /// * `format` - The desired image format (Png, Jpeg).
-    /// * `options` - Additional options for the screenshot (quality, clip, etc.).
+    /// * `format` - The desired image format (Png, Jpeg, Webp).
+    /// * `options` - Additional options for the screenshot (quality, clip, full page, etc.).
     ///
     /// # Returns
     /// - `Ok(Vec<u8>)` containing the raw image bytes in the specified format.
@@ -140,11 +219,423 @@ pub trait Page: Send + Sync + Debug {
         options: ScreenshotOptions,
     ) -> Result<Vec<u8>, ApiError>;
 
-    // --- Input Methods (Placeholder - Defined but not implemented in Phase 1) ---
-    // async fn click(&self, selector: &str) -> Result<(), ApiError>;
-    // async fn type_text(&self, selector: &str, text: &str) -> Result<(), ApiError>;
+    /// Prints the page to PDF using the print media type.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)` containing the PDF document.
+    /// - `Err(ApiError)` if printing fails (e.g., invalid page ranges).
+    async fn pdf(&self, options: PdfOptions) -> Result<Vec<u8>, ApiError>;
+
+    /// Prints the page to PDF and streams the document into `writer` chunk by chunk,
+    /// without holding it in memory as a whole.
+    ///
+    /// # Returns
+    /// - `Ok(bytes)` with the number of bytes written.
+    /// - `Err(ApiError)` if printing or writing fails.
+    async fn pdf_to_writer(
+        &self,
+        options: PdfOptions,
+        writer: &mut (dyn AsyncWrite + Send + Unpin),
+    ) -> Result<u64, ApiError>;
+
+    // --- Frames ---
+
+    /// Returns the page's main frame.
+    async fn main_frame(&self) -> Result<Box<dyn Frame>, ApiError>;
+
+    /// Returns all frames of the page, parents before their children, starting with the
+    /// main frame. Cross-origin (out-of-process) frames are included.
+    async fn frames(&self) -> Result<Vec<Box<dyn Frame>>, ApiError>;
+
+    // --- Scripts ---
+
+    /// Adds a script that runs in each new document of the page and its frames, before
+    /// the document's own scripts, e.g. to stub `Date.now`. It does not run in the
+    /// current document, nor in out-of-process (cross-origin) frames.
+    ///
+    /// # Returns
+    /// - `Ok(InitScriptId)` to pass to [`Page::remove_init_script`].
+    async fn add_init_script(&self, source: &str) -> Result<InitScriptId, ApiError>;
+
+    /// Removes a script added with [`Page::add_init_script`] from future documents.
+    ///
+    /// # Returns
+    /// - `Err(ApiError::InvalidParameters)` if the ID is unknown.
+    async fn remove_init_script(&self, id: InitScriptId) -> Result<(), ApiError>;
+
+    /// Installs `window[name]` in the current document and every new one of the page
+    /// and its frames (except out-of-process ones): an async function that calls
+    /// `function` with its (JSON-serialized) arguments, and resolves to its result or
+    /// rejects with its error message.
+    ///
+    /// ```ignore
+    /// page.expose_function("reportResult", Box::new(|args| Box::pin(async move {
+    ///     println!("page reported {:?}", args);
+    ///     Ok(Value::Bool(true))
+    /// }))).await?;
+    /// // In the page: `const ok = await window.reportResult({ passed: 3 });`
+    /// ```
+    ///
+    /// # Returns
+    /// - `Err(ApiError::InvalidParameters)` if a function named `name` is already exposed.
+    async fn expose_function(&self, name: &str, function: ExposedFunction) -> Result<(), ApiError>;
+
+    // --- Cookies ---
+
+    /// Returns the cookies visible to this page.
+    ///
+    /// # Arguments
+    /// * `urls` - Only return cookies that would be sent to one of these URLs.
+    ///   An empty slice means the page's current URL (and those of its frames).
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Cookie>)` with the matching cookies.
+    /// - `Err(ApiError)` if reading the cookies fails.
+    async fn cookies(&self, urls: &[String]) -> Result<Vec<Cookie>, ApiError>;
+
+    /// Sets cookies, replacing existing ones with the same name, domain and path.
+    ///
+    /// # Returns
+    /// - `Ok(())` once all cookies are set.
+    /// - `Err(ApiError)` if a cookie is rejected (e.g., neither `url` nor `domain` is set
+    ///   and the page has no URL to fall back to).
+    async fn set_cookies(&self, cookies: Vec<Cookie>) -> Result<(), ApiError>;
+
+    /// Deletes the cookies selected by `filter`.
+    /// Without `url` and `domain`, the page's current URL is used.
+    async fn delete_cookies(&self, filter: CookieFilter) -> Result<(), ApiError>;
+
+    /// Deletes all cookies visible to this page; cookies of other sites are kept.
+    async fn clear_cookies(&self) -> Result<(), ApiError>;
+
+    // --- Network ---
+
+    /// Returns the body of a finished response.
+    ///
+    /// # Arguments
+    /// * `request_id` - ID from a [`NetworkResponse`] or [`RequestFinished`] event.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)` with the decoded body.
+    /// - `Err(ApiError)` if the body is unavailable (e.g., still loading, a redirect,
+    ///   or evicted from the browser's buffer).
+    async fn response_body(&self, request_id: &str) -> Result<Vec<u8>, ApiError>;
+
+    // --- Request Interception ---
+
+    /// Intercepts requests whose URL matches `pattern` and lets `handler` continue,
+    /// modify, fulfil or abort each of them. In the pattern, `*` matches any run of
+    /// characters and `?` a single character (e.g. `"*/api/*"`). When several routes
+    /// match, the most recently added one handles the request.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unroute`], once interception is active.
+    /// - `Err(ApiError)` if interception could not be enabled.
+    async fn route(&self, pattern: &str, handler: RouteHandler) -> Result<SubscriptionId, ApiError>;
 
-    // --- Event Subscription (Placeholder - Complex, deferred beyond Phase 1) ---
-    // async fn on_load(&self, handler: Box<dyn Fn() + Send + Sync + 'static>) -> Result<SubscriptionId, ApiError>;
-    // async fn on_console_message(&self, handler: Box<dyn Fn(ConsoleMessage) + Send + Sync + 'static>) -> Result<SubscriptionId, ApiError>;
+    /// Removes a route added with [`Page::route`]. Interception stops with the last route.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the route was removed.
+    /// - `Err(ApiError::InvalidParameters)` if the ID is unknown.
+    async fn unroute(&self, id: SubscriptionId) -> Result<(), ApiError>;
+
+    // --- HAR ---
+
+    /// Starts recording the page's network traffic for [`Page::stop_har_recording`].
+    ///
+    /// # Returns
+    /// - `Ok(())` once recording has started.
+    /// - `Err(ApiError::InvalidParameters)` if a recording is already in progress.
+    async fn start_har_recording(&self, options: HarRecordOptions) -> Result<(), ApiError>;
+
+    /// Stops the recording and returns the requests seen since it started. Requests
+    /// still waiting for their response are left out.
+    ///
+    /// # Returns
+    /// - `Ok(Har)` with one page per main frame document and one entry per request
+    ///   (redirects included).
+    /// - `Err(ApiError::InvalidParameters)` if no recording is in progress.
+    async fn stop_har_recording(&self) -> Result<Har, ApiError>;
+
+    /// Serves requests from a recorded HAR instead of the network. Each request gets
+    /// the first matching entry not served yet, or the last matching one once all have
+    /// been served, so repeated calls to an endpoint replay in order.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unroute`], once replay is active.
+    /// - `Err(ApiError)` if interception could not be enabled.
+    async fn route_from_har(
+        &self,
+        har: Har,
+        options: HarReplayOptions,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    // --- Emulation ---
+
+    /// Emulates a device: viewport size, device scale factor, mobile mode, touch
+    /// support and user agent, in one call. Stays in effect across navigations.
+    ///
+    /// # Errors
+    /// `ApiError::InvalidParameters` if the size or scale factor is zero.
+    async fn emulate(&self, device: DeviceDescriptor) -> Result<(), ApiError>;
+
+    /// Overrides the time zone seen by scripts (`Date`, `Intl`).
+    ///
+    /// # Arguments
+    /// * `timezone_id` - An IANA time zone ID (e.g., `"Europe/Berlin"`), or `None` to
+    ///   restore the system time zone.
+    ///
+    /// # Errors
+    /// `ApiError::ProtocolError` if the browser does not know the time zone.
+    async fn set_timezone(&self, timezone_id: Option<&str>) -> Result<(), ApiError>;
+
+    /// Overrides the locale used by `Intl` and locale-aware formatting.
+    ///
+    /// # Arguments
+    /// * `locale` - A BCP 47 tag (e.g., `"de-DE"`), or `None` to restore the default.
+    async fn set_locale(&self, locale: Option<&str>) -> Result<(), ApiError>;
+
+    /// Overrides the position reported by the Geolocation API, or restores the real
+    /// one with `None`. Setting a position also grants `Permission::Geolocation` to all
+    /// origins of the page's browser context.
+    ///
+    /// # Errors
+    /// `ApiError::InvalidParameters` if a coordinate is out of range.
+    async fn set_geolocation(&self, geolocation: Option<Geolocation>) -> Result<(), ApiError>;
+
+    /// Emulates a CSS media type and user preference media features, replacing the
+    /// previous emulation. `MediaEmulation::default()` turns it off.
+    async fn emulate_media(&self, media: MediaEmulation) -> Result<(), ApiError>;
+
+    // --- Dialogs ---
+
+    /// Sets how dialogs are answered while no [`Page::on_dialog`] handler is
+    /// registered. Defaults to `DialogPolicy::Dismiss`, so `alert()` and friends never
+    /// block the page.
+    async fn set_dialog_policy(&self, policy: DialogPolicy) -> Result<(), ApiError>;
+
+    /// Accepts the open dialog.
+    ///
+    /// # Arguments
+    /// * `prompt_text` - The value `prompt()` returns; ignored by other dialogs.
+    ///
+    /// # Errors
+    /// `ApiError::ProtocolError` if no dialog is open.
+    async fn accept_dialog(&self, prompt_text: Option<&str>) -> Result<(), ApiError>;
+
+    /// Dismisses the open dialog.
+    ///
+    /// # Errors
+    /// `ApiError::ProtocolError` if no dialog is open.
+    async fn dismiss_dialog(&self) -> Result<(), ApiError>;
+
+    // --- File Choosers ---
+
+    /// Chooses files for a chooser reported to an [`Page::on_file_chooser`] handler,
+    /// like [`ElementHandle::set_input_files`] does for its input.
+    ///
+    /// # Returns
+    /// - `Err(ApiError::InvalidParameters)` if the chooser is unknown or was already
+    ///   answered, or several files are given to a chooser that is not `multiple`.
+    async fn set_file_chooser_files(&self, chooser: &FileChooser, files: &[PathBuf]) -> Result<(), ApiError>;
+
+    // --- Input Methods ---
+    // Input is dispatched as trusted browser events, unlike clicks triggered from script.
+    // Coordinates are CSS pixels relative to the top-left corner of the viewport.
+
+    /// Scrolls the first element matching `selector` into view and clicks its center.
+    ///
+    /// # Returns
+    /// - `Ok(())` once the click has been dispatched.
+    /// - `Err(ApiError::InvalidParameters)` if no element matches the selector.
+    async fn click(&self, selector: &str) -> Result<(), ApiError>;
+
+    /// Focuses the first element matching `selector` and types `text` into it.
+    ///
+    /// # Returns
+    /// - `Ok(())` once all key events have been dispatched.
+    /// - `Err(ApiError::InvalidParameters)` if no element matches the selector.
+    async fn type_text(&self, selector: &str, text: &str) -> Result<(), ApiError>;
+
+    /// Moves the mouse pointer to `(x, y)`.
+    async fn mouse_move(&self, x: f64, y: f64) -> Result<(), ApiError>;
+
+    /// Presses a mouse button at `(x, y)`.
+    async fn mouse_down(&self, x: f64, y: f64, options: MouseOptions) -> Result<(), ApiError>;
+
+    /// Releases a mouse button at `(x, y)`.
+    async fn mouse_up(&self, x: f64, y: f64, options: MouseOptions) -> Result<(), ApiError>;
+
+    /// Moves to `(x, y)`, then presses and releases a mouse button.
+    /// `options.click_count` of 2 produces a double click.
+    async fn mouse_click(&self, x: f64, y: f64, options: MouseOptions) -> Result<(), ApiError>;
+
+    /// Dispatches a wheel event at `(x, y)`, scrolling by `(delta_x, delta_y)` pixels.
+    async fn mouse_wheel(&self, x: f64, y: f64, delta_x: f64, delta_y: f64)
+    -> Result<(), ApiError>;
+
+    /// Presses a key and keeps it held down.
+    ///
+    /// # Arguments
+    /// * `key` - A key name on a US keyboard layout (e.g., `"a"`, `"A"`, `"Enter"`,
+    ///   `"ArrowLeft"`, `"Shift"`) or a code (e.g., `"KeyA"`, `"Digit1"`).
+    ///
+    /// # Returns
+    /// - `Ok(())` once the key event has been dispatched.
+    /// - `Err(ApiError::InvalidParameters)` if the key is unknown.
+    async fn keyboard_down(&self, key: &str) -> Result<(), ApiError>;
+
+    /// Releases a key previously pressed with [`Page::keyboard_down`].
+    async fn keyboard_up(&self, key: &str) -> Result<(), ApiError>;
+
+    /// Presses and releases a key or key combination such as `"Control+A"` or `"Shift+Tab"`.
+    async fn keyboard_press(&self, key: &str) -> Result<(), ApiError>;
+
+    /// Types `text` into the focused element, one key press per character.
+    /// Characters without a key on a US layout are inserted as text input.
+    async fn keyboard_type(&self, text: &str) -> Result<(), ApiError>;
+
+    /// Dispatches a single-finger tap at `(x, y)`.
+    async fn touch_tap(&self, x: f64, y: f64) -> Result<(), ApiError>;
+
+    // --- Event Subscription ---
+
+    /// Registers a handler for messages logged to the page's console
+    /// (`console.log`, `console.error`, ...).
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_console_message(
+        &self,
+        handler: EventHandler<ConsoleMessage>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for page lifecycle events (`DOMContentLoaded`, `load`,
+    /// `networkIdle`, ...) of all frames in the page.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_lifecycle_event(
+        &self,
+        handler: EventHandler<LifecycleEvent>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for uncaught exceptions thrown by page script.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_page_error(
+        &self,
+        handler: EventHandler<PageError>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for JavaScript dialogs opened by the page.
+    ///
+    /// While any such handler is registered, dialogs stay open (blocking the page's
+    /// scripts) until answered with [`Page::accept_dialog`] or [`Page::dismiss_dialog`];
+    /// otherwise the [`DialogPolicy`] applies.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_dialog(
+        &self,
+        handler: EventHandler<DialogInfo>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler called when the main frame fires its `load` event.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_load(
+        &self,
+        handler: Box<dyn Fn() + Send + Sync + 'static>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for requests about to be sent, including redirects.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_request(
+        &self,
+        handler: EventHandler<NetworkRequest>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for received responses (status line and headers).
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_response(
+        &self,
+        handler: EventHandler<NetworkResponse>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for requests whose response body finished loading.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_request_finished(
+        &self,
+        handler: EventHandler<RequestFinished>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for failed or canceled requests.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_request_failed(
+        &self,
+        handler: EventHandler<RequestFailed>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Registers a handler for file choosers opened by the page.
+    ///
+    /// While any such handler is registered, choosers are not shown to the user but
+    /// wait for [`Page::set_file_chooser_files`]; choosers of the File System Access
+    /// API (`showOpenFilePicker`) are not reported.
+    ///
+    /// # Returns
+    /// - `Ok(SubscriptionId)` to pass to [`Page::unsubscribe`].
+    /// - `Err(ApiError)` if the subscription could not be registered.
+    async fn on_file_chooser(
+        &self,
+        handler: EventHandler<FileChooser>,
+    ) -> Result<SubscriptionId, ApiError>;
+
+    /// Removes a handler registered with one of the `on_*` methods.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the subscription was removed.
+    /// - `Err(ApiError::InvalidParameters)` if the ID is unknown.
+    async fn unsubscribe(&self, id: SubscriptionId) -> Result<(), ApiError>;
 }
+
+/// Typed helpers for every [`Page`], including `dyn Page`.
+#[async_trait]
+pub trait PageExt: Page {
+    /// Evaluates a JavaScript expression and deserializes its result into `T`.
+    ///
+    /// ```ignore
+    /// let title: String = page.evaluate("document.title").await?;
+    /// let missing: Option<u32> = page.evaluate("undefined").await?;
+    /// let big: i128 = page.evaluate("2n ** 100n").await?;
+    /// ```
+    ///
+    /// # Returns
+    /// - `Err(ApiError::Evaluation)` if the script throws or its promise is rejected.
+    /// - `Err(ApiError::InvalidParameters)` if the result does not deserialize into `T`.
+    async fn evaluate<T: DeserializeOwned>(&self, script: &str) -> Result<T, ApiError> {
+        self.evaluate_value(script).await?.deserialize()
+    }
+}
+
+impl<P: Page + ?Sized> PageExt for P {}
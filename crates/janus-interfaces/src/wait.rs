@@ -0,0 +1,70 @@
+use crate::error::ApiError;
+use std::fmt::Debug;
+use std::future::Future;
+use std::time::Duration;
+
+/// Delays between checks of [`wait_until`], in milliseconds; the last one repeats.
+const WAIT_BACKOFF_MS: [u64; 5] = [0, 20, 50, 100, 500];
+
+/// How `Page::wait_for_function` re-runs its predicate in the page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Polling {
+    /// Before every repaint (at most every 100ms in pages that are not rendering).
+    #[default]
+    RequestAnimationFrame,
+    /// Every given number of milliseconds.
+    Interval(u64),
+    /// After every change of the DOM.
+    Mutation,
+}
+
+/// Outcome of one check of a condition polled by [`wait_until`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition<T> {
+    /// The condition holds; the wait resolves with this value.
+    Met(T),
+    /// The condition does not hold yet. The description of what was observed is
+    /// reported if the wait times out.
+    Unmet(String),
+}
+
+impl<T> Condition<T> {
+    /// An unmet condition, described by the `Debug` output of `observed`.
+    pub fn unmet(observed: impl Debug) -> Self {
+        Condition::Unmet(format!("{:?}", observed))
+    }
+}
+
+/// Runs `check` until it returns [`Condition::Met`], with a delay between checks
+/// growing from none to 500ms. Useful for conditions outside the DOM, e.g. a number
+/// of downloads or a server-side state.
+///
+/// # Returns
+/// - `Ok(value)` with the value of the met condition.
+/// - `Err(ApiError::Timeout { last_observed })` if the condition is not met within
+///   `timeout_ms`; `last_observed` is the description of the last unmet check.
+/// - The first error returned by `check`, right away.
+pub async fn wait_until<T, F, Fut>(timeout_ms: u64, mut check: F) -> Result<T, ApiError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Condition<T>, ApiError>>,
+{
+    let mut last_observed = None;
+    let poll = async {
+        let mut attempt = 0;
+        loop {
+            let delay = WAIT_BACKOFF_MS[attempt.min(WAIT_BACKOFF_MS.len() - 1)];
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            match check().await? {
+                Condition::Met(value) => return Ok(value),
+                Condition::Unmet(observed) => last_observed = Some(observed),
+            }
+            attempt += 1;
+        }
+    };
+    let result = tokio::time::timeout(Duration::from_millis(timeout_ms), poll).await;
+    match result {
+        Ok(result) => result,
+        Err(_) => Err(ApiError::Timeout { last_observed }),
+    }
+}
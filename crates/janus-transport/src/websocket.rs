@@ -71,47 +71,18 @@ impl Transport for WebSocketTransport {
         info!("Connecting WebSocket to {}", self.params.url);
         let _ws_config = Self::apply_options(&self.params.ws_options);
 
-        // Try connecting to the URL as provided
-        info!("Attempting direct connection to {}", self.params.url);
-        match connect_async(&self.params.url).await {
-            Ok((ws_stream, response)) => {
-                debug!("WebSocket handshake successful: {:?}", response);
+        // Connect to the URL as provided. Browser endpoints need the full target path
+        // (e.g. `/devtools/browser/<id>`); callers resolve it beforehand via HTTP discovery.
+        let (ws_stream, response) = connect_async(&self.params.url).await?;
+        debug!("WebSocket handshake successful: {:?}", response);
 
-                let (sink, source) = ws_stream.split();
-                self.sink = Some(sink);
-                self.source = Some(source);
-                // self.stream = Some(ws_stream); // Don't store stream if split
+        let (sink, source) = ws_stream.split();
+        self.sink = Some(sink);
+        self.source = Some(source);
+        // self.stream = Some(ws_stream); // Don't store stream if split
 
-                info!("WebSocket connection established.");
-                Ok(())
-            }
-            Err(err) => {
-                // If the URL ends with a slash, try without the trailing slash
-                if self.params.url.ends_with('/') {
-                    let alt_url = self.params.url.trim_end_matches('/').to_string();
-                    info!(
-                        "Initial connection failed, trying alternative URL: {}",
-                        alt_url
-                    );
-                    let (ws_stream, response) = connect_async(&alt_url).await?;
-
-                    debug!(
-                        "WebSocket handshake successful with alternative URL: {:?}",
-                        response
-                    );
-
-                    let (sink, source) = ws_stream.split();
-                    self.sink = Some(sink);
-                    self.source = Some(source);
-
-                    info!("WebSocket connection established with alternative URL.");
-                    Ok(())
-                } else {
-                    // Return the original error
-                    Err(err.into())
-                }
-            }
-        }
+        info!("WebSocket connection established.");
+        Ok(())
     }
 
     async fn disconnect(&mut self) -> Result<(), TransportError> {
@@ -3,7 +3,7 @@
 //! This example requires Chrome to be running with remote debugging enabled:
 //! `chrome --remote-debugging-port=9222 --headless=new`
 
-use janus_client::{ApiError, Browser, Page};
+use janus_client::{ApiError, Browser, Page, ScreenshotFormat, ScreenshotOptions};
 use janus_client::launch::{self, LaunchMode};
 use std::time::Duration;
 
@@ -60,10 +60,11 @@ async fn main() -> Result<(), ApiError> {
     let mozilla_title = page.title().await?;
     println!("Mozilla page title: {}", mozilla_title);
     
-    // Take a screenshot (if implemented)
-    // println!("Taking a screenshot");
-    // let screenshot = page.take_screenshot(ScreenshotFormat::Png, ScreenshotOptions::default()).await?;
-    // println!("Screenshot taken ({} bytes)", screenshot.len());
+    // Take a full-page screenshot
+    println!("Taking a screenshot");
+    let options = ScreenshotOptions { full_page: Some(true), ..Default::default() };
+    let screenshot = page.take_screenshot(ScreenshotFormat::Png, options).await?;
+    println!("Screenshot taken ({} bytes)", screenshot.len());
     
     // Close the page
     println!("Closing page");